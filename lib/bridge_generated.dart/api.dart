@@ -3,22 +3,179 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'cancel.dart';
+import 'error.dart';
 import 'frb_generated.dart';
+import 'journal.dart';
+import 'lightning.dart';
+import 'network.dart';
+import 'nwc.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'payment.dart';
+import 'penalty.dart';
+import 'progress.dart';
+import 'zap.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `end_stream`, `progress_to`
+
+
+            /// Open (or reuse) the NWC session for a connection string
+///
+/// The returned handle keeps the relay connection warm; later calls with
+/// the same connection string share it.
+Future<NwcSession>  connectNwc({required String connectionString }) => RustLib.instance.api.crateApiConnectNwc(connectionString: connectionString);
+
+/// Whether the session's relay connection is currently up
+bool  isNwcConnected({required NwcSession session }) => RustLib.instance.api.crateApiIsNwcConnected(session: session);
+
+/// Close the session's relay connection
+void  disconnectNwc({required NwcSession session }) => RustLib.instance.api.crateApiDisconnectNwc(session: session);
 
 /// Test NWC connection and get balance
-Future<BigInt> testNwcConnection({required String connectionString}) =>
-    RustLib.instance.api.crateApiTestNwcConnection(connectionString: connectionString);
+Future<BigInt>  testNwcConnection({required String connectionString }) => RustLib.instance.api.crateApiTestNwcConnection(connectionString: connectionString);
+
+/// Get wallet capabilities (NIP-47 `get_info` and the wallet's info event)
+Future<WalletInfo>  getWalletInfo({required String connectionString }) => RustLib.instance.api.crateApiGetWalletInfo(connectionString: connectionString);
+
+/// List wallet transactions (e.g. to reconcile paid penalties)
+Future<List<WalletTransaction>>  listWalletTransactions({required String connectionString , required TransactionFilter filter }) => RustLib.instance.api.crateApiListWalletTransactions(connectionString: connectionString, filter: filter);
+
+/// Look up an invoice by payment hash or bolt11
+Future<WalletTransaction>  lookupWalletInvoice({required String connectionString , required InvoiceLookup lookup }) => RustLib.instance.api.crateApiLookupWalletInvoice(connectionString: connectionString, lookup: lookup);
+
+/// Resolve a Lightning address without paying
+///
+/// Returns the sendable range, comment limit, metadata and zap support so
+/// recipients can be validated when they are added in settings.
+Future<ResolvedRecipient>  resolveLightningAddress({required String lightningAddress }) => RustLib.instance.api.crateApiResolveLightningAddress(lightningAddress: lightningAddress);
 
 /// Execute Lightning payment
-Future<String> payLightningInvoice({
-  required String connectionString,
-  required String lightningAddress,
-  required BigInt amountSats,
-  String? comment,
-}) => RustLib.instance.api.crateApiPayLightningInvoice(
-  connectionString: connectionString,
-  lightningAddress: lightningAddress,
-  amountSats: amountSats,
-  comment: comment,
-);
+///
+/// Returns a receipt with the invoice, payment hash and preimage.
+Future<PaymentOutcome>  payLightningInvoice({required String connectionString , required String lightningAddress , required BigInt amountSats , String? comment }) => RustLib.instance.api.crateApiPayLightningInvoice(connectionString: connectionString, lightningAddress: lightningAddress, amountSats: amountSats, comment: comment);
+
+/// Execute Lightning payment, streaming its progress
+///
+/// Emits an event per stage (resolving address, invoice received, relay
+/// connected, request published, response received, verified) and ends
+/// with `Completed`, or with `Failed` carrying the payment error.
+Stream<PaymentProgress>  payLightningInvoiceWithProgress({required String connectionString , required String lightningAddress , required BigInt amountSats , String? comment }) => RustLib.instance.api.crateApiPayLightningInvoiceWithProgress(connectionString: connectionString, lightningAddress: lightningAddress, amountSats: amountSats, comment: comment);
+
+/// Pay a BOLT11 invoice directly (e.g. a shared team pot)
+///
+/// `amount_sats` is only needed for invoices without an amount.
+Future<PaymentOutcome>  payBolt11Invoice({required String connectionString , required String bolt11 , BigInt? amountSats }) => RustLib.instance.api.crateApiPayBolt11Invoice(connectionString: connectionString, bolt11: bolt11, amountSats: amountSats);
+
+/// Pay a penalty to any kind of recipient (LNURL, BOLT11 invoice or keysend)
+Future<PaymentOutcome>  payRecipient({required String connectionString , required PaymentRecipient recipient , required BigInt amountSats , String? comment }) => RustLib.instance.api.crateApiPayRecipient(connectionString: connectionString, recipient: recipient, amountSats: amountSats, comment: comment);
+
+/// Set the directory payment records are kept in (call once at startup)
+Future<void>  initPaymentStore({required String directory }) => RustLib.instance.api.crateApiInitPaymentStore(directory: directory);
+
+/// Set the payment journal file (call once at startup)
+Future<void>  initPaymentJournal({required String path }) => RustLib.instance.api.crateApiInitPaymentJournal(path: path);
+
+/// Set timeouts and retries for NWC and LNURL calls made from now on
+///
+/// Rejects policies with a zero timeout or deadline.
+void  setNetworkPolicy({required NetworkPolicy policy }) => RustLib.instance.api.crateApiSetNetworkPolicy(policy: policy);
+
+/// Network policy currently in use
+NetworkPolicy  getNetworkPolicy() => RustLib.instance.api.crateApiGetNetworkPolicy();
+
+/// Built-in network policy (starting point for custom settings)
+NetworkPolicy  defaultNetworkPolicy() => RustLib.instance.api.crateApiDefaultNetworkPolicy();
+
+/// Journal entries, oldest first (all payments, or only `payment_key`)
+Future<List<JournalEntry>>  listJournalEntries({String? paymentKey }) => RustLib.instance.api.crateApiListJournalEntries(paymentKey: paymentKey);
+
+/// Latest attempt of every journaled payment, most recent first
+Future<List<JournalPayment>>  listJournalPayments() => RustLib.instance.api.crateApiListJournalPayments();
+
+/// Payments left in an unknown state by a crash or lost connection
+///
+/// Call at startup; each may or may not have been paid. Paying the same
+/// alarm occurrence again with `pay_alarm_penalty` resumes it safely.
+Future<List<JournalPayment>>  recoverUnknownPayments() => RustLib.instance.api.crateApiRecoverUnknownPayments();
+
+/// Pay the penalty of one alarm occurrence, at most once
+///
+/// `scheduled_at` is the unix time the alarm was scheduled to fire.
+/// Calling again for the same alarm occurrence (e.g. after a crash or a
+/// retry) returns the recorded outcome or resumes the in-flight payment
+/// instead of paying twice.
+Future<PaymentOutcome>  payAlarmPenalty({required String connectionString , required int alarmId , required BigInt scheduledAt , required PaymentRecipient recipient , required BigInt amountSats , String? comment }) => RustLib.instance.api.crateApiPayAlarmPenalty(connectionString: connectionString, alarmId: alarmId, scheduledAt: scheduledAt, recipient: recipient, amountSats: amountSats, comment: comment);
+
+/// Start the penalty countdown of a ringing alarm
+///
+/// Calling again for the same alarm occurrence returns the running
+/// session, so the countdown is not reset by a restart.
+Future<PenaltySession>  startPenalty({required int alarmId , required BigInt scheduledAt , required PaymentRecipient recipient , required BigInt amountSats , String? comment , required BigInt timeoutSecs }) => RustLib.instance.api.crateApiStartPenalty(alarmId: alarmId, scheduledAt: scheduledAt, recipient: recipient, amountSats: amountSats, comment: comment, timeoutSecs: timeoutSecs);
+
+/// Stop an alarm's countdown in time (None if the alarm has no penalty)
+///
+/// A penalty whose payment has started is cancelled if its request hasn't
+/// been published yet; after that this fails with `PaymentCommitted`.
+Future<PenaltySession?>  dismissPenalty({required int alarmId }) => RustLib.instance.api.crateApiDismissPenalty(alarmId: alarmId);
+
+/// Seconds left before an alarm's penalty is due (None if it has no penalty)
+Future<BigInt?>  penaltyRemainingSecs({required int alarmId }) => RustLib.instance.api.crateApiPenaltyRemainingSecs(alarmId: alarmId);
+
+/// Current penalty session of an alarm
+Future<PenaltySession?>  getPenalty({required int alarmId }) => RustLib.instance.api.crateApiGetPenalty(alarmId: alarmId);
+
+/// Pay an alarm's penalty once its countdown has run out
+///
+/// Payment failures are reported in the returned session's state
+/// (`Failed` or `Unknown`), not as an error.
+Future<PenaltySession>  payDuePenalty({required String connectionString , required int alarmId }) => RustLib.instance.api.crateApiPayDuePenalty(connectionString: connectionString, alarmId: alarmId);
+
+/// Pay an alarm's due penalty, streaming its progress
+///
+/// Same stages as `pay_lightning_invoice_with_progress`. If the penalty is
+/// not paid the stream ends with `Failed`; the session state
+/// (`Failed` / `Unknown`) can then be read with `get_penalty`.
+Stream<PaymentProgress>  payDuePenaltyWithProgress({required String connectionString , required int alarmId }) => RustLib.instance.api.crateApiPayDuePenaltyWithProgress(connectionString: connectionString, alarmId: alarmId);
+
+/// Pick up penalty sessions after a process restart
+///
+/// Pays penalties that came due while the app was gone and resumes
+/// interrupted payments. Returns every unfinished session; countdowns
+/// still running must be re-armed by the caller.
+Future<List<PenaltySession>>  resumePenalties({required String connectionString }) => RustLib.instance.api.crateApiResumePenalties(connectionString: connectionString);
+
+/// Create a handle for cancelling a payment started with it
+PaymentCancel  newPaymentCancel() => RustLib.instance.api.crateApiNewPaymentCancel();
+
+/// Cancel a payment, if its request hasn't been published yet
+///
+/// `Cancelled` means nothing will be paid; `TooLate` means the request
+/// already reached the wallet and the payment may still complete.
+CancelOutcome  cancelPayment({required PaymentCancel cancel }) => RustLib.instance.api.crateApiCancelPayment(cancel: cancel);
+
+/// Pay a recipient with a payment that `cancel` can stop
+///
+/// Fails with `Cancelled` if cancelled before the request was published.
+Future<PaymentOutcome>  payRecipientCancellable({required PaymentCancel cancel , required String connectionString , required PaymentRecipient recipient , required BigInt amountSats , String? comment }) => RustLib.instance.api.crateApiPayRecipientCancellable(cancel: cancel, connectionString: connectionString, recipient: recipient, amountSats: amountSats, comment: comment);
+
+/// Split a penalty across weighted recipients (e.g. 50/30/20)
+///
+/// Returns one outcome per recipient; some shares may fail while others
+/// are paid.
+Future<List<SplitShareOutcome>>  paySplit({required String connectionString , required List<WeightedRecipient> recipients , required BigInt amountSats , String? comment }) => RustLib.instance.api.crateApiPaySplit(connectionString: connectionString, recipients: recipients, amountSats: amountSats, comment: comment);
+
+/// Wait for the zap receipt of an alarm's paid penalty and store it
+///
+/// The receipt status is saved with the penalty session and its payment
+/// record. Returns the updated session (None if the alarm has no penalty).
+Future<PenaltySession?>  confirmPenaltyZapReceipt({required int alarmId , required BigInt timeoutSecs }) => RustLib.instance.api.crateApiConfirmPenaltyZapReceipt(alarmId: alarmId, timeoutSecs: timeoutSecs);
+
+/// Wait for the NIP-57 zap receipt of a completed payment
+///
+/// Call after the alarm has been stopped; returns the outcome with its
+/// receipt status filled in (unchanged if the payment wasn't a zap).
+Future<PaymentOutcome>  confirmZapReceipt({required PaymentOutcome outcome , required BigInt timeoutSecs }) => RustLib.instance.api.crateApiConfirmZapReceipt(outcome: outcome, timeoutSecs: timeoutSecs);
+
+            
+            
\ No newline at end of file
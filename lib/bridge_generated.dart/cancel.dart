@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>
+                abstract class PaymentCancel implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// What a cancel request achieved
+enum CancelOutcome {
+                    /// Stopped before the request was published: nothing will be paid
+cancelled,
+/// Request already published: the payment may still complete
+tooLate,
+/// The payment had been cancelled before
+alreadyCancelled,
+                    ;
+                    
+                }
+            
\ No newline at end of file
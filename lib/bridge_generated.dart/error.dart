@@ -0,0 +1,125 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `payment_unknown`, `wallet`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`
+
+
+            
+
+            /// NIP-47 error codes returned by the wallet service
+enum WalletErrorCode {
+                    /// The client is sending commands too fast
+rateLimited,
+/// The command is not known or is intentionally not implemented
+notImplemented,
+/// Not enough funds to cover the payment amount and fee reserve
+insufficientBalance,
+/// Payment failed (no route, timeout, insufficient capacity, ...)
+paymentFailed,
+/// The invoice could not be found
+notFound,
+/// The connection's spending budget is exhausted
+quotaExceeded,
+/// This connection is not allowed to do this operation
+restricted,
+/// No wallet is connected to this connection
+unauthorized,
+/// Internal wallet error
+internal,
+/// Other error
+other,
+                    ;
+                    /// Whether a request rejected with this code is worth retrying
+///
+/// Rate limits, routing failures and internal errors are transient.
+/// Everything else (budget, balance, permissions) will fail again
+/// until the user changes something in their wallet.
+ bool  isRetryable()=>RustLib.instance.api.crateErrorWalletErrorCodeIsRetryable(that: this, );
+
+
+                }
+
+@freezed
+                sealed class ZapClockError with _$ZapClockError implements FrbException {
+                    const ZapClockError._();
+
+                     /// Lightning address could not be parsed
+const factory ZapClockError.invalidAddress({   required String address , }) = ZapClockError_InvalidAddress;
+ /// HTTP error while talking to the LNURL-pay server
+const factory ZapClockError.lnurlHttp({   required String url ,  int? status ,  required String message , }) = ZapClockError_LnurlHttp;
+ /// LNURL server answered with a LUD-06 `{"status":"ERROR"}` response
+const factory ZapClockError.lnurlServer({   required String url ,  required String reason , }) = ZapClockError_LnurlServer;
+ /// LNURL server answered with something that isn't a valid LNURL-pay response
+const factory ZapClockError.invalidLnurlResponse({   required String message , }) = ZapClockError_InvalidLnurlResponse;
+ /// Requested amount is outside the recipient's sendable range
+const factory ZapClockError.amountOutOfRange({   required BigInt amountSats ,  required BigInt minSats ,  required BigInt maxSats , }) = ZapClockError_AmountOutOfRange;
+ /// Split recipients can't share the amount (no recipients or all weights zero)
+const factory ZapClockError.invalidSplit({   required String message , }) = ZapClockError_InvalidSplit;
+ /// BOLT11 invoice could not be decoded
+const factory ZapClockError.invalidInvoice({   required String message , }) = ZapClockError_InvalidInvoice;
+ /// Keysend node key, preimage or TLV record is malformed
+const factory ZapClockError.invalidKeysend({   required String message , }) = ZapClockError_InvalidKeysend;
+ /// Invoice amount differs from the amount that was requested
+const factory ZapClockError.invoiceAmountMismatch({   required BigInt expectedMsats ,  BigInt? actualMsats , }) = ZapClockError_InvoiceAmountMismatch;
+ /// Invoice is for a different network (e.g. testnet instead of mainnet)
+const factory ZapClockError.invoiceNetworkMismatch({   required String expected ,  required String actual , }) = ZapClockError_InvoiceNetworkMismatch;
+ /// Invoice expired before it could be paid
+const factory ZapClockError.invoiceExpired({   required BigInt expiredAt , }) = ZapClockError_InvoiceExpired;
+ /// Invoice description hash doesn't commit to the LNURL metadata
+const factory ZapClockError.invoiceDescriptionHashMismatch({   required String expected ,  String? actual , }) = ZapClockError_InvoiceDescriptionHashMismatch;
+ /// Wallet reported success but the preimage doesn't match the invoice
+///
+/// The payment may or may not have gone out; it must not be retried
+/// automatically.
+const factory ZapClockError.preimageMismatch({   required String paymentHash ,  required String preimage , }) = ZapClockError_PreimageMismatch;
+ /// NWC connection string could not be parsed
+const factory ZapClockError.invalidConnectionString({   required String message , }) = ZapClockError_InvalidConnectionString;
+ /// No response to a read-only request within the timeout
+const factory ZapClockError.nwcTimeout({   required BigInt seconds , }) = ZapClockError_NwcTimeout;
+ /// Payment request may have reached the wallet, but no answer came back
+///
+/// The payment may or may not have gone out. Sending it again could pay
+/// twice; resume it with the same idempotency key instead.
+const factory ZapClockError.paymentUnknown({   required String message , }) = ZapClockError_PaymentUnknown;
+ /// Wallet service answered with a NIP-47 error code
+const factory ZapClockError.wallet({   required WalletErrorCode code ,  required String message ,/// Whether the same request may succeed if sent again later
+  required bool retryable , }) = ZapClockError_Wallet;
+ /// Operation still failing when its overall deadline ran out
+const factory ZapClockError.deadlineExceeded({   required BigInt seconds , }) = ZapClockError_DeadlineExceeded;
+ /// Network policy with a zero timeout or deadline
+const factory ZapClockError.invalidNetworkPolicy({   required String message , }) = ZapClockError_InvalidNetworkPolicy;
+ /// Relay connection or publish failure
+const factory ZapClockError.relay({   required String message , }) = ZapClockError_Relay;
+ /// Payment was cancelled before its request was published
+const factory ZapClockError.cancelled() = ZapClockError_Cancelled;
+ /// Payment request was already published and can't be cancelled
+const factory ZapClockError.paymentCommitted() = ZapClockError_PaymentCommitted;
+ /// Penalty session is not in a state that allows the action
+const factory ZapClockError.invalidPenaltyTransition({   required String state ,  required String action , }) = ZapClockError_InvalidPenaltyTransition;
+ /// No penalty session exists for the alarm
+const factory ZapClockError.penaltyNotFound({   required int alarmId , }) = ZapClockError_PenaltyNotFound;
+ /// Payment records could not be read or written
+const factory ZapClockError.storage({   required String message , }) = ZapClockError_Storage;
+ /// Wallet response could not be decrypted or decoded
+const factory ZapClockError.invalidResponse({   required String message , }) = ZapClockError_InvalidResponse;
+
+                    
+
+                    /// Whether the failed operation may succeed if attempted again
+///
+/// Timeouts and relay failures of payment requests are reported as
+/// `PaymentUnknown` once the request may have been published, so a
+/// retryable error never hides a payment that may have gone out.
+ bool  isRetryable()=>RustLib.instance.api.crateErrorZapClockErrorIsRetryable(that: this, );
+
+
+                }
+            
\ No newline at end of file
@@ -4,263 +4,2605 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api.dart';
+import 'cancel.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'error.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'journal.dart';
+import 'lightning.dart';
+import 'network.dart';
+import 'nwc.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'payment.dart';
+import 'penalty.dart';
+import 'progress.dart';
+import 'zap.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1368788448;
-
-  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
-    stem: 'zap_clock',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<String> crateApiPayLightningInvoice({
-    required String connectionString,
-    required String lightningAddress,
-    required BigInt amountSats,
-    String? comment,
-  });
-
-  Future<BigInt> crateApiTestNwcConnection({required String connectionString});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<String> crateApiPayLightningInvoice({
-    required String connectionString,
-    required String lightningAddress,
-    required BigInt amountSats,
-    String? comment,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(connectionString, serializer);
-          sse_encode_String(lightningAddress, serializer);
-          sse_encode_u_64(amountSats, serializer);
-          sse_encode_opt_String(comment, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
-        },
-        codec: SseCodec(decodeSuccessData: sse_decode_String, decodeErrorData: sse_decode_String),
-        constMeta: kCrateApiPayLightningInvoiceConstMeta,
-        argValues: [connectionString, lightningAddress, amountSats, comment],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiPayLightningInvoiceConstMeta => const TaskConstMeta(
-    debugName: "pay_lightning_invoice",
-    argNames: ["connectionString", "lightningAddress", "amountSats", "comment"],
-  );
-
-  @override
-  Future<BigInt> crateApiTestNwcConnection({required String connectionString}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(connectionString, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
-        },
-        codec: SseCodec(decodeSuccessData: sse_decode_u_64, decodeErrorData: sse_decode_String),
-        constMeta: kCrateApiTestNwcConnectionConstMeta,
-        argValues: [connectionString],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTestNwcConnectionConstMeta =>
-      const TaskConstMeta(debugName: "test_nwc_connection", argNames: ["connectionString"]);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -2100985885;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'zap_clock',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  CancelOutcome crateApiCancelPayment({required PaymentCancel cancel });
+
+Future<PenaltySession?> crateApiConfirmPenaltyZapReceipt({required int alarmId , required BigInt timeoutSecs });
+
+Future<PaymentOutcome> crateApiConfirmZapReceipt({required PaymentOutcome outcome , required BigInt timeoutSecs });
+
+Future<NwcSession> crateApiConnectNwc({required String connectionString });
+
+NetworkPolicy crateApiDefaultNetworkPolicy();
+
+void crateApiDisconnectNwc({required NwcSession session });
+
+Future<PenaltySession?> crateApiDismissPenalty({required int alarmId });
+
+NetworkPolicy crateApiGetNetworkPolicy();
+
+Future<PenaltySession?> crateApiGetPenalty({required int alarmId });
+
+Future<WalletInfo> crateApiGetWalletInfo({required String connectionString });
+
+Future<void> crateApiInitPaymentJournal({required String path });
+
+Future<void> crateApiInitPaymentStore({required String directory });
+
+bool crateApiIsNwcConnected({required NwcSession session });
+
+Future<List<JournalEntry>> crateApiListJournalEntries({String? paymentKey });
+
+Future<List<JournalPayment>> crateApiListJournalPayments();
+
+Future<List<WalletTransaction>> crateApiListWalletTransactions({required String connectionString , required TransactionFilter filter });
+
+Future<WalletTransaction> crateApiLookupWalletInvoice({required String connectionString , required InvoiceLookup lookup });
+
+PaymentCancel crateApiNewPaymentCancel();
+
+Future<PaymentOutcome> crateApiPayAlarmPenalty({required String connectionString , required int alarmId , required BigInt scheduledAt , required PaymentRecipient recipient , required BigInt amountSats , String? comment });
+
+Future<PaymentOutcome> crateApiPayBolt11Invoice({required String connectionString , required String bolt11 , BigInt? amountSats });
+
+Future<PenaltySession> crateApiPayDuePenalty({required String connectionString , required int alarmId });
+
+Stream<PaymentProgress> crateApiPayDuePenaltyWithProgress({required String connectionString , required int alarmId });
+
+Future<PaymentOutcome> crateApiPayLightningInvoice({required String connectionString , required String lightningAddress , required BigInt amountSats , String? comment });
+
+Stream<PaymentProgress> crateApiPayLightningInvoiceWithProgress({required String connectionString , required String lightningAddress , required BigInt amountSats , String? comment });
+
+Future<PaymentOutcome> crateApiPayRecipient({required String connectionString , required PaymentRecipient recipient , required BigInt amountSats , String? comment });
+
+Future<PaymentOutcome> crateApiPayRecipientCancellable({required PaymentCancel cancel , required String connectionString , required PaymentRecipient recipient , required BigInt amountSats , String? comment });
+
+Future<List<SplitShareOutcome>> crateApiPaySplit({required String connectionString , required List<WeightedRecipient> recipients , required BigInt amountSats , String? comment });
+
+Future<BigInt?> crateApiPenaltyRemainingSecs({required int alarmId });
+
+Future<List<JournalPayment>> crateApiRecoverUnknownPayments();
+
+Future<ResolvedRecipient> crateApiResolveLightningAddress({required String lightningAddress });
+
+Future<List<PenaltySession>> crateApiResumePenalties({required String connectionString });
+
+void crateApiSetNetworkPolicy({required NetworkPolicy policy });
+
+Future<PenaltySession> crateApiStartPenalty({required int alarmId , required BigInt scheduledAt , required PaymentRecipient recipient , required BigInt amountSats , String? comment , required BigInt timeoutSecs });
+
+Future<BigInt> crateApiTestNwcConnection({required String connectionString });
+
+bool crateErrorWalletErrorCodeIsRetryable({required WalletErrorCode that });
+
+bool crateErrorZapClockErrorIsRetryable({required ZapClockError that });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NwcSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NwcSession;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NwcSessionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PaymentCancel;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PaymentCancel;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PaymentCancelPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override CancelOutcome crateApiCancelPayment({required PaymentCancel cancel })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(cancel, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cancel_outcome,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCancelPaymentConstMeta,
+            argValues: [cancel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelPaymentConstMeta => const TaskConstMeta(
+            debugName: "cancel_payment",
+            argNames: ["cancel"],
+        );
+        
+
+@override Future<PenaltySession?> crateApiConfirmPenaltyZapReceipt({required int alarmId , required BigInt timeoutSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(alarmId, serializer);
+sse_encode_u_64(timeoutSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_penalty_session,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiConfirmPenaltyZapReceiptConstMeta,
+            argValues: [alarmId, timeoutSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfirmPenaltyZapReceiptConstMeta => const TaskConstMeta(
+            debugName: "confirm_penalty_zap_receipt",
+            argNames: ["alarmId", "timeoutSecs"],
+        );
+        
+
+@override Future<PaymentOutcome> crateApiConfirmZapReceipt({required PaymentOutcome outcome , required BigInt timeoutSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_payment_outcome(outcome, serializer);
+sse_encode_u_64(timeoutSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiConfirmZapReceiptConstMeta,
+            argValues: [outcome, timeoutSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConfirmZapReceiptConstMeta => const TaskConstMeta(
+            debugName: "confirm_zap_receipt",
+            argNames: ["outcome", "timeoutSecs"],
+        );
+        
+
+@override Future<NwcSession> crateApiConnectNwc({required String connectionString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiConnectNwcConstMeta,
+            argValues: [connectionString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectNwcConstMeta => const TaskConstMeta(
+            debugName: "connect_nwc",
+            argNames: ["connectionString"],
+        );
+        
+
+@override NetworkPolicy crateApiDefaultNetworkPolicy()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDefaultNetworkPolicyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDefaultNetworkPolicyConstMeta => const TaskConstMeta(
+            debugName: "default_network_policy",
+            argNames: [],
+        );
+        
+
+@override void crateApiDisconnectNwc({required NwcSession session })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(session, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDisconnectNwcConstMeta,
+            argValues: [session],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDisconnectNwcConstMeta => const TaskConstMeta(
+            debugName: "disconnect_nwc",
+            argNames: ["session"],
+        );
+        
+
+@override Future<PenaltySession?> crateApiDismissPenalty({required int alarmId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(alarmId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_penalty_session,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiDismissPenaltyConstMeta,
+            argValues: [alarmId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDismissPenaltyConstMeta => const TaskConstMeta(
+            debugName: "dismiss_penalty",
+            argNames: ["alarmId"],
+        );
+        
+
+@override NetworkPolicy crateApiGetNetworkPolicy()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetNetworkPolicyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNetworkPolicyConstMeta => const TaskConstMeta(
+            debugName: "get_network_policy",
+            argNames: [],
+        );
+        
+
+@override Future<PenaltySession?> crateApiGetPenalty({required int alarmId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(alarmId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_penalty_session,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiGetPenaltyConstMeta,
+            argValues: [alarmId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPenaltyConstMeta => const TaskConstMeta(
+            debugName: "get_penalty",
+            argNames: ["alarmId"],
+        );
+        
+
+@override Future<WalletInfo> crateApiGetWalletInfo({required String connectionString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_wallet_info,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiGetWalletInfoConstMeta,
+            argValues: [connectionString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWalletInfoConstMeta => const TaskConstMeta(
+            debugName: "get_wallet_info",
+            argNames: ["connectionString"],
+        );
+        
+
+@override Future<void> crateApiInitPaymentJournal({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiInitPaymentJournalConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitPaymentJournalConstMeta => const TaskConstMeta(
+            debugName: "init_payment_journal",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiInitPaymentStore({required String directory })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(directory, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiInitPaymentStoreConstMeta,
+            argValues: [directory],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitPaymentStoreConstMeta => const TaskConstMeta(
+            debugName: "init_payment_store",
+            argNames: ["directory"],
+        );
+        
+
+@override bool crateApiIsNwcConnected({required NwcSession session })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(session, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsNwcConnectedConstMeta,
+            argValues: [session],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNwcConnectedConstMeta => const TaskConstMeta(
+            debugName: "is_nwc_connected",
+            argNames: ["session"],
+        );
+        
+
+@override Future<List<JournalEntry>> crateApiListJournalEntries({String? paymentKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(paymentKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_journal_entry,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiListJournalEntriesConstMeta,
+            argValues: [paymentKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListJournalEntriesConstMeta => const TaskConstMeta(
+            debugName: "list_journal_entries",
+            argNames: ["paymentKey"],
+        );
+        
+
+@override Future<List<JournalPayment>> crateApiListJournalPayments()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_journal_payment,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiListJournalPaymentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListJournalPaymentsConstMeta => const TaskConstMeta(
+            debugName: "list_journal_payments",
+            argNames: [],
+        );
+        
+
+@override Future<List<WalletTransaction>> crateApiListWalletTransactions({required String connectionString , required TransactionFilter filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_box_autoadd_transaction_filter(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_wallet_transaction,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiListWalletTransactionsConstMeta,
+            argValues: [connectionString, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListWalletTransactionsConstMeta => const TaskConstMeta(
+            debugName: "list_wallet_transactions",
+            argNames: ["connectionString", "filter"],
+        );
+        
+
+@override Future<WalletTransaction> crateApiLookupWalletInvoice({required String connectionString , required InvoiceLookup lookup })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_box_autoadd_invoice_lookup(lookup, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_wallet_transaction,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiLookupWalletInvoiceConstMeta,
+            argValues: [connectionString, lookup],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLookupWalletInvoiceConstMeta => const TaskConstMeta(
+            debugName: "lookup_wallet_invoice",
+            argNames: ["connectionString", "lookup"],
+        );
+        
+
+@override PaymentCancel crateApiNewPaymentCancel()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNewPaymentCancelConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNewPaymentCancelConstMeta => const TaskConstMeta(
+            debugName: "new_payment_cancel",
+            argNames: [],
+        );
+        
+
+@override Future<PaymentOutcome> crateApiPayAlarmPenalty({required String connectionString , required int alarmId , required BigInt scheduledAt , required PaymentRecipient recipient , required BigInt amountSats , String? comment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_i_32(alarmId, serializer);
+sse_encode_u_64(scheduledAt, serializer);
+sse_encode_box_autoadd_payment_recipient(recipient, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPayAlarmPenaltyConstMeta,
+            argValues: [connectionString, alarmId, scheduledAt, recipient, amountSats, comment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPayAlarmPenaltyConstMeta => const TaskConstMeta(
+            debugName: "pay_alarm_penalty",
+            argNames: ["connectionString", "alarmId", "scheduledAt", "recipient", "amountSats", "comment"],
+        );
+        
+
+@override Future<PaymentOutcome> crateApiPayBolt11Invoice({required String connectionString , required String bolt11 , BigInt? amountSats })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_String(bolt11, serializer);
+sse_encode_opt_box_autoadd_u_64(amountSats, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPayBolt11InvoiceConstMeta,
+            argValues: [connectionString, bolt11, amountSats],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPayBolt11InvoiceConstMeta => const TaskConstMeta(
+            debugName: "pay_bolt11_invoice",
+            argNames: ["connectionString", "bolt11", "amountSats"],
+        );
+        
+
+@override Future<PenaltySession> crateApiPayDuePenalty({required String connectionString , required int alarmId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_i_32(alarmId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_penalty_session,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPayDuePenaltyConstMeta,
+            argValues: [connectionString, alarmId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPayDuePenaltyConstMeta => const TaskConstMeta(
+            debugName: "pay_due_penalty",
+            argNames: ["connectionString", "alarmId"],
+        );
+        
+
+@override Stream<PaymentProgress> crateApiPayDuePenaltyWithProgress({required String connectionString , required int alarmId })  { 
+            final sink = RustStreamSink<PaymentProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_payment_progress_Sse(sink, serializer);
+sse_encode_String(connectionString, serializer);
+sse_encode_i_32(alarmId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPayDuePenaltyWithProgressConstMeta,
+            argValues: [sink, connectionString, alarmId],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiPayDuePenaltyWithProgressConstMeta => const TaskConstMeta(
+            debugName: "pay_due_penalty_with_progress",
+            argNames: ["sink", "connectionString", "alarmId"],
+        );
+        
+
+@override Future<PaymentOutcome> crateApiPayLightningInvoice({required String connectionString , required String lightningAddress , required BigInt amountSats , String? comment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_String(lightningAddress, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPayLightningInvoiceConstMeta,
+            argValues: [connectionString, lightningAddress, amountSats, comment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPayLightningInvoiceConstMeta => const TaskConstMeta(
+            debugName: "pay_lightning_invoice",
+            argNames: ["connectionString", "lightningAddress", "amountSats", "comment"],
+        );
+        
+
+@override Stream<PaymentProgress> crateApiPayLightningInvoiceWithProgress({required String connectionString , required String lightningAddress , required BigInt amountSats , String? comment })  { 
+            final sink = RustStreamSink<PaymentProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_payment_progress_Sse(sink, serializer);
+sse_encode_String(connectionString, serializer);
+sse_encode_String(lightningAddress, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPayLightningInvoiceWithProgressConstMeta,
+            argValues: [sink, connectionString, lightningAddress, amountSats, comment],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiPayLightningInvoiceWithProgressConstMeta => const TaskConstMeta(
+            debugName: "pay_lightning_invoice_with_progress",
+            argNames: ["sink", "connectionString", "lightningAddress", "amountSats", "comment"],
+        );
+        
+
+@override Future<PaymentOutcome> crateApiPayRecipient({required String connectionString , required PaymentRecipient recipient , required BigInt amountSats , String? comment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_box_autoadd_payment_recipient(recipient, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPayRecipientConstMeta,
+            argValues: [connectionString, recipient, amountSats, comment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPayRecipientConstMeta => const TaskConstMeta(
+            debugName: "pay_recipient",
+            argNames: ["connectionString", "recipient", "amountSats", "comment"],
+        );
+        
+
+@override Future<PaymentOutcome> crateApiPayRecipientCancellable({required PaymentCancel cancel , required String connectionString , required PaymentRecipient recipient , required BigInt amountSats , String? comment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(cancel, serializer);
+sse_encode_String(connectionString, serializer);
+sse_encode_box_autoadd_payment_recipient(recipient, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPayRecipientCancellableConstMeta,
+            argValues: [cancel, connectionString, recipient, amountSats, comment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPayRecipientCancellableConstMeta => const TaskConstMeta(
+            debugName: "pay_recipient_cancellable",
+            argNames: ["cancel", "connectionString", "recipient", "amountSats", "comment"],
+        );
+        
+
+@override Future<List<SplitShareOutcome>> crateApiPaySplit({required String connectionString , required List<WeightedRecipient> recipients , required BigInt amountSats , String? comment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+sse_encode_list_weighted_recipient(recipients, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_split_share_outcome,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPaySplitConstMeta,
+            argValues: [connectionString, recipients, amountSats, comment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPaySplitConstMeta => const TaskConstMeta(
+            debugName: "pay_split",
+            argNames: ["connectionString", "recipients", "amountSats", "comment"],
+        );
+        
+
+@override Future<BigInt?> crateApiPenaltyRemainingSecs({required int alarmId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(alarmId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiPenaltyRemainingSecsConstMeta,
+            argValues: [alarmId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPenaltyRemainingSecsConstMeta => const TaskConstMeta(
+            debugName: "penalty_remaining_secs",
+            argNames: ["alarmId"],
+        );
+        
+
+@override Future<List<JournalPayment>> crateApiRecoverUnknownPayments()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_journal_payment,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiRecoverUnknownPaymentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecoverUnknownPaymentsConstMeta => const TaskConstMeta(
+            debugName: "recover_unknown_payments",
+            argNames: [],
+        );
+        
+
+@override Future<ResolvedRecipient> crateApiResolveLightningAddress({required String lightningAddress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(lightningAddress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resolved_recipient,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiResolveLightningAddressConstMeta,
+            argValues: [lightningAddress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResolveLightningAddressConstMeta => const TaskConstMeta(
+            debugName: "resolve_lightning_address",
+            argNames: ["lightningAddress"],
+        );
+        
+
+@override Future<List<PenaltySession>> crateApiResumePenalties({required String connectionString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_penalty_session,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiResumePenaltiesConstMeta,
+            argValues: [connectionString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResumePenaltiesConstMeta => const TaskConstMeta(
+            debugName: "resume_penalties",
+            argNames: ["connectionString"],
+        );
+        
+
+@override void crateApiSetNetworkPolicy({required NetworkPolicy policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_policy(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiSetNetworkPolicyConstMeta,
+            argValues: [policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetNetworkPolicyConstMeta => const TaskConstMeta(
+            debugName: "set_network_policy",
+            argNames: ["policy"],
+        );
+        
+
+@override Future<PenaltySession> crateApiStartPenalty({required int alarmId , required BigInt scheduledAt , required PaymentRecipient recipient , required BigInt amountSats , String? comment , required BigInt timeoutSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(alarmId, serializer);
+sse_encode_u_64(scheduledAt, serializer);
+sse_encode_box_autoadd_payment_recipient(recipient, serializer);
+sse_encode_u_64(amountSats, serializer);
+sse_encode_opt_String(comment, serializer);
+sse_encode_u_64(timeoutSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_penalty_session,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiStartPenaltyConstMeta,
+            argValues: [alarmId, scheduledAt, recipient, amountSats, comment, timeoutSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartPenaltyConstMeta => const TaskConstMeta(
+            debugName: "start_penalty",
+            argNames: ["alarmId", "scheduledAt", "recipient", "amountSats", "comment", "timeoutSecs"],
+        );
+        
+
+@override Future<BigInt> crateApiTestNwcConnection({required String connectionString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(connectionString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_zap_clock_error,
+        )
+        ,
+            constMeta: kCrateApiTestNwcConnectionConstMeta,
+            argValues: [connectionString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTestNwcConnectionConstMeta => const TaskConstMeta(
+            debugName: "test_nwc_connection",
+            argNames: ["connectionString"],
+        );
+        
+
+@override bool crateErrorWalletErrorCodeIsRetryable({required WalletErrorCode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_wallet_error_code(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateErrorWalletErrorCodeIsRetryableConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateErrorWalletErrorCodeIsRetryableConstMeta => const TaskConstMeta(
+            debugName: "wallet_error_code_is_retryable",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateErrorZapClockErrorIsRetryable({required ZapClockError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_zap_clock_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateErrorZapClockErrorIsRetryableConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateErrorZapClockErrorIsRetryableConstMeta => const TaskConstMeta(
+            debugName: "zap_clock_error_is_retryable",
+            argNames: ["that"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NwcSession => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NwcSession => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PaymentCancel => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PaymentCancel => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected NwcSession dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NwcSessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PaymentCancel dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaymentCancelImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NwcSession dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NwcSessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PaymentCancel dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaymentCancelImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NwcSession dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NwcSessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PaymentCancel dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaymentCancelImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<PaymentProgress> dco_decode_StreamSink_payment_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected InvoiceLookup dco_decode_box_autoadd_invoice_lookup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_invoice_lookup(raw); }
+
+@protected LnurlImage dco_decode_box_autoadd_lnurl_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lnurl_image(raw); }
+
+@protected NetworkPolicy dco_decode_box_autoadd_network_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_network_policy(raw); }
+
+@protected PaymentOutcome dco_decode_box_autoadd_payment_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_payment_outcome(raw); }
+
+@protected PaymentRecipient dco_decode_box_autoadd_payment_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_payment_recipient(raw); }
+
+@protected PenaltySession dco_decode_box_autoadd_penalty_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_penalty_session(raw); }
+
+@protected SuccessAction dco_decode_box_autoadd_success_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_success_action(raw); }
+
+@protected TransactionFilter dco_decode_box_autoadd_transaction_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_transaction_filter(raw); }
+
+@protected TransactionType dco_decode_box_autoadd_transaction_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_transaction_type(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected ZapClockError dco_decode_box_autoadd_zap_clock_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_zap_clock_error(raw); }
+
+@protected ZapInfo dco_decode_box_autoadd_zap_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_zap_info(raw); }
+
+@protected PaymentOutcome dco_decode_box_payment_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_payment_outcome(raw); }
+
+@protected CancelOutcome dco_decode_cancel_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancelOutcome.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected InvoiceLookup dco_decode_invoice_lookup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return InvoiceLookup_PaymentHash(dco_decode_String(raw[1]),);
+case 1: return InvoiceLookup_Bolt11(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected JournalEntry dco_decode_journal_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return JournalEntry(paymentKey: dco_decode_String(arr[0]),
+stage: dco_decode_journal_stage(arr[1]),
+at: dco_decode_u_64(arr[2]),
+paymentHash: dco_decode_opt_String(arr[3]),
+amountMsats: dco_decode_opt_box_autoadd_u_64(arr[4]),
+detail: dco_decode_opt_String(arr[5]),); }
+
+@protected JournalPayment dco_decode_journal_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return JournalPayment(paymentKey: dco_decode_String(arr[0]),
+state: dco_decode_journal_payment_state(arr[1]),
+lastStage: dco_decode_journal_stage(arr[2]),
+paymentHash: dco_decode_opt_String(arr[3]),
+amountMsats: dco_decode_opt_box_autoadd_u_64(arr[4]),
+recipient: dco_decode_opt_String(arr[5]),
+error: dco_decode_opt_String(arr[6]),
+startedAt: dco_decode_u_64(arr[7]),
+updatedAt: dco_decode_u_64(arr[8]),); }
+
+@protected JournalPaymentState dco_decode_journal_payment_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JournalPaymentState.values[raw as int]; }
+
+@protected JournalStage dco_decode_journal_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return JournalStage.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<JournalEntry> dco_decode_list_journal_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_journal_entry).toList(); }
+
+@protected List<JournalPayment> dco_decode_list_journal_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_journal_payment).toList(); }
+
+@protected List<PenaltySession> dco_decode_list_penalty_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_penalty_session).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<SplitShareOutcome> dco_decode_list_split_share_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_split_share_outcome).toList(); }
+
+@protected List<TlvRecord> dco_decode_list_tlv_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_tlv_record).toList(); }
+
+@protected List<WalletTransaction> dco_decode_list_wallet_transaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_wallet_transaction).toList(); }
+
+@protected List<WeightedRecipient> dco_decode_list_weighted_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_weighted_recipient).toList(); }
+
+@protected LnurlImage dco_decode_lnurl_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LnurlImage(mimeType: dco_decode_String(arr[0]),
+data: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected LnurlMetadata dco_decode_lnurl_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LnurlMetadata(description: dco_decode_String(arr[0]),
+longDescription: dco_decode_opt_String(arr[1]),
+image: dco_decode_opt_box_autoadd_lnurl_image(arr[2]),
+identifier: dco_decode_opt_String(arr[3]),
+email: dco_decode_opt_String(arr[4]),); }
+
+@protected NetworkPolicy dco_decode_network_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return NetworkPolicy(relayConnectTimeoutSecs: dco_decode_u_64(arr[0]),
+relayQueryTimeoutSecs: dco_decode_u_64(arr[1]),
+walletTimeoutSecs: dco_decode_u_64(arr[2]),
+paymentTimeoutSecs: dco_decode_u_64(arr[3]),
+httpConnectTimeoutSecs: dco_decode_u_64(arr[4]),
+httpTimeoutSecs: dco_decode_u_64(arr[5]),
+maxRetries: dco_decode_u_32(arr[6]),
+retryBackoffMs: dco_decode_u_64(arr[7]),
+deadlineSecs: dco_decode_u_64(arr[8]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected LnurlImage? dco_decode_opt_box_autoadd_lnurl_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_lnurl_image(raw); }
+
+@protected PaymentOutcome? dco_decode_opt_box_autoadd_payment_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_payment_outcome(raw); }
+
+@protected PenaltySession? dco_decode_opt_box_autoadd_penalty_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_penalty_session(raw); }
+
+@protected SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_success_action(raw); }
+
+@protected TransactionType? dco_decode_opt_box_autoadd_transaction_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_transaction_type(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected ZapClockError? dco_decode_opt_box_autoadd_zap_clock_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_zap_clock_error(raw); }
+
+@protected ZapInfo? dco_decode_opt_box_autoadd_zap_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_zap_info(raw); }
+
+@protected PaymentOutcome dco_decode_payment_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return PaymentOutcome(bolt11: dco_decode_opt_String(arr[0]),
+paymentHash: dco_decode_String(arr[1]),
+preimage: dco_decode_String(arr[2]),
+amountMsats: dco_decode_u_64(arr[3]),
+feesPaidMsats: dco_decode_opt_box_autoadd_u_64(arr[4]),
+recipient: dco_decode_String(arr[5]),
+comment: dco_decode_opt_String(arr[6]),
+successAction: dco_decode_opt_box_autoadd_success_action(arr[7]),
+zap: dco_decode_opt_box_autoadd_zap_info(arr[8]),
+startedAt: dco_decode_u_64(arr[9]),
+paidAt: dco_decode_u_64(arr[10]),); }
+
+@protected PaymentProgress dco_decode_payment_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PaymentProgress_ResolvingAddress(address: dco_decode_String(raw[1]),);
+case 1: return PaymentProgress_InvoiceReceived(amountMsats: dco_decode_u_64(raw[1]),paymentHash: dco_decode_String(raw[2]),);
+case 2: return PaymentProgress_RelayConnected(relay: dco_decode_String(raw[1]),);
+case 3: return PaymentProgress_RequestPublished(requestId: dco_decode_String(raw[1]),);
+case 4: return PaymentProgress_ResponseReceived();
+case 5: return PaymentProgress_Verified(paymentHash: dco_decode_String(raw[1]),);
+case 6: return PaymentProgress_Completed(outcome: dco_decode_box_payment_outcome(raw[1]),);
+case 7: return PaymentProgress_Failed(error: dco_decode_box_autoadd_zap_clock_error(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected PaymentRecipient dco_decode_payment_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PaymentRecipient_Lnurl(address: dco_decode_String(raw[1]),);
+case 1: return PaymentRecipient_Bolt11(invoice: dco_decode_String(raw[1]),);
+case 2: return PaymentRecipient_Keysend(pubkey: dco_decode_String(raw[1]),tlvRecords: dco_decode_list_tlv_record(raw[2]),preimage: dco_decode_opt_String(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected PenaltySession dco_decode_penalty_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return PenaltySession(alarmId: dco_decode_i_32(arr[0]),
+scheduledAt: dco_decode_u_64(arr[1]),
+state: dco_decode_penalty_state(arr[2]),
+recipient: dco_decode_payment_recipient(arr[3]),
+amountSats: dco_decode_u_64(arr[4]),
+comment: dco_decode_opt_String(arr[5]),
+timeoutSecs: dco_decode_u_64(arr[6]),
+ringingSince: dco_decode_u_64(arr[7]),
+countdownStartedAt: dco_decode_opt_box_autoadd_u_64(arr[8]),
+outcome: dco_decode_opt_box_autoadd_payment_outcome(arr[9]),
+error: dco_decode_opt_String(arr[10]),
+updatedAt: dco_decode_u_64(arr[11]),); }
+
+@protected PenaltyState dco_decode_penalty_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PenaltyState.values[raw as int]; }
+
+@protected ResolvedRecipient dco_decode_resolved_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ResolvedRecipient(address: dco_decode_String(arr[0]),
+endpoint: dco_decode_String(arr[1]),
+metadata: dco_decode_lnurl_metadata(arr[2]),
+minSendableMsats: dco_decode_u_64(arr[3]),
+maxSendableMsats: dco_decode_u_64(arr[4]),
+commentAllowed: dco_decode_opt_box_autoadd_u_64(arr[5]),
+supportsZaps: dco_decode_bool(arr[6]),
+nostrPubkey: dco_decode_opt_String(arr[7]),); }
+
+@protected SplitShareOutcome dco_decode_split_share_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SplitShareOutcome(address: dco_decode_String(arr[0]),
+amountSats: dco_decode_u_64(arr[1]),
+outcome: dco_decode_opt_box_autoadd_payment_outcome(arr[2]),
+error: dco_decode_opt_box_autoadd_zap_clock_error(arr[3]),); }
+
+@protected SuccessAction dco_decode_success_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SuccessAction_Message(message: dco_decode_String(raw[1]),);
+case 1: return SuccessAction_Url(description: dco_decode_String(raw[1]),url: dco_decode_String(raw[2]),);
+case 2: return SuccessAction_Aes(description: dco_decode_String(raw[1]),plaintext: dco_decode_opt_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected TlvRecord dco_decode_tlv_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TlvRecord(recordType: dco_decode_u_64(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
+@protected TransactionFilter dco_decode_transaction_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TransactionFilter(from: dco_decode_opt_box_autoadd_u_64(arr[0]),
+until: dco_decode_opt_box_autoadd_u_64(arr[1]),
+limit: dco_decode_opt_box_autoadd_u_64(arr[2]),
+offset: dco_decode_opt_box_autoadd_u_64(arr[3]),
+unpaid: dco_decode_opt_box_autoadd_bool(arr[4]),
+transactionType: dco_decode_opt_box_autoadd_transaction_type(arr[5]),); }
+
+@protected TransactionType dco_decode_transaction_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransactionType.values[raw as int]; }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected WalletErrorCode dco_decode_wallet_error_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WalletErrorCode.values[raw as int]; }
+
+@protected WalletInfo dco_decode_wallet_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return WalletInfo(alias: dco_decode_opt_String(arr[0]),
+color: dco_decode_opt_String(arr[1]),
+pubkey: dco_decode_opt_String(arr[2]),
+network: dco_decode_opt_String(arr[3]),
+blockHeight: dco_decode_opt_box_autoadd_u_32(arr[4]),
+blockHash: dco_decode_opt_String(arr[5]),
+methods: dco_decode_list_String(arr[6]),
+notifications: dco_decode_list_String(arr[7]),
+encryption: dco_decode_list_String(arr[8]),
+getInfoSupported: dco_decode_bool(arr[9]),
+canPayInvoice: dco_decode_bool(arr[10]),); }
+
+@protected WalletTransaction dco_decode_wallet_transaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return WalletTransaction(transactionType: dco_decode_opt_box_autoadd_transaction_type(arr[0]),
+invoice: dco_decode_opt_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+descriptionHash: dco_decode_opt_String(arr[3]),
+preimage: dco_decode_opt_String(arr[4]),
+paymentHash: dco_decode_String(arr[5]),
+amount: dco_decode_u_64(arr[6]),
+feesPaid: dco_decode_u_64(arr[7]),
+createdAt: dco_decode_u_64(arr[8]),
+expiresAt: dco_decode_opt_box_autoadd_u_64(arr[9]),
+settledAt: dco_decode_opt_box_autoadd_u_64(arr[10]),); }
+
+@protected WeightedRecipient dco_decode_weighted_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return WeightedRecipient(address: dco_decode_String(arr[0]),
+weight: dco_decode_u_32(arr[1]),); }
+
+@protected ZapClockError dco_decode_zap_clock_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ZapClockError_InvalidAddress(address: dco_decode_String(raw[1]),);
+case 1: return ZapClockError_LnurlHttp(url: dco_decode_String(raw[1]),status: dco_decode_opt_box_autoadd_u_16(raw[2]),message: dco_decode_String(raw[3]),);
+case 2: return ZapClockError_LnurlServer(url: dco_decode_String(raw[1]),reason: dco_decode_String(raw[2]),);
+case 3: return ZapClockError_InvalidLnurlResponse(message: dco_decode_String(raw[1]),);
+case 4: return ZapClockError_AmountOutOfRange(amountSats: dco_decode_u_64(raw[1]),minSats: dco_decode_u_64(raw[2]),maxSats: dco_decode_u_64(raw[3]),);
+case 5: return ZapClockError_InvalidSplit(message: dco_decode_String(raw[1]),);
+case 6: return ZapClockError_InvalidInvoice(message: dco_decode_String(raw[1]),);
+case 7: return ZapClockError_InvalidKeysend(message: dco_decode_String(raw[1]),);
+case 8: return ZapClockError_InvoiceAmountMismatch(expectedMsats: dco_decode_u_64(raw[1]),actualMsats: dco_decode_opt_box_autoadd_u_64(raw[2]),);
+case 9: return ZapClockError_InvoiceNetworkMismatch(expected: dco_decode_String(raw[1]),actual: dco_decode_String(raw[2]),);
+case 10: return ZapClockError_InvoiceExpired(expiredAt: dco_decode_u_64(raw[1]),);
+case 11: return ZapClockError_InvoiceDescriptionHashMismatch(expected: dco_decode_String(raw[1]),actual: dco_decode_opt_String(raw[2]),);
+case 12: return ZapClockError_PreimageMismatch(paymentHash: dco_decode_String(raw[1]),preimage: dco_decode_String(raw[2]),);
+case 13: return ZapClockError_InvalidConnectionString(message: dco_decode_String(raw[1]),);
+case 14: return ZapClockError_NwcTimeout(seconds: dco_decode_u_64(raw[1]),);
+case 15: return ZapClockError_PaymentUnknown(message: dco_decode_String(raw[1]),);
+case 16: return ZapClockError_Wallet(code: dco_decode_wallet_error_code(raw[1]),message: dco_decode_String(raw[2]),retryable: dco_decode_bool(raw[3]),);
+case 17: return ZapClockError_DeadlineExceeded(seconds: dco_decode_u_64(raw[1]),);
+case 18: return ZapClockError_InvalidNetworkPolicy(message: dco_decode_String(raw[1]),);
+case 19: return ZapClockError_Relay(message: dco_decode_String(raw[1]),);
+case 20: return ZapClockError_Cancelled();
+case 21: return ZapClockError_PaymentCommitted();
+case 22: return ZapClockError_InvalidPenaltyTransition(state: dco_decode_String(raw[1]),action: dco_decode_String(raw[2]),);
+case 23: return ZapClockError_PenaltyNotFound(alarmId: dco_decode_i_32(raw[1]),);
+case 24: return ZapClockError_Storage(message: dco_decode_String(raw[1]),);
+case 25: return ZapClockError_InvalidResponse(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ZapInfo dco_decode_zap_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ZapInfo(requestId: dco_decode_String(arr[0]),
+requestJson: dco_decode_String(arr[1]),
+serverPubkey: dco_decode_String(arr[2]),
+receipt: dco_decode_zap_receipt_status(arr[3]),); }
+
+@protected ZapReceiptStatus dco_decode_zap_receipt_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ZapReceiptStatus_Pending();
+case 1: return ZapReceiptStatus_Observed(receiptId: dco_decode_String(raw[1]),);
+case 2: return ZapReceiptStatus_NotObserved();
+                default: throw Exception("unreachable");
+            } }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected NwcSession sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NwcSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PaymentCancel sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PaymentCancelImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NwcSession sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NwcSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PaymentCancel sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PaymentCancelImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NwcSession sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NwcSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PaymentCancel sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PaymentCancelImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<PaymentProgress> sse_decode_StreamSink_payment_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected InvoiceLookup sse_decode_box_autoadd_invoice_lookup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_invoice_lookup(deserializer)); }
+
+@protected LnurlImage sse_decode_box_autoadd_lnurl_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lnurl_image(deserializer)); }
+
+@protected NetworkPolicy sse_decode_box_autoadd_network_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_network_policy(deserializer)); }
+
+@protected PaymentOutcome sse_decode_box_autoadd_payment_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_payment_outcome(deserializer)); }
+
+@protected PaymentRecipient sse_decode_box_autoadd_payment_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_payment_recipient(deserializer)); }
+
+@protected PenaltySession sse_decode_box_autoadd_penalty_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_penalty_session(deserializer)); }
+
+@protected SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_success_action(deserializer)); }
+
+@protected TransactionFilter sse_decode_box_autoadd_transaction_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_transaction_filter(deserializer)); }
+
+@protected TransactionType sse_decode_box_autoadd_transaction_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_transaction_type(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected ZapClockError sse_decode_box_autoadd_zap_clock_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_zap_clock_error(deserializer)); }
+
+@protected ZapInfo sse_decode_box_autoadd_zap_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_zap_info(deserializer)); }
+
+@protected PaymentOutcome sse_decode_box_payment_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_payment_outcome(deserializer)); }
+
+@protected CancelOutcome sse_decode_cancel_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CancelOutcome.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected InvoiceLookup sse_decode_invoice_lookup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return InvoiceLookup_PaymentHash(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return InvoiceLookup_Bolt11(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected JournalEntry sse_decode_journal_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_paymentKey = sse_decode_String(deserializer);
+var var_stage = sse_decode_journal_stage(deserializer);
+var var_at = sse_decode_u_64(deserializer);
+var var_paymentHash = sse_decode_opt_String(deserializer);
+var var_amountMsats = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_detail = sse_decode_opt_String(deserializer);
+return JournalEntry(paymentKey: var_paymentKey, stage: var_stage, at: var_at, paymentHash: var_paymentHash, amountMsats: var_amountMsats, detail: var_detail); }
+
+@protected JournalPayment sse_decode_journal_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_paymentKey = sse_decode_String(deserializer);
+var var_state = sse_decode_journal_payment_state(deserializer);
+var var_lastStage = sse_decode_journal_stage(deserializer);
+var var_paymentHash = sse_decode_opt_String(deserializer);
+var var_amountMsats = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_recipient = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_startedAt = sse_decode_u_64(deserializer);
+var var_updatedAt = sse_decode_u_64(deserializer);
+return JournalPayment(paymentKey: var_paymentKey, state: var_state, lastStage: var_lastStage, paymentHash: var_paymentHash, amountMsats: var_amountMsats, recipient: var_recipient, error: var_error, startedAt: var_startedAt, updatedAt: var_updatedAt); }
+
+@protected JournalPaymentState sse_decode_journal_payment_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return JournalPaymentState.values[inner]; }
+
+@protected JournalStage sse_decode_journal_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return JournalStage.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<JournalEntry> sse_decode_list_journal_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <JournalEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_journal_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<JournalPayment> sse_decode_list_journal_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <JournalPayment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_journal_payment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PenaltySession> sse_decode_list_penalty_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PenaltySession>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_penalty_session(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<SplitShareOutcome> sse_decode_list_split_share_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SplitShareOutcome>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_split_share_outcome(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TlvRecord> sse_decode_list_tlv_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TlvRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_tlv_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WalletTransaction> sse_decode_list_wallet_transaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WalletTransaction>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_wallet_transaction(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WeightedRecipient> sse_decode_list_weighted_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WeightedRecipient>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_weighted_recipient(deserializer)); }
+        return ans_;
+         }
+
+@protected LnurlImage sse_decode_lnurl_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_mimeType = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return LnurlImage(mimeType: var_mimeType, data: var_data); }
+
+@protected LnurlMetadata sse_decode_lnurl_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_description = sse_decode_String(deserializer);
+var var_longDescription = sse_decode_opt_String(deserializer);
+var var_image = sse_decode_opt_box_autoadd_lnurl_image(deserializer);
+var var_identifier = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+return LnurlMetadata(description: var_description, longDescription: var_longDescription, image: var_image, identifier: var_identifier, email: var_email); }
+
+@protected NetworkPolicy sse_decode_network_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relayConnectTimeoutSecs = sse_decode_u_64(deserializer);
+var var_relayQueryTimeoutSecs = sse_decode_u_64(deserializer);
+var var_walletTimeoutSecs = sse_decode_u_64(deserializer);
+var var_paymentTimeoutSecs = sse_decode_u_64(deserializer);
+var var_httpConnectTimeoutSecs = sse_decode_u_64(deserializer);
+var var_httpTimeoutSecs = sse_decode_u_64(deserializer);
+var var_maxRetries = sse_decode_u_32(deserializer);
+var var_retryBackoffMs = sse_decode_u_64(deserializer);
+var var_deadlineSecs = sse_decode_u_64(deserializer);
+return NetworkPolicy(relayConnectTimeoutSecs: var_relayConnectTimeoutSecs, relayQueryTimeoutSecs: var_relayQueryTimeoutSecs, walletTimeoutSecs: var_walletTimeoutSecs, paymentTimeoutSecs: var_paymentTimeoutSecs, httpConnectTimeoutSecs: var_httpConnectTimeoutSecs, httpTimeoutSecs: var_httpTimeoutSecs, maxRetries: var_maxRetries, retryBackoffMs: var_retryBackoffMs, deadlineSecs: var_deadlineSecs); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LnurlImage? sse_decode_opt_box_autoadd_lnurl_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_lnurl_image(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PaymentOutcome? sse_decode_opt_box_autoadd_payment_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_payment_outcome(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PenaltySession? sse_decode_opt_box_autoadd_penalty_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_penalty_session(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_success_action(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TransactionType? sse_decode_opt_box_autoadd_transaction_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_transaction_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ZapClockError? sse_decode_opt_box_autoadd_zap_clock_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_zap_clock_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ZapInfo? sse_decode_opt_box_autoadd_zap_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_zap_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PaymentOutcome sse_decode_payment_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bolt11 = sse_decode_opt_String(deserializer);
+var var_paymentHash = sse_decode_String(deserializer);
+var var_preimage = sse_decode_String(deserializer);
+var var_amountMsats = sse_decode_u_64(deserializer);
+var var_feesPaidMsats = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_recipient = sse_decode_String(deserializer);
+var var_comment = sse_decode_opt_String(deserializer);
+var var_successAction = sse_decode_opt_box_autoadd_success_action(deserializer);
+var var_zap = sse_decode_opt_box_autoadd_zap_info(deserializer);
+var var_startedAt = sse_decode_u_64(deserializer);
+var var_paidAt = sse_decode_u_64(deserializer);
+return PaymentOutcome(bolt11: var_bolt11, paymentHash: var_paymentHash, preimage: var_preimage, amountMsats: var_amountMsats, feesPaidMsats: var_feesPaidMsats, recipient: var_recipient, comment: var_comment, successAction: var_successAction, zap: var_zap, startedAt: var_startedAt, paidAt: var_paidAt); }
+
+@protected PaymentProgress sse_decode_payment_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_address = sse_decode_String(deserializer);
+return PaymentProgress_ResolvingAddress(address: var_address);case 1: var var_amountMsats = sse_decode_u_64(deserializer);
+var var_paymentHash = sse_decode_String(deserializer);
+return PaymentProgress_InvoiceReceived(amountMsats: var_amountMsats, paymentHash: var_paymentHash);case 2: var var_relay = sse_decode_String(deserializer);
+return PaymentProgress_RelayConnected(relay: var_relay);case 3: var var_requestId = sse_decode_String(deserializer);
+return PaymentProgress_RequestPublished(requestId: var_requestId);case 4: return PaymentProgress_ResponseReceived();case 5: var var_paymentHash = sse_decode_String(deserializer);
+return PaymentProgress_Verified(paymentHash: var_paymentHash);case 6: var var_outcome = sse_decode_box_payment_outcome(deserializer);
+return PaymentProgress_Completed(outcome: var_outcome);case 7: var var_error = sse_decode_box_autoadd_zap_clock_error(deserializer);
+return PaymentProgress_Failed(error: var_error); default: throw UnimplementedError(''); }
+             }
+
+@protected PaymentRecipient sse_decode_payment_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_address = sse_decode_String(deserializer);
+return PaymentRecipient_Lnurl(address: var_address);case 1: var var_invoice = sse_decode_String(deserializer);
+return PaymentRecipient_Bolt11(invoice: var_invoice);case 2: var var_pubkey = sse_decode_String(deserializer);
+var var_tlvRecords = sse_decode_list_tlv_record(deserializer);
+var var_preimage = sse_decode_opt_String(deserializer);
+return PaymentRecipient_Keysend(pubkey: var_pubkey, tlvRecords: var_tlvRecords, preimage: var_preimage); default: throw UnimplementedError(''); }
+             }
+
+@protected PenaltySession sse_decode_penalty_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_alarmId = sse_decode_i_32(deserializer);
+var var_scheduledAt = sse_decode_u_64(deserializer);
+var var_state = sse_decode_penalty_state(deserializer);
+var var_recipient = sse_decode_payment_recipient(deserializer);
+var var_amountSats = sse_decode_u_64(deserializer);
+var var_comment = sse_decode_opt_String(deserializer);
+var var_timeoutSecs = sse_decode_u_64(deserializer);
+var var_ringingSince = sse_decode_u_64(deserializer);
+var var_countdownStartedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_outcome = sse_decode_opt_box_autoadd_payment_outcome(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_updatedAt = sse_decode_u_64(deserializer);
+return PenaltySession(alarmId: var_alarmId, scheduledAt: var_scheduledAt, state: var_state, recipient: var_recipient, amountSats: var_amountSats, comment: var_comment, timeoutSecs: var_timeoutSecs, ringingSince: var_ringingSince, countdownStartedAt: var_countdownStartedAt, outcome: var_outcome, error: var_error, updatedAt: var_updatedAt); }
+
+@protected PenaltyState sse_decode_penalty_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PenaltyState.values[inner]; }
+
+@protected ResolvedRecipient sse_decode_resolved_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_endpoint = sse_decode_String(deserializer);
+var var_metadata = sse_decode_lnurl_metadata(deserializer);
+var var_minSendableMsats = sse_decode_u_64(deserializer);
+var var_maxSendableMsats = sse_decode_u_64(deserializer);
+var var_commentAllowed = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_supportsZaps = sse_decode_bool(deserializer);
+var var_nostrPubkey = sse_decode_opt_String(deserializer);
+return ResolvedRecipient(address: var_address, endpoint: var_endpoint, metadata: var_metadata, minSendableMsats: var_minSendableMsats, maxSendableMsats: var_maxSendableMsats, commentAllowed: var_commentAllowed, supportsZaps: var_supportsZaps, nostrPubkey: var_nostrPubkey); }
+
+@protected SplitShareOutcome sse_decode_split_share_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_amountSats = sse_decode_u_64(deserializer);
+var var_outcome = sse_decode_opt_box_autoadd_payment_outcome(deserializer);
+var var_error = sse_decode_opt_box_autoadd_zap_clock_error(deserializer);
+return SplitShareOutcome(address: var_address, amountSats: var_amountSats, outcome: var_outcome, error: var_error); }
+
+@protected SuccessAction sse_decode_success_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_message = sse_decode_String(deserializer);
+return SuccessAction_Message(message: var_message);case 1: var var_description = sse_decode_String(deserializer);
+var var_url = sse_decode_String(deserializer);
+return SuccessAction_Url(description: var_description, url: var_url);case 2: var var_description = sse_decode_String(deserializer);
+var var_plaintext = sse_decode_opt_String(deserializer);
+return SuccessAction_Aes(description: var_description, plaintext: var_plaintext); default: throw UnimplementedError(''); }
+             }
+
+@protected TlvRecord sse_decode_tlv_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_recordType = sse_decode_u_64(deserializer);
+var var_value = sse_decode_String(deserializer);
+return TlvRecord(recordType: var_recordType, value: var_value); }
+
+@protected TransactionFilter sse_decode_transaction_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_from = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_until = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_limit = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_offset = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_unpaid = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_transactionType = sse_decode_opt_box_autoadd_transaction_type(deserializer);
+return TransactionFilter(from: var_from, until: var_until, limit: var_limit, offset: var_offset, unpaid: var_unpaid, transactionType: var_transactionType); }
+
+@protected TransactionType sse_decode_transaction_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TransactionType.values[inner]; }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected WalletErrorCode sse_decode_wallet_error_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WalletErrorCode.values[inner]; }
+
+@protected WalletInfo sse_decode_wallet_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_alias = sse_decode_opt_String(deserializer);
+var var_color = sse_decode_opt_String(deserializer);
+var var_pubkey = sse_decode_opt_String(deserializer);
+var var_network = sse_decode_opt_String(deserializer);
+var var_blockHeight = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_blockHash = sse_decode_opt_String(deserializer);
+var var_methods = sse_decode_list_String(deserializer);
+var var_notifications = sse_decode_list_String(deserializer);
+var var_encryption = sse_decode_list_String(deserializer);
+var var_getInfoSupported = sse_decode_bool(deserializer);
+var var_canPayInvoice = sse_decode_bool(deserializer);
+return WalletInfo(alias: var_alias, color: var_color, pubkey: var_pubkey, network: var_network, blockHeight: var_blockHeight, blockHash: var_blockHash, methods: var_methods, notifications: var_notifications, encryption: var_encryption, getInfoSupported: var_getInfoSupported, canPayInvoice: var_canPayInvoice); }
+
+@protected WalletTransaction sse_decode_wallet_transaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transactionType = sse_decode_opt_box_autoadd_transaction_type(deserializer);
+var var_invoice = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_descriptionHash = sse_decode_opt_String(deserializer);
+var var_preimage = sse_decode_opt_String(deserializer);
+var var_paymentHash = sse_decode_String(deserializer);
+var var_amount = sse_decode_u_64(deserializer);
+var var_feesPaid = sse_decode_u_64(deserializer);
+var var_createdAt = sse_decode_u_64(deserializer);
+var var_expiresAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_settledAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+return WalletTransaction(transactionType: var_transactionType, invoice: var_invoice, description: var_description, descriptionHash: var_descriptionHash, preimage: var_preimage, paymentHash: var_paymentHash, amount: var_amount, feesPaid: var_feesPaid, createdAt: var_createdAt, expiresAt: var_expiresAt, settledAt: var_settledAt); }
+
+@protected WeightedRecipient sse_decode_weighted_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_weight = sse_decode_u_32(deserializer);
+return WeightedRecipient(address: var_address, weight: var_weight); }
+
+@protected ZapClockError sse_decode_zap_clock_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_address = sse_decode_String(deserializer);
+return ZapClockError_InvalidAddress(address: var_address);case 1: var var_url = sse_decode_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_message = sse_decode_String(deserializer);
+return ZapClockError_LnurlHttp(url: var_url, status: var_status, message: var_message);case 2: var var_url = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return ZapClockError_LnurlServer(url: var_url, reason: var_reason);case 3: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidLnurlResponse(message: var_message);case 4: var var_amountSats = sse_decode_u_64(deserializer);
+var var_minSats = sse_decode_u_64(deserializer);
+var var_maxSats = sse_decode_u_64(deserializer);
+return ZapClockError_AmountOutOfRange(amountSats: var_amountSats, minSats: var_minSats, maxSats: var_maxSats);case 5: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidSplit(message: var_message);case 6: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidInvoice(message: var_message);case 7: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidKeysend(message: var_message);case 8: var var_expectedMsats = sse_decode_u_64(deserializer);
+var var_actualMsats = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ZapClockError_InvoiceAmountMismatch(expectedMsats: var_expectedMsats, actualMsats: var_actualMsats);case 9: var var_expected = sse_decode_String(deserializer);
+var var_actual = sse_decode_String(deserializer);
+return ZapClockError_InvoiceNetworkMismatch(expected: var_expected, actual: var_actual);case 10: var var_expiredAt = sse_decode_u_64(deserializer);
+return ZapClockError_InvoiceExpired(expiredAt: var_expiredAt);case 11: var var_expected = sse_decode_String(deserializer);
+var var_actual = sse_decode_opt_String(deserializer);
+return ZapClockError_InvoiceDescriptionHashMismatch(expected: var_expected, actual: var_actual);case 12: var var_paymentHash = sse_decode_String(deserializer);
+var var_preimage = sse_decode_String(deserializer);
+return ZapClockError_PreimageMismatch(paymentHash: var_paymentHash, preimage: var_preimage);case 13: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidConnectionString(message: var_message);case 14: var var_seconds = sse_decode_u_64(deserializer);
+return ZapClockError_NwcTimeout(seconds: var_seconds);case 15: var var_message = sse_decode_String(deserializer);
+return ZapClockError_PaymentUnknown(message: var_message);case 16: var var_code = sse_decode_wallet_error_code(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_retryable = sse_decode_bool(deserializer);
+return ZapClockError_Wallet(code: var_code, message: var_message, retryable: var_retryable);case 17: var var_seconds = sse_decode_u_64(deserializer);
+return ZapClockError_DeadlineExceeded(seconds: var_seconds);case 18: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidNetworkPolicy(message: var_message);case 19: var var_message = sse_decode_String(deserializer);
+return ZapClockError_Relay(message: var_message);case 20: return ZapClockError_Cancelled();case 21: return ZapClockError_PaymentCommitted();case 22: var var_state = sse_decode_String(deserializer);
+var var_action = sse_decode_String(deserializer);
+return ZapClockError_InvalidPenaltyTransition(state: var_state, action: var_action);case 23: var var_alarmId = sse_decode_i_32(deserializer);
+return ZapClockError_PenaltyNotFound(alarmId: var_alarmId);case 24: var var_message = sse_decode_String(deserializer);
+return ZapClockError_Storage(message: var_message);case 25: var var_message = sse_decode_String(deserializer);
+return ZapClockError_InvalidResponse(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected ZapInfo sse_decode_zap_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_requestId = sse_decode_String(deserializer);
+var var_requestJson = sse_decode_String(deserializer);
+var var_serverPubkey = sse_decode_String(deserializer);
+var var_receipt = sse_decode_zap_receipt_status(deserializer);
+return ZapInfo(requestId: var_requestId, requestJson: var_requestJson, serverPubkey: var_serverPubkey, receipt: var_receipt); }
+
+@protected ZapReceiptStatus sse_decode_zap_receipt_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ZapReceiptStatus_Pending();case 1: var var_receiptId = sse_decode_String(deserializer);
+return ZapReceiptStatus_Observed(receiptId: var_receiptId);case 2: return ZapReceiptStatus_NotObserved(); default: throw UnimplementedError(''); }
+             }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(NwcSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NwcSessionImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(PaymentCancel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PaymentCancelImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(NwcSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NwcSessionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(PaymentCancel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PaymentCancelImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(NwcSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NwcSessionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(PaymentCancel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PaymentCancelImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_payment_progress_Sse(RustStreamSink<PaymentProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_payment_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_invoice_lookup(InvoiceLookup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_invoice_lookup(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lnurl_image(LnurlImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lnurl_image(self, serializer); }
+
+@protected void sse_encode_box_autoadd_network_policy(NetworkPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_network_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_payment_outcome(PaymentOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_payment_outcome(self, serializer); }
+
+@protected void sse_encode_box_autoadd_payment_recipient(PaymentRecipient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_payment_recipient(self, serializer); }
+
+@protected void sse_encode_box_autoadd_penalty_session(PenaltySession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_penalty_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_success_action(SuccessAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_success_action(self, serializer); }
+
+@protected void sse_encode_box_autoadd_transaction_filter(TransactionFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_transaction_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_transaction_type(TransactionType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_transaction_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_zap_clock_error(ZapClockError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_zap_clock_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_zap_info(ZapInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_zap_info(self, serializer); }
+
+@protected void sse_encode_box_payment_outcome(PaymentOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_payment_outcome(self, serializer); }
+
+@protected void sse_encode_cancel_outcome(CancelOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_invoice_lookup(InvoiceLookup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case InvoiceLookup_PaymentHash(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case InvoiceLookup_Bolt11(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_journal_entry(JournalEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.paymentKey, serializer);
+sse_encode_journal_stage(self.stage, serializer);
+sse_encode_u_64(self.at, serializer);
+sse_encode_opt_String(self.paymentHash, serializer);
+sse_encode_opt_box_autoadd_u_64(self.amountMsats, serializer);
+sse_encode_opt_String(self.detail, serializer);
+ }
+
+@protected void sse_encode_journal_payment(JournalPayment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.paymentKey, serializer);
+sse_encode_journal_payment_state(self.state, serializer);
+sse_encode_journal_stage(self.lastStage, serializer);
+sse_encode_opt_String(self.paymentHash, serializer);
+sse_encode_opt_box_autoadd_u_64(self.amountMsats, serializer);
+sse_encode_opt_String(self.recipient, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_u_64(self.startedAt, serializer);
+sse_encode_u_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_journal_payment_state(JournalPaymentState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_journal_stage(JournalStage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_journal_entry(List<JournalEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_journal_entry(item, serializer); } }
+
+@protected void sse_encode_list_journal_payment(List<JournalPayment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_journal_payment(item, serializer); } }
+
+@protected void sse_encode_list_penalty_session(List<PenaltySession> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_penalty_session(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_split_share_outcome(List<SplitShareOutcome> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_split_share_outcome(item, serializer); } }
+
+@protected void sse_encode_list_tlv_record(List<TlvRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_tlv_record(item, serializer); } }
+
+@protected void sse_encode_list_wallet_transaction(List<WalletTransaction> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_wallet_transaction(item, serializer); } }
+
+@protected void sse_encode_list_weighted_recipient(List<WeightedRecipient> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_weighted_recipient(item, serializer); } }
+
+@protected void sse_encode_lnurl_image(LnurlImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.mimeType, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+ }
+
+@protected void sse_encode_lnurl_metadata(LnurlMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.description, serializer);
+sse_encode_opt_String(self.longDescription, serializer);
+sse_encode_opt_box_autoadd_lnurl_image(self.image, serializer);
+sse_encode_opt_String(self.identifier, serializer);
+sse_encode_opt_String(self.email, serializer);
+ }
+
+@protected void sse_encode_network_policy(NetworkPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.relayConnectTimeoutSecs, serializer);
+sse_encode_u_64(self.relayQueryTimeoutSecs, serializer);
+sse_encode_u_64(self.walletTimeoutSecs, serializer);
+sse_encode_u_64(self.paymentTimeoutSecs, serializer);
+sse_encode_u_64(self.httpConnectTimeoutSecs, serializer);
+sse_encode_u_64(self.httpTimeoutSecs, serializer);
+sse_encode_u_32(self.maxRetries, serializer);
+sse_encode_u_64(self.retryBackoffMs, serializer);
+sse_encode_u_64(self.deadlineSecs, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_lnurl_image(LnurlImage? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_lnurl_image(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_payment_outcome(PaymentOutcome? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_payment_outcome(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_penalty_session(PenaltySession? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_penalty_session(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_success_action(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_transaction_type(TransactionType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_transaction_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_zap_clock_error(ZapClockError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_zap_clock_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_zap_info(ZapInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_zap_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_payment_outcome(PaymentOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.bolt11, serializer);
+sse_encode_String(self.paymentHash, serializer);
+sse_encode_String(self.preimage, serializer);
+sse_encode_u_64(self.amountMsats, serializer);
+sse_encode_opt_box_autoadd_u_64(self.feesPaidMsats, serializer);
+sse_encode_String(self.recipient, serializer);
+sse_encode_opt_String(self.comment, serializer);
+sse_encode_opt_box_autoadd_success_action(self.successAction, serializer);
+sse_encode_opt_box_autoadd_zap_info(self.zap, serializer);
+sse_encode_u_64(self.startedAt, serializer);
+sse_encode_u_64(self.paidAt, serializer);
+ }
+
+@protected void sse_encode_payment_progress(PaymentProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PaymentProgress_ResolvingAddress(address: final address): sse_encode_i_32(0, serializer); sse_encode_String(address, serializer);
+case PaymentProgress_InvoiceReceived(amountMsats: final amountMsats,paymentHash: final paymentHash): sse_encode_i_32(1, serializer); sse_encode_u_64(amountMsats, serializer);
+sse_encode_String(paymentHash, serializer);
+case PaymentProgress_RelayConnected(relay: final relay): sse_encode_i_32(2, serializer); sse_encode_String(relay, serializer);
+case PaymentProgress_RequestPublished(requestId: final requestId): sse_encode_i_32(3, serializer); sse_encode_String(requestId, serializer);
+case PaymentProgress_ResponseReceived(): sse_encode_i_32(4, serializer); case PaymentProgress_Verified(paymentHash: final paymentHash): sse_encode_i_32(5, serializer); sse_encode_String(paymentHash, serializer);
+case PaymentProgress_Completed(outcome: final outcome): sse_encode_i_32(6, serializer); sse_encode_box_payment_outcome(outcome, serializer);
+case PaymentProgress_Failed(error: final error): sse_encode_i_32(7, serializer); sse_encode_box_autoadd_zap_clock_error(error, serializer);
+  } }
+
+@protected void sse_encode_payment_recipient(PaymentRecipient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PaymentRecipient_Lnurl(address: final address): sse_encode_i_32(0, serializer); sse_encode_String(address, serializer);
+case PaymentRecipient_Bolt11(invoice: final invoice): sse_encode_i_32(1, serializer); sse_encode_String(invoice, serializer);
+case PaymentRecipient_Keysend(pubkey: final pubkey,tlvRecords: final tlvRecords,preimage: final preimage): sse_encode_i_32(2, serializer); sse_encode_String(pubkey, serializer);
+sse_encode_list_tlv_record(tlvRecords, serializer);
+sse_encode_opt_String(preimage, serializer);
+  } }
+
+@protected void sse_encode_penalty_session(PenaltySession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.alarmId, serializer);
+sse_encode_u_64(self.scheduledAt, serializer);
+sse_encode_penalty_state(self.state, serializer);
+sse_encode_payment_recipient(self.recipient, serializer);
+sse_encode_u_64(self.amountSats, serializer);
+sse_encode_opt_String(self.comment, serializer);
+sse_encode_u_64(self.timeoutSecs, serializer);
+sse_encode_u_64(self.ringingSince, serializer);
+sse_encode_opt_box_autoadd_u_64(self.countdownStartedAt, serializer);
+sse_encode_opt_box_autoadd_payment_outcome(self.outcome, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_u_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_penalty_state(PenaltyState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_resolved_recipient(ResolvedRecipient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_String(self.endpoint, serializer);
+sse_encode_lnurl_metadata(self.metadata, serializer);
+sse_encode_u_64(self.minSendableMsats, serializer);
+sse_encode_u_64(self.maxSendableMsats, serializer);
+sse_encode_opt_box_autoadd_u_64(self.commentAllowed, serializer);
+sse_encode_bool(self.supportsZaps, serializer);
+sse_encode_opt_String(self.nostrPubkey, serializer);
+ }
+
+@protected void sse_encode_split_share_outcome(SplitShareOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_u_64(self.amountSats, serializer);
+sse_encode_opt_box_autoadd_payment_outcome(self.outcome, serializer);
+sse_encode_opt_box_autoadd_zap_clock_error(self.error, serializer);
+ }
+
+@protected void sse_encode_success_action(SuccessAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SuccessAction_Message(message: final message): sse_encode_i_32(0, serializer); sse_encode_String(message, serializer);
+case SuccessAction_Url(description: final description,url: final url): sse_encode_i_32(1, serializer); sse_encode_String(description, serializer);
+sse_encode_String(url, serializer);
+case SuccessAction_Aes(description: final description,plaintext: final plaintext): sse_encode_i_32(2, serializer); sse_encode_String(description, serializer);
+sse_encode_opt_String(plaintext, serializer);
+  } }
+
+@protected void sse_encode_tlv_record(TlvRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.recordType, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_transaction_filter(TransactionFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.from, serializer);
+sse_encode_opt_box_autoadd_u_64(self.until, serializer);
+sse_encode_opt_box_autoadd_u_64(self.limit, serializer);
+sse_encode_opt_box_autoadd_u_64(self.offset, serializer);
+sse_encode_opt_box_autoadd_bool(self.unpaid, serializer);
+sse_encode_opt_box_autoadd_transaction_type(self.transactionType, serializer);
+ }
+
+@protected void sse_encode_transaction_type(TransactionType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_wallet_error_code(WalletErrorCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.alias, serializer);
+sse_encode_opt_String(self.color, serializer);
+sse_encode_opt_String(self.pubkey, serializer);
+sse_encode_opt_String(self.network, serializer);
+sse_encode_opt_box_autoadd_u_32(self.blockHeight, serializer);
+sse_encode_opt_String(self.blockHash, serializer);
+sse_encode_list_String(self.methods, serializer);
+sse_encode_list_String(self.notifications, serializer);
+sse_encode_list_String(self.encryption, serializer);
+sse_encode_bool(self.getInfoSupported, serializer);
+sse_encode_bool(self.canPayInvoice, serializer);
+ }
+
+@protected void sse_encode_wallet_transaction(WalletTransaction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_transaction_type(self.transactionType, serializer);
+sse_encode_opt_String(self.invoice, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_String(self.descriptionHash, serializer);
+sse_encode_opt_String(self.preimage, serializer);
+sse_encode_String(self.paymentHash, serializer);
+sse_encode_u_64(self.amount, serializer);
+sse_encode_u_64(self.feesPaid, serializer);
+sse_encode_u_64(self.createdAt, serializer);
+sse_encode_opt_box_autoadd_u_64(self.expiresAt, serializer);
+sse_encode_opt_box_autoadd_u_64(self.settledAt, serializer);
+ }
+
+@protected void sse_encode_weighted_recipient(WeightedRecipient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_u_32(self.weight, serializer);
+ }
+
+@protected void sse_encode_zap_clock_error(ZapClockError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ZapClockError_InvalidAddress(address: final address): sse_encode_i_32(0, serializer); sse_encode_String(address, serializer);
+case ZapClockError_LnurlHttp(url: final url,status: final status,message: final message): sse_encode_i_32(1, serializer); sse_encode_String(url, serializer);
+sse_encode_opt_box_autoadd_u_16(status, serializer);
+sse_encode_String(message, serializer);
+case ZapClockError_LnurlServer(url: final url,reason: final reason): sse_encode_i_32(2, serializer); sse_encode_String(url, serializer);
+sse_encode_String(reason, serializer);
+case ZapClockError_InvalidLnurlResponse(message: final message): sse_encode_i_32(3, serializer); sse_encode_String(message, serializer);
+case ZapClockError_AmountOutOfRange(amountSats: final amountSats,minSats: final minSats,maxSats: final maxSats): sse_encode_i_32(4, serializer); sse_encode_u_64(amountSats, serializer);
+sse_encode_u_64(minSats, serializer);
+sse_encode_u_64(maxSats, serializer);
+case ZapClockError_InvalidSplit(message: final message): sse_encode_i_32(5, serializer); sse_encode_String(message, serializer);
+case ZapClockError_InvalidInvoice(message: final message): sse_encode_i_32(6, serializer); sse_encode_String(message, serializer);
+case ZapClockError_InvalidKeysend(message: final message): sse_encode_i_32(7, serializer); sse_encode_String(message, serializer);
+case ZapClockError_InvoiceAmountMismatch(expectedMsats: final expectedMsats,actualMsats: final actualMsats): sse_encode_i_32(8, serializer); sse_encode_u_64(expectedMsats, serializer);
+sse_encode_opt_box_autoadd_u_64(actualMsats, serializer);
+case ZapClockError_InvoiceNetworkMismatch(expected: final expected,actual: final actual): sse_encode_i_32(9, serializer); sse_encode_String(expected, serializer);
+sse_encode_String(actual, serializer);
+case ZapClockError_InvoiceExpired(expiredAt: final expiredAt): sse_encode_i_32(10, serializer); sse_encode_u_64(expiredAt, serializer);
+case ZapClockError_InvoiceDescriptionHashMismatch(expected: final expected,actual: final actual): sse_encode_i_32(11, serializer); sse_encode_String(expected, serializer);
+sse_encode_opt_String(actual, serializer);
+case ZapClockError_PreimageMismatch(paymentHash: final paymentHash,preimage: final preimage): sse_encode_i_32(12, serializer); sse_encode_String(paymentHash, serializer);
+sse_encode_String(preimage, serializer);
+case ZapClockError_InvalidConnectionString(message: final message): sse_encode_i_32(13, serializer); sse_encode_String(message, serializer);
+case ZapClockError_NwcTimeout(seconds: final seconds): sse_encode_i_32(14, serializer); sse_encode_u_64(seconds, serializer);
+case ZapClockError_PaymentUnknown(message: final message): sse_encode_i_32(15, serializer); sse_encode_String(message, serializer);
+case ZapClockError_Wallet(code: final code,message: final message,retryable: final retryable): sse_encode_i_32(16, serializer); sse_encode_wallet_error_code(code, serializer);
+sse_encode_String(message, serializer);
+sse_encode_bool(retryable, serializer);
+case ZapClockError_DeadlineExceeded(seconds: final seconds): sse_encode_i_32(17, serializer); sse_encode_u_64(seconds, serializer);
+case ZapClockError_InvalidNetworkPolicy(message: final message): sse_encode_i_32(18, serializer); sse_encode_String(message, serializer);
+case ZapClockError_Relay(message: final message): sse_encode_i_32(19, serializer); sse_encode_String(message, serializer);
+case ZapClockError_Cancelled(): sse_encode_i_32(20, serializer); case ZapClockError_PaymentCommitted(): sse_encode_i_32(21, serializer); case ZapClockError_InvalidPenaltyTransition(state: final state,action: final action): sse_encode_i_32(22, serializer); sse_encode_String(state, serializer);
+sse_encode_String(action, serializer);
+case ZapClockError_PenaltyNotFound(alarmId: final alarmId): sse_encode_i_32(23, serializer); sse_encode_i_32(alarmId, serializer);
+case ZapClockError_Storage(message: final message): sse_encode_i_32(24, serializer); sse_encode_String(message, serializer);
+case ZapClockError_InvalidResponse(message: final message): sse_encode_i_32(25, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_zap_info(ZapInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.requestId, serializer);
+sse_encode_String(self.requestJson, serializer);
+sse_encode_String(self.serverPubkey, serializer);
+sse_encode_zap_receipt_status(self.receipt, serializer);
+ }
+
+@protected void sse_encode_zap_receipt_status(ZapReceiptStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ZapReceiptStatus_Pending(): sse_encode_i_32(0, serializer); case ZapReceiptStatus_Observed(receiptId: final receiptId): sse_encode_i_32(1, serializer); sse_encode_String(receiptId, serializer);
+case ZapReceiptStatus_NotObserved(): sse_encode_i_32(2, serializer);   } }
+                }
+                
+
+            @sealed class NwcSessionImpl extends RustOpaque implements NwcSession {
+                // Not to be used by end users
+                NwcSessionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                NwcSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_NwcSession,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_NwcSession,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_NwcSessionPtr,
+                );
+
+                
+            }
+            @sealed class PaymentCancelImpl extends RustOpaque implements PaymentCancel {
+                // Not to be used by end users
+                PaymentCancelImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PaymentCancelImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PaymentCancel,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PaymentCancel,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PaymentCancelPtr,
+                );
+
+                
+            }
\ No newline at end of file
@@ -3,90 +3,554 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
 import 'api.dart';
+import 'cancel.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'error.dart';
 import 'frb_generated.dart';
+import 'journal.dart';
+import 'lightning.dart';
+import 'network.dart';
+import 'nwc.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'payment.dart';
+import 'penalty.dart';
+import 'progress.dart';
+import 'zap.dart';
+
+
+
+
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NwcSessionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PaymentCancelPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected NwcSession dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(dynamic raw);
+
+@protected PaymentCancel dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(dynamic raw);
+
+@protected NwcSession dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(dynamic raw);
+
+@protected PaymentCancel dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(dynamic raw);
+
+@protected NwcSession dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(dynamic raw);
+
+@protected PaymentCancel dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(dynamic raw);
+
+@protected RustStreamSink<PaymentProgress> dco_decode_StreamSink_payment_progress_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected InvoiceLookup dco_decode_box_autoadd_invoice_lookup(dynamic raw);
+
+@protected LnurlImage dco_decode_box_autoadd_lnurl_image(dynamic raw);
+
+@protected NetworkPolicy dco_decode_box_autoadd_network_policy(dynamic raw);
+
+@protected PaymentOutcome dco_decode_box_autoadd_payment_outcome(dynamic raw);
+
+@protected PaymentRecipient dco_decode_box_autoadd_payment_recipient(dynamic raw);
+
+@protected PenaltySession dco_decode_box_autoadd_penalty_session(dynamic raw);
+
+@protected SuccessAction dco_decode_box_autoadd_success_action(dynamic raw);
+
+@protected TransactionFilter dco_decode_box_autoadd_transaction_filter(dynamic raw);
+
+@protected TransactionType dco_decode_box_autoadd_transaction_type(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected ZapClockError dco_decode_box_autoadd_zap_clock_error(dynamic raw);
+
+@protected ZapInfo dco_decode_box_autoadd_zap_info(dynamic raw);
+
+@protected PaymentOutcome dco_decode_box_payment_outcome(dynamic raw);
+
+@protected CancelOutcome dco_decode_cancel_outcome(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected InvoiceLookup dco_decode_invoice_lookup(dynamic raw);
+
+@protected JournalEntry dco_decode_journal_entry(dynamic raw);
+
+@protected JournalPayment dco_decode_journal_payment(dynamic raw);
+
+@protected JournalPaymentState dco_decode_journal_payment_state(dynamic raw);
+
+@protected JournalStage dco_decode_journal_stage(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<JournalEntry> dco_decode_list_journal_entry(dynamic raw);
+
+@protected List<JournalPayment> dco_decode_list_journal_payment(dynamic raw);
+
+@protected List<PenaltySession> dco_decode_list_penalty_session(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<SplitShareOutcome> dco_decode_list_split_share_outcome(dynamic raw);
+
+@protected List<TlvRecord> dco_decode_list_tlv_record(dynamic raw);
+
+@protected List<WalletTransaction> dco_decode_list_wallet_transaction(dynamic raw);
+
+@protected List<WeightedRecipient> dco_decode_list_weighted_recipient(dynamic raw);
+
+@protected LnurlImage dco_decode_lnurl_image(dynamic raw);
+
+@protected LnurlMetadata dco_decode_lnurl_metadata(dynamic raw);
+
+@protected NetworkPolicy dco_decode_network_policy(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected LnurlImage? dco_decode_opt_box_autoadd_lnurl_image(dynamic raw);
+
+@protected PaymentOutcome? dco_decode_opt_box_autoadd_payment_outcome(dynamic raw);
+
+@protected PenaltySession? dco_decode_opt_box_autoadd_penalty_session(dynamic raw);
+
+@protected SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw);
+
+@protected TransactionType? dco_decode_opt_box_autoadd_transaction_type(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected ZapClockError? dco_decode_opt_box_autoadd_zap_clock_error(dynamic raw);
+
+@protected ZapInfo? dco_decode_opt_box_autoadd_zap_info(dynamic raw);
+
+@protected PaymentOutcome dco_decode_payment_outcome(dynamic raw);
+
+@protected PaymentProgress dco_decode_payment_progress(dynamic raw);
+
+@protected PaymentRecipient dco_decode_payment_recipient(dynamic raw);
+
+@protected PenaltySession dco_decode_penalty_session(dynamic raw);
+
+@protected PenaltyState dco_decode_penalty_state(dynamic raw);
+
+@protected ResolvedRecipient dco_decode_resolved_recipient(dynamic raw);
+
+@protected SplitShareOutcome dco_decode_split_share_outcome(dynamic raw);
+
+@protected SuccessAction dco_decode_success_action(dynamic raw);
+
+@protected TlvRecord dco_decode_tlv_record(dynamic raw);
+
+@protected TransactionFilter dco_decode_transaction_filter(dynamic raw);
+
+@protected TransactionType dco_decode_transaction_type(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected WalletErrorCode dco_decode_wallet_error_code(dynamic raw);
+
+@protected WalletInfo dco_decode_wallet_info(dynamic raw);
+
+@protected WalletTransaction dco_decode_wallet_transaction(dynamic raw);
+
+@protected WeightedRecipient dco_decode_weighted_recipient(dynamic raw);
+
+@protected ZapClockError dco_decode_zap_clock_error(dynamic raw);
+
+@protected ZapInfo dco_decode_zap_info(dynamic raw);
+
+@protected ZapReceiptStatus dco_decode_zap_receipt_status(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected NwcSession sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(SseDeserializer deserializer);
+
+@protected PaymentCancel sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(SseDeserializer deserializer);
+
+@protected NwcSession sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(SseDeserializer deserializer);
+
+@protected PaymentCancel sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(SseDeserializer deserializer);
+
+@protected NwcSession sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(SseDeserializer deserializer);
+
+@protected PaymentCancel sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(SseDeserializer deserializer);
+
+@protected RustStreamSink<PaymentProgress> sse_decode_StreamSink_payment_progress_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected InvoiceLookup sse_decode_box_autoadd_invoice_lookup(SseDeserializer deserializer);
+
+@protected LnurlImage sse_decode_box_autoadd_lnurl_image(SseDeserializer deserializer);
+
+@protected NetworkPolicy sse_decode_box_autoadd_network_policy(SseDeserializer deserializer);
+
+@protected PaymentOutcome sse_decode_box_autoadd_payment_outcome(SseDeserializer deserializer);
+
+@protected PaymentRecipient sse_decode_box_autoadd_payment_recipient(SseDeserializer deserializer);
+
+@protected PenaltySession sse_decode_box_autoadd_penalty_session(SseDeserializer deserializer);
+
+@protected SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer);
+
+@protected TransactionFilter sse_decode_box_autoadd_transaction_filter(SseDeserializer deserializer);
+
+@protected TransactionType sse_decode_box_autoadd_transaction_type(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected ZapClockError sse_decode_box_autoadd_zap_clock_error(SseDeserializer deserializer);
+
+@protected ZapInfo sse_decode_box_autoadd_zap_info(SseDeserializer deserializer);
+
+@protected PaymentOutcome sse_decode_box_payment_outcome(SseDeserializer deserializer);
+
+@protected CancelOutcome sse_decode_cancel_outcome(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected InvoiceLookup sse_decode_invoice_lookup(SseDeserializer deserializer);
+
+@protected JournalEntry sse_decode_journal_entry(SseDeserializer deserializer);
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+@protected JournalPayment sse_decode_journal_payment(SseDeserializer deserializer);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected JournalPaymentState sse_decode_journal_payment_state(SseDeserializer deserializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected JournalStage sse_decode_journal_stage(SseDeserializer deserializer);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected List<JournalEntry> sse_decode_list_journal_entry(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected List<JournalPayment> sse_decode_list_journal_payment(SseDeserializer deserializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected List<PenaltySession> sse_decode_list_penalty_session(SseDeserializer deserializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected List<SplitShareOutcome> sse_decode_list_split_share_outcome(SseDeserializer deserializer);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected List<TlvRecord> sse_decode_list_tlv_record(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<WalletTransaction> sse_decode_list_wallet_transaction(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected List<WeightedRecipient> sse_decode_list_weighted_recipient(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected LnurlImage sse_decode_lnurl_image(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected LnurlMetadata sse_decode_lnurl_metadata(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+@protected NetworkPolicy sse_decode_network_policy(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected LnurlImage? sse_decode_opt_box_autoadd_lnurl_image(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected PaymentOutcome? sse_decode_opt_box_autoadd_payment_outcome(SseDeserializer deserializer);
+
+@protected PenaltySession? sse_decode_opt_box_autoadd_penalty_session(SseDeserializer deserializer);
+
+@protected SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer);
+
+@protected TransactionType? sse_decode_opt_box_autoadd_transaction_type(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected ZapClockError? sse_decode_opt_box_autoadd_zap_clock_error(SseDeserializer deserializer);
+
+@protected ZapInfo? sse_decode_opt_box_autoadd_zap_info(SseDeserializer deserializer);
+
+@protected PaymentOutcome sse_decode_payment_outcome(SseDeserializer deserializer);
+
+@protected PaymentProgress sse_decode_payment_progress(SseDeserializer deserializer);
+
+@protected PaymentRecipient sse_decode_payment_recipient(SseDeserializer deserializer);
+
+@protected PenaltySession sse_decode_penalty_session(SseDeserializer deserializer);
+
+@protected PenaltyState sse_decode_penalty_state(SseDeserializer deserializer);
+
+@protected ResolvedRecipient sse_decode_resolved_recipient(SseDeserializer deserializer);
+
+@protected SplitShareOutcome sse_decode_split_share_outcome(SseDeserializer deserializer);
+
+@protected SuccessAction sse_decode_success_action(SseDeserializer deserializer);
+
+@protected TlvRecord sse_decode_tlv_record(SseDeserializer deserializer);
+
+@protected TransactionFilter sse_decode_transaction_filter(SseDeserializer deserializer);
+
+@protected TransactionType sse_decode_transaction_type(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected WalletErrorCode sse_decode_wallet_error_code(SseDeserializer deserializer);
+
+@protected WalletInfo sse_decode_wallet_info(SseDeserializer deserializer);
+
+@protected WalletTransaction sse_decode_wallet_transaction(SseDeserializer deserializer);
+
+@protected WeightedRecipient sse_decode_weighted_recipient(SseDeserializer deserializer);
+
+@protected ZapClockError sse_decode_zap_clock_error(SseDeserializer deserializer);
+
+@protected ZapInfo sse_decode_zap_info(SseDeserializer deserializer);
+
+@protected ZapReceiptStatus sse_decode_zap_receipt_status(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(NwcSession self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(PaymentCancel self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(NwcSession self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(PaymentCancel self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(NwcSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(PaymentCancel self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_payment_progress_Sse(RustStreamSink<PaymentProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_invoice_lookup(InvoiceLookup self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lnurl_image(LnurlImage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_network_policy(NetworkPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_payment_outcome(PaymentOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_payment_recipient(PaymentRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_penalty_session(PenaltySession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_success_action(SuccessAction self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_transaction_filter(TransactionFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_transaction_type(TransactionType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_zap_clock_error(ZapClockError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_zap_info(ZapInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_payment_outcome(PaymentOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_cancel_outcome(CancelOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_invoice_lookup(InvoiceLookup self, SseSerializer serializer);
+
+@protected void sse_encode_journal_entry(JournalEntry self, SseSerializer serializer);
+
+@protected void sse_encode_journal_payment(JournalPayment self, SseSerializer serializer);
+
+@protected void sse_encode_journal_payment_state(JournalPaymentState self, SseSerializer serializer);
+
+@protected void sse_encode_journal_stage(JournalStage self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_journal_entry(List<JournalEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_journal_payment(List<JournalPayment> self, SseSerializer serializer);
+
+@protected void sse_encode_list_penalty_session(List<PenaltySession> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_split_share_outcome(List<SplitShareOutcome> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tlv_record(List<TlvRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_wallet_transaction(List<WalletTransaction> self, SseSerializer serializer);
+
+@protected void sse_encode_list_weighted_recipient(List<WeightedRecipient> self, SseSerializer serializer);
+
+@protected void sse_encode_lnurl_image(LnurlImage self, SseSerializer serializer);
+
+@protected void sse_encode_lnurl_metadata(LnurlMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_network_policy(NetworkPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_lnurl_image(LnurlImage? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_payment_outcome(PaymentOutcome? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_penalty_session(PenaltySession? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_transaction_type(TransactionType? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_zap_clock_error(ZapClockError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_zap_info(ZapInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_payment_outcome(PaymentOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_payment_progress(PaymentProgress self, SseSerializer serializer);
+
+@protected void sse_encode_payment_recipient(PaymentRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_penalty_session(PenaltySession self, SseSerializer serializer);
+
+@protected void sse_encode_penalty_state(PenaltyState self, SseSerializer serializer);
+
+@protected void sse_encode_resolved_recipient(ResolvedRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_split_share_outcome(SplitShareOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_success_action(SuccessAction self, SseSerializer serializer);
+
+@protected void sse_encode_tlv_record(TlvRecord self, SseSerializer serializer);
+
+@protected void sse_encode_transaction_filter(TransactionFilter self, SseSerializer serializer);
+
+@protected void sse_encode_transaction_type(TransactionType self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_wallet_error_code(WalletErrorCode self, SseSerializer serializer);
+
+@protected void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer);
+
+@protected void sse_encode_wallet_transaction(WalletTransaction self, SseSerializer serializer);
+
+@protected void sse_encode_weighted_recipient(WeightedRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_zap_clock_error(ZapClockError self, SseSerializer serializer);
+
+@protected void sse_encode_zap_info(ZapInfo self, SseSerializer serializer);
+
+@protected void sse_encode_zap_receipt_status(ZapReceiptStatus self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(int ptr);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(int ptr);
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(int ptr);
+        }
+        
\ No newline at end of file
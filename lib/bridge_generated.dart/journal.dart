@@ -0,0 +1,112 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One line of the journal
+class JournalEntry  {
+                /// Idempotency key of the payment (alarm id + scheduled fire time)
+final String paymentKey;
+final JournalStage stage;
+/// Unix timestamp of the stage
+final BigInt at;
+/// Hex-encoded payment hash, once known
+final String? paymentHash;
+final BigInt? amountMsats;
+/// Recipient, error message or other detail of the stage
+final String? detail;
+
+                const JournalEntry({required this.paymentKey ,required this.stage ,required this.at ,this.paymentHash ,this.amountMsats ,this.detail ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => paymentKey.hashCode^stage.hashCode^at.hashCode^paymentHash.hashCode^amountMsats.hashCode^detail.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JournalEntry &&
+                runtimeType == other.runtimeType
+                && paymentKey == other.paymentKey&& stage == other.stage&& at == other.at&& paymentHash == other.paymentHash&& amountMsats == other.amountMsats&& detail == other.detail;
+        
+            }
+
+/// Latest attempt of one payment, summarized from its journal entries
+class JournalPayment  {
+                final String paymentKey;
+final JournalPaymentState state;
+final JournalStage lastStage;
+final String? paymentHash;
+final BigInt? amountMsats;
+/// Recipient given when the attempt started
+final String? recipient;
+/// Error of a failed attempt
+final String? error;
+final BigInt startedAt;
+final BigInt updatedAt;
+
+                const JournalPayment({required this.paymentKey ,required this.state ,required this.lastStage ,this.paymentHash ,this.amountMsats ,this.recipient ,this.error ,required this.startedAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => paymentKey.hashCode^state.hashCode^lastStage.hashCode^paymentHash.hashCode^amountMsats.hashCode^recipient.hashCode^error.hashCode^startedAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JournalPayment &&
+                runtimeType == other.runtimeType
+                && paymentKey == other.paymentKey&& state == other.state&& lastStage == other.lastStage&& paymentHash == other.paymentHash&& amountMsats == other.amountMsats&& recipient == other.recipient&& error == other.error&& startedAt == other.startedAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Where a payment stands according to the journal
+enum JournalPaymentState {
+                    /// Request not published yet (or the app stopped before publishing)
+notSent,
+/// Request published but no verified answer: the payment may or may not have gone out
+unknown,
+paid,
+failed,
+                    ;
+                    
+                }
+
+/// Stage a payment reached
+enum JournalStage {
+                    /// Payment attempt started (or restarted with a new invoice)
+started,
+/// LNURL-pay parameters read from the recipient
+addressResolved,
+/// Invoice obtained and validated
+invoiceFetched,
+/// NIP-47 request published; the payment may go out from here on
+requestSent,
+/// Wallet answered the request
+responseReceived,
+/// Preimage matches the payment hash: the payment is proven
+preimageVerified,
+/// Payment definitely did not go out
+failed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
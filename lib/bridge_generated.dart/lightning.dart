@@ -0,0 +1,130 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'lightning.freezed.dart';
+
+            
+
+            
+
+            /// Avatar image embedded in LNURL metadata
+class LnurlImage  {
+                /// `image/png` or `image/jpeg`
+final String mimeType;
+/// Decoded image bytes
+final Uint8List data;
+
+                const LnurlImage({required this.mimeType ,required this.data ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => mimeType.hashCode^data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LnurlImage &&
+                runtimeType == other.runtimeType
+                && mimeType == other.mimeType&& data == other.data;
+        
+            }
+
+/// Parsed LUD-06 metadata of a recipient
+class LnurlMetadata  {
+                /// Short description (`text/plain`)
+final String description;
+/// Long description (`text/long-desc`)
+final String? longDescription;
+/// Avatar (`image/png;base64` or `image/jpeg;base64`)
+final LnurlImage? image;
+/// Internet identifier such as a Lightning address (`text/identifier`)
+final String? identifier;
+/// Contact email (`text/email`)
+final String? email;
+
+                const LnurlMetadata({required this.description ,this.longDescription ,this.image ,this.identifier ,this.email ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => description.hashCode^longDescription.hashCode^image.hashCode^identifier.hashCode^email.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LnurlMetadata &&
+                runtimeType == other.runtimeType
+                && description == other.description&& longDescription == other.longDescription&& image == other.image&& identifier == other.identifier&& email == other.email;
+        
+            }
+
+/// Recipient details read from the LNURL-pay endpoint, without paying
+class ResolvedRecipient  {
+                /// Recipient string that was resolved
+final String address;
+/// LNURL-pay endpoint the recipient resolved to
+final String endpoint;
+final LnurlMetadata metadata;
+/// Minimum amount the recipient accepts (msats)
+final BigInt minSendableMsats;
+/// Maximum amount the recipient accepts (msats)
+final BigInt maxSendableMsats;
+/// Maximum comment length (None if comments aren't accepted)
+final BigInt? commentAllowed;
+/// Whether the recipient accepts NIP-57 zap requests
+final bool supportsZaps;
+/// Key the recipient signs zap receipts with (hex, only set with zap support)
+final String? nostrPubkey;
+
+                const ResolvedRecipient({required this.address ,required this.endpoint ,required this.metadata ,required this.minSendableMsats ,required this.maxSendableMsats ,this.commentAllowed ,required this.supportsZaps ,this.nostrPubkey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => address.hashCode^endpoint.hashCode^metadata.hashCode^minSendableMsats.hashCode^maxSendableMsats.hashCode^commentAllowed.hashCode^supportsZaps.hashCode^nostrPubkey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResolvedRecipient &&
+                runtimeType == other.runtimeType
+                && address == other.address&& endpoint == other.endpoint&& metadata == other.metadata&& minSendableMsats == other.minSendableMsats&& maxSendableMsats == other.maxSendableMsats&& commentAllowed == other.commentAllowed&& supportsZaps == other.supportsZaps&& nostrPubkey == other.nostrPubkey;
+        
+            }
+
+@freezed
+                sealed class SuccessAction with _$SuccessAction  {
+                    const SuccessAction._();
+
+                     /// Plain message from the recipient
+const factory SuccessAction.message({   required String message , }) = SuccessAction_Message;
+ /// Link the recipient wants to show (same domain as the LNURL callback)
+const factory SuccessAction.url({   required String description ,  required String url , }) = SuccessAction_Url;
+ /// Secret decrypted with the payment preimage
+///
+/// `plaintext` is None until the invoice is paid, or if decryption failed.
+const factory SuccessAction.aes({   required String description ,  String? plaintext , }) = SuccessAction_Aes;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
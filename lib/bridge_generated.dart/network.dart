@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Timeouts per stage, retries and overall deadline of network calls
+class NetworkPolicy  {
+                /// Connecting to the wallet relay
+final BigInt relayConnectTimeoutSecs;
+/// Querying stored events on the wallet relay (info event, earlier responses)
+final BigInt relayQueryTimeoutSecs;
+/// Wallet answer to a non-payment request (balance, info, transactions)
+final BigInt walletTimeoutSecs;
+/// Wallet answer to a payment request
+final BigInt paymentTimeoutSecs;
+/// TCP/TLS connect to an LNURL server
+final BigInt httpConnectTimeoutSecs;
+/// Whole HTTP request to an LNURL server, including reading the body
+final BigInt httpTimeoutSecs;
+/// Extra attempts after a retryable failure (payment requests are never retried)
+final int maxRetries;
+/// Delay before the first retry; doubles with every further retry
+final BigInt retryBackoffMs;
+/// Upper bound on one operation, including all retries and backoff
+///
+/// A payment counts as one operation, from resolving the address to
+/// the wallet's answer.
+final BigInt deadlineSecs;
+
+                const NetworkPolicy({required this.relayConnectTimeoutSecs ,required this.relayQueryTimeoutSecs ,required this.walletTimeoutSecs ,required this.paymentTimeoutSecs ,required this.httpConnectTimeoutSecs ,required this.httpTimeoutSecs ,required this.maxRetries ,required this.retryBackoffMs ,required this.deadlineSecs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relayConnectTimeoutSecs.hashCode^relayQueryTimeoutSecs.hashCode^walletTimeoutSecs.hashCode^paymentTimeoutSecs.hashCode^httpConnectTimeoutSecs.hashCode^httpTimeoutSecs.hashCode^maxRetries.hashCode^retryBackoffMs.hashCode^deadlineSecs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NetworkPolicy &&
+                runtimeType == other.runtimeType
+                && relayConnectTimeoutSecs == other.relayConnectTimeoutSecs&& relayQueryTimeoutSecs == other.relayQueryTimeoutSecs&& walletTimeoutSecs == other.walletTimeoutSecs&& paymentTimeoutSecs == other.paymentTimeoutSecs&& httpConnectTimeoutSecs == other.httpConnectTimeoutSecs&& httpTimeoutSecs == other.httpTimeoutSecs&& maxRetries == other.maxRetries&& retryBackoffMs == other.retryBackoffMs&& deadlineSecs == other.deadlineSecs;
+        
+            }
+            
\ No newline at end of file
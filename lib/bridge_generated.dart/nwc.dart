@@ -0,0 +1,178 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'nwc.freezed.dart';
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>
+                abstract class NwcSession implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+@freezed
+                sealed class InvoiceLookup with _$InvoiceLookup  {
+                    const InvoiceLookup._();
+
+                     const factory InvoiceLookup.paymentHash(  String field0,) = InvoiceLookup_PaymentHash;
+ const factory InvoiceLookup.bolt11(  String field0,) = InvoiceLookup_Bolt11;
+
+                    
+
+                    
+                }
+
+/// Custom TLV record attached to a keysend payment
+class TlvRecord  {
+                final BigInt recordType;
+/// Hex-encoded value
+final String value;
+
+                const TlvRecord({required this.recordType ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => recordType.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TlvRecord &&
+                runtimeType == other.runtimeType
+                && recordType == other.recordType&& value == other.value;
+        
+            }
+
+/// Filters for `list_transactions` (timestamps are unix seconds)
+class TransactionFilter  {
+                final BigInt? from;
+final BigInt? until;
+final BigInt? limit;
+final BigInt? offset;
+/// Include unpaid invoices
+final bool? unpaid;
+final TransactionType? transactionType;
+
+                const TransactionFilter({this.from ,this.until ,this.limit ,this.offset ,this.unpaid ,this.transactionType ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => from.hashCode^until.hashCode^limit.hashCode^offset.hashCode^unpaid.hashCode^transactionType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TransactionFilter &&
+                runtimeType == other.runtimeType
+                && from == other.from&& until == other.until&& limit == other.limit&& offset == other.offset&& unpaid == other.unpaid&& transactionType == other.transactionType;
+        
+            }
+
+/// Direction of a wallet transaction
+enum TransactionType {
+                    incoming,
+outgoing,
+                    ;
+                    
+                }
+
+/// Capabilities of the connected wallet service
+///
+/// Combines the `get_info` response (what this connection may do) with the
+/// wallet service's info event, kind 13194 (what the service supports).
+class WalletInfo  {
+                final String? alias;
+final String? color;
+/// Lightning node public key
+final String? pubkey;
+/// Active network (mainnet, testnet, signet or regtest)
+final String? network;
+final int? blockHeight;
+final String? blockHash;
+/// Methods this connection is allowed to call
+final List<String> methods;
+/// Notification types the wallet service can send
+final List<String> notifications;
+/// Encryption schemes the wallet service accepts
+final List<String> encryption;
+/// Whether `get_info` was answered (otherwise only the info event was read)
+final bool getInfoSupported;
+/// Whether penalties can be paid with this connection
+final bool canPayInvoice;
+
+                const WalletInfo({this.alias ,this.color ,this.pubkey ,this.network ,this.blockHeight ,this.blockHash ,required this.methods ,required this.notifications ,required this.encryption ,required this.getInfoSupported ,required this.canPayInvoice ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => alias.hashCode^color.hashCode^pubkey.hashCode^network.hashCode^blockHeight.hashCode^blockHash.hashCode^methods.hashCode^notifications.hashCode^encryption.hashCode^getInfoSupported.hashCode^canPayInvoice.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WalletInfo &&
+                runtimeType == other.runtimeType
+                && alias == other.alias&& color == other.color&& pubkey == other.pubkey&& network == other.network&& blockHeight == other.blockHeight&& blockHash == other.blockHash&& methods == other.methods&& notifications == other.notifications&& encryption == other.encryption&& getInfoSupported == other.getInfoSupported&& canPayInvoice == other.canPayInvoice;
+        
+            }
+
+/// Transaction record reported by the wallet (amounts in msats)
+class WalletTransaction  {
+                final TransactionType? transactionType;
+final String? invoice;
+final String? description;
+final String? descriptionHash;
+/// Present once the payment has settled
+final String? preimage;
+final String paymentHash;
+final BigInt amount;
+final BigInt feesPaid;
+final BigInt createdAt;
+final BigInt? expiresAt;
+final BigInt? settledAt;
+
+                const WalletTransaction({this.transactionType ,this.invoice ,this.description ,this.descriptionHash ,this.preimage ,required this.paymentHash ,required this.amount ,required this.feesPaid ,required this.createdAt ,this.expiresAt ,this.settledAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transactionType.hashCode^invoice.hashCode^description.hashCode^descriptionHash.hashCode^preimage.hashCode^paymentHash.hashCode^amount.hashCode^feesPaid.hashCode^createdAt.hashCode^expiresAt.hashCode^settledAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WalletTransaction &&
+                runtimeType == other.runtimeType
+                && transactionType == other.transactionType&& invoice == other.invoice&& description == other.description&& descriptionHash == other.descriptionHash&& preimage == other.preimage&& paymentHash == other.paymentHash&& amount == other.amount&& feesPaid == other.feesPaid&& createdAt == other.createdAt&& expiresAt == other.expiresAt&& settledAt == other.settledAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,139 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'lightning.dart';
+import 'nwc.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'zap.dart';
+part 'payment.freezed.dart';
+
+            
+
+            
+
+            /// Receipt for a completed Lightning payment
+class PaymentOutcome  {
+                /// Paid invoice (None for keysend payments)
+final String? bolt11;
+/// Hex-encoded payment hash, decoded from the invoice
+final String paymentHash;
+/// Hex-encoded preimage returned by the wallet
+final String preimage;
+/// Amount paid to the recipient, excluding fees
+final BigInt amountMsats;
+/// Routing fees, if the wallet reported them
+final BigInt? feesPaidMsats;
+/// Recipient the invoice was requested from (node id for raw invoices and keysend)
+final String recipient;
+/// Comment attached to the LNURL request (None if it was omitted)
+final String? comment;
+/// Message, link or decrypted secret returned by the recipient (LUD-09)
+final SuccessAction? successAction;
+/// NIP-57 zap request and receipt status (None if the recipient doesn't support zaps)
+final ZapInfo? zap;
+/// Unix timestamp when the payment flow started
+final BigInt startedAt;
+/// Unix timestamp when the wallet confirmed the payment
+final BigInt paidAt;
+
+                const PaymentOutcome({this.bolt11 ,required this.paymentHash ,required this.preimage ,required this.amountMsats ,this.feesPaidMsats ,required this.recipient ,this.comment ,this.successAction ,this.zap ,required this.startedAt ,required this.paidAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bolt11.hashCode^paymentHash.hashCode^preimage.hashCode^amountMsats.hashCode^feesPaidMsats.hashCode^recipient.hashCode^comment.hashCode^successAction.hashCode^zap.hashCode^startedAt.hashCode^paidAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaymentOutcome &&
+                runtimeType == other.runtimeType
+                && bolt11 == other.bolt11&& paymentHash == other.paymentHash&& preimage == other.preimage&& amountMsats == other.amountMsats&& feesPaidMsats == other.feesPaidMsats&& recipient == other.recipient&& comment == other.comment&& successAction == other.successAction&& zap == other.zap&& startedAt == other.startedAt&& paidAt == other.paidAt;
+        
+            }
+
+@freezed
+                sealed class PaymentRecipient with _$PaymentRecipient  {
+                    const PaymentRecipient._();
+
+                     /// Lightning address, LNURL or `lightning:` URI
+const factory PaymentRecipient.lnurl({   required String address , }) = PaymentRecipient_Lnurl;
+ /// Fixed BOLT11 invoice
+const factory PaymentRecipient.bolt11({   required String invoice , }) = PaymentRecipient_Bolt11;
+ /// Node public key (hex), paid with keysend
+const factory PaymentRecipient.keysend({   required String pubkey ,  required List<TlvRecord> tlvRecords ,/// Hex preimage to use (a random one if None)
+  String? preimage , }) = PaymentRecipient_Keysend;
+
+                    
+
+                    
+                }
+
+/// Result of paying one share of a split penalty
+class SplitShareOutcome  {
+                final String address;
+/// Share of the total amount assigned to this recipient
+final BigInt amountSats;
+/// Receipt, if this share was paid
+final PaymentOutcome? outcome;
+/// Why this share wasn't paid (None on success)
+///
+/// `PaymentUnknown` means the share may still have been paid.
+final ZapClockError? error;
+
+                const SplitShareOutcome({required this.address ,required this.amountSats ,this.outcome ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => address.hashCode^amountSats.hashCode^outcome.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SplitShareOutcome &&
+                runtimeType == other.runtimeType
+                && address == other.address&& amountSats == other.amountSats&& outcome == other.outcome&& error == other.error;
+        
+            }
+
+/// Recipient of a share of a split penalty
+class WeightedRecipient  {
+                /// Lightning address, LNURL or `lightning:` URI
+final String address;
+/// Relative share (e.g. 50 / 30 / 20)
+final int weight;
+
+                const WeightedRecipient({required this.address ,required this.weight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => address.hashCode^weight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WeightedRecipient &&
+                runtimeType == other.runtimeType
+                && address == other.address&& weight == other.weight;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lightning.dart';
+import 'nwc.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'payment.dart';
+import 'zap.dart';
+
+
+            
+
+            
+
+            /// Penalty of one alarm occurrence
+class PenaltySession  {
+                final int alarmId;
+/// Unix time the alarm was scheduled to fire
+final BigInt scheduledAt;
+final PenaltyState state;
+final PaymentRecipient recipient;
+final BigInt amountSats;
+final String? comment;
+/// Seconds the user has to stop the alarm before paying
+final BigInt timeoutSecs;
+final BigInt ringingSince;
+final BigInt? countdownStartedAt;
+/// Receipt once paid
+final PaymentOutcome? outcome;
+/// Error of the last payment attempt
+final String? error;
+final BigInt updatedAt;
+
+                const PenaltySession({required this.alarmId ,required this.scheduledAt ,required this.state ,required this.recipient ,required this.amountSats ,this.comment ,required this.timeoutSecs ,required this.ringingSince ,this.countdownStartedAt ,this.outcome ,this.error ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => alarmId.hashCode^scheduledAt.hashCode^state.hashCode^recipient.hashCode^amountSats.hashCode^comment.hashCode^timeoutSecs.hashCode^ringingSince.hashCode^countdownStartedAt.hashCode^outcome.hashCode^error.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PenaltySession &&
+                runtimeType == other.runtimeType
+                && alarmId == other.alarmId&& scheduledAt == other.scheduledAt&& state == other.state&& recipient == other.recipient&& amountSats == other.amountSats&& comment == other.comment&& timeoutSecs == other.timeoutSecs&& ringingSince == other.ringingSince&& countdownStartedAt == other.countdownStartedAt&& outcome == other.outcome&& error == other.error&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Where a penalty session stands
+enum PenaltyState {
+                    /// Alarm is ringing, countdown not started yet
+ringing,
+/// Countdown running; dismissing now avoids the penalty
+countingDown,
+/// Alarm stopped in time, nothing is paid
+dismissed,
+/// Countdown ran out, payment in progress
+paying,
+paid,
+/// Payment definitely did not go out
+failed,
+/// Payment may or may not have gone out; paying again resumes it
+unknown,
+                    ;
+                    
+                }
+            
\ No newline at end of file
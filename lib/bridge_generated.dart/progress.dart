@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'error.dart';
+import 'frb_generated.dart';
+import 'lightning.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'payment.dart';
+import 'zap.dart';
+part 'progress.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class PaymentProgress with _$PaymentProgress  {
+                    const PaymentProgress._();
+
+                     /// Reading the recipient's LNURL-pay parameters
+const factory PaymentProgress.resolvingAddress({   required String address , }) = PaymentProgress_ResolvingAddress;
+ /// Invoice obtained and validated
+const factory PaymentProgress.invoiceReceived({   required BigInt amountMsats ,/// Hex-encoded payment hash
+  required String paymentHash , }) = PaymentProgress_InvoiceReceived;
+ /// Wallet relay connection is up
+const factory PaymentProgress.relayConnected({   required String relay , }) = PaymentProgress_RelayConnected;
+ /// NIP-47 request published to the wallet relay
+const factory PaymentProgress.requestPublished({   required String requestId , }) = PaymentProgress_RequestPublished;
+ /// Wallet answered the request
+const factory PaymentProgress.responseReceived() = PaymentProgress_ResponseReceived;
+ /// Preimage matches the payment hash
+const factory PaymentProgress.verified({   required String paymentHash , }) = PaymentProgress_Verified;
+ /// Payment finished; always the last event of a successful payment
+const factory PaymentProgress.completed({   required PaymentOutcome outcome , }) = PaymentProgress_Completed;
+ /// Payment not made (or its fate unknown); always the last event of a failed payment
+const factory PaymentProgress.failed({   required ZapClockError error , }) = PaymentProgress_Failed;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
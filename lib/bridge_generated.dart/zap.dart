@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'zap.freezed.dart';
+
+            
+
+            
+
+            /// Zap details attached to a payment result
+class ZapInfo  {
+                /// Id of the zap request event (hex)
+final String requestId;
+/// Zap request as sent in the `nostr` callback parameter
+final String requestJson;
+/// LNURL server key expected to sign the receipt (hex)
+final String serverPubkey;
+final ZapReceiptStatus receipt;
+
+                const ZapInfo({required this.requestId ,required this.requestJson ,required this.serverPubkey ,required this.receipt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => requestId.hashCode^requestJson.hashCode^serverPubkey.hashCode^receipt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ZapInfo &&
+                runtimeType == other.runtimeType
+                && requestId == other.requestId&& requestJson == other.requestJson&& serverPubkey == other.serverPubkey&& receipt == other.receipt;
+        
+            }
+
+@freezed
+                sealed class ZapReceiptStatus with _$ZapReceiptStatus  {
+                    const ZapReceiptStatus._();
+
+                     /// Not checked yet
+const factory ZapReceiptStatus.pending() = ZapReceiptStatus_Pending;
+ /// Valid kind-9735 receipt found on the zap request relays
+const factory ZapReceiptStatus.observed({   required String receiptId , }) = ZapReceiptStatus_Observed;
+ /// No valid receipt showed up before the timeout
+const factory ZapReceiptStatus.notObserved() = ZapReceiptStatus_NotObserved;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -836,6 +836,54 @@ abstract class AppLocalizations {
   /// **'This Lightning Address is already registered'**
   String get addressAlreadyExists;
 
+  /// Error message when a Lightning address cannot be resolved
+  ///
+  /// In en, this message translates to:
+  /// **'Could not verify this Lightning Address: {error}'**
+  String addressUnreachable(String error);
+
+  /// Payment progress: reading the recipient's LNURL-pay parameters
+  ///
+  /// In en, this message translates to:
+  /// **'Looking up {address}...'**
+  String paymentResolvingAddress(String address);
+
+  /// Payment progress: invoice obtained and validated
+  ///
+  /// In en, this message translates to:
+  /// **'Invoice received: {sats} sats'**
+  String paymentInvoiceReceived(int sats);
+
+  /// Payment progress: NWC relay connected
+  ///
+  /// In en, this message translates to:
+  /// **'Connected to wallet relay'**
+  String get paymentRelayConnected;
+
+  /// Payment progress: NIP-47 request published
+  ///
+  /// In en, this message translates to:
+  /// **'Payment request sent to wallet'**
+  String get paymentRequestPublished;
+
+  /// Payment progress: wallet response received
+  ///
+  /// In en, this message translates to:
+  /// **'Wallet responded'**
+  String get paymentResponseReceived;
+
+  /// Payment progress: preimage verified
+  ///
+  /// In en, this message translates to:
+  /// **'Payment verified'**
+  String get paymentVerified;
+
+  /// Payment progress: payment finished
+  ///
+  /// In en, this message translates to:
+  /// **'Penalty paid ⚡'**
+  String get paymentCompleted;
+
   /// Dialog title for requesting permissions
   ///
   /// In en, this message translates to:
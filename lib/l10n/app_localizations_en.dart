@@ -409,6 +409,36 @@ class AppLocalizationsEn extends AppLocalizations {
   String get addressAlreadyExists =>
       'This Lightning Address is already registered';
 
+  @override
+  String addressUnreachable(String error) {
+    return 'Could not verify this Lightning Address: $error';
+  }
+
+  @override
+  String paymentResolvingAddress(String address) {
+    return 'Looking up $address...';
+  }
+
+  @override
+  String paymentInvoiceReceived(int sats) {
+    return 'Invoice received: $sats sats';
+  }
+
+  @override
+  String get paymentRelayConnected => 'Connected to wallet relay';
+
+  @override
+  String get paymentRequestPublished => 'Payment request sent to wallet';
+
+  @override
+  String get paymentResponseReceived => 'Wallet responded';
+
+  @override
+  String get paymentVerified => 'Payment verified';
+
+  @override
+  String get paymentCompleted => 'Penalty paid ⚡';
+
   @override
   String get requestPermissions => 'Request Permissions';
 
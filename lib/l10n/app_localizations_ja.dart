@@ -396,6 +396,36 @@ class AppLocalizationsJa extends AppLocalizations {
   @override
   String get addressAlreadyExists => 'この Lightning Address は既に登録されています';
 
+  @override
+  String addressUnreachable(String error) {
+    return 'この Lightning Address を確認できませんでした: $error';
+  }
+
+  @override
+  String paymentResolvingAddress(String address) {
+    return '$address を確認中...';
+  }
+
+  @override
+  String paymentInvoiceReceived(int sats) {
+    return '請求書を受信しました: $sats sats';
+  }
+
+  @override
+  String get paymentRelayConnected => 'ウォレットのリレーに接続しました';
+
+  @override
+  String get paymentRequestPublished => 'ウォレットに支払いリクエストを送信しました';
+
+  @override
+  String get paymentResponseReceived => 'ウォレットが応答しました';
+
+  @override
+  String get paymentVerified => '支払いを確認しました';
+
+  @override
+  String get paymentCompleted => 'ペナルティを支払いました ⚡';
+
   @override
   String get requestPermissions => '必要な権限の許可';
 
@@ -114,6 +114,12 @@ class _MyAppState extends ConsumerState<MyApp> {
         _handleAlarmRinging(alarmSettings.id);
       }
     });
+    
+    // Resume penalties (countdowns / payments) interrupted by a restart
+    AlarmCountdownService().resumeAfterRestart(
+      storageService: ref.read(storageServiceProvider),
+      nwcService: ref.read(nwcServiceProvider),
+    );
   }
 
   /// Handle alarm ringing: start background countdown and navigate to ring screen
@@ -9,6 +9,7 @@ import '../models/alarm.dart' as app_models;
 import '../providers/alarm_provider.dart';
 import '../providers/storage_provider.dart';
 import '../services/alarm_countdown_service.dart';
+import '../bridge_generated.dart/progress.dart';
 import '../app_theme.dart';
 
 /// Alarm ringing screen
@@ -33,6 +34,9 @@ class _AlarmRingScreenState extends State<AlarmRingScreen>
   Timer? _updateTimer;
   int _remainingSeconds = 0; // 初期値は0、アラーム読み込み時に設定
   final _countdownService = AlarmCountdownService();
+  PaymentProgress? _paymentProgress;
+  bool _paymentCommitted = false;
+  StreamSubscription<PaymentProgress>? _progressSubscription;
   
   @override
   void initState() {
@@ -53,12 +57,34 @@ class _AlarmRingScreenState extends State<AlarmRingScreen>
         curve: Curves.easeInOut,
       ),
     );
+    
+    // 自動送金が始まったら各段階を表示する
+    _progressSubscription = _countdownService.progressOf(widget.alarmId).listen(
+      (progress) {
+        if (!mounted) return;
+        setState(() {
+          _isProcessingPayment = progress is! PaymentProgress_Completed;
+          // リクエスト送信後は送金を取り消せない
+          _paymentCommitted = _paymentCommitted ||
+              progress is PaymentProgress_RequestPublished;
+          _paymentProgress = progress;
+        });
+      },
+      onError: (Object e) {
+        if (!mounted) return;
+        setState(() {
+          _isProcessingPayment = false;
+          _paymentError = e.toString();
+        });
+      },
+    );
   }
   
   @override
   void dispose() {
     _animationController.dispose();
     _updateTimer?.cancel();
+    _progressSubscription?.cancel();
     SystemChrome.setEnabledSystemUIMode(
       SystemUiMode.manual,
       overlays: SystemUiOverlay.values,
@@ -228,6 +254,12 @@ class _AlarmRingScreenState extends State<AlarmRingScreen>
             if (_alarm?.amountSats != null)
               _buildCountdownTimer(),
             
+            // 送金の進捗
+            if (_paymentProgress != null) ...[
+              const SizedBox(height: 16),
+              _buildPaymentProgress(context),
+            ],
+            
             const SizedBox(height: 16),
             
             // 手動停止ボタン
@@ -361,6 +393,47 @@ class _AlarmRingScreenState extends State<AlarmRingScreen>
     );
   }
   
+  /// 自動送金の進捗を表示
+  Widget _buildPaymentProgress(BuildContext context) {
+    final l10n = AppLocalizations.of(context)!;
+    final text = switch (_paymentProgress!) {
+      PaymentProgress_ResolvingAddress(:final address) => l10n.paymentResolvingAddress(address),
+      PaymentProgress_InvoiceReceived(:final amountMsats) =>
+        l10n.paymentInvoiceReceived((amountMsats ~/ BigInt.from(1000)).toInt()),
+      PaymentProgress_RelayConnected() => l10n.paymentRelayConnected,
+      PaymentProgress_RequestPublished() => l10n.paymentRequestPublished,
+      PaymentProgress_ResponseReceived() => l10n.paymentResponseReceived,
+      PaymentProgress_Verified() => l10n.paymentVerified,
+      PaymentProgress_Completed() => l10n.paymentCompleted,
+      // NwcService が送金エラーとして流すので通常は届かない
+      PaymentProgress_Failed(:final error) => error.toString(),
+    };
+    
+    return Row(
+      mainAxisSize: MainAxisSize.min,
+      children: [
+        if (_isProcessingPayment) ...[
+          const SizedBox(
+            width: 16,
+            height: 16,
+            child: CircularProgressIndicator(strokeWidth: 2, color: Colors.white),
+          ),
+          const SizedBox(width: 8),
+        ],
+        Flexible(
+          child: Text(
+            text,
+            style: const TextStyle(
+              color: Colors.white,
+              fontSize: 16,
+            ),
+            textAlign: TextAlign.center,
+          ),
+        ),
+      ],
+    );
+  }
+  
   /// カウントダウンタイマーを表示
   Widget _buildCountdownTimer() {
     final minutes = _remainingSeconds ~/ 60;
@@ -413,7 +486,8 @@ class _AlarmRingScreenState extends State<AlarmRingScreen>
       width: double.infinity,
       height: 64,
       child: ElevatedButton(
-        onPressed: _isProcessingPayment
+        // 送金中でもリクエスト送信前なら止められる
+        onPressed: _paymentCommitted && _isProcessingPayment
             ? null
             : () {
                 // バックグラウンドカウントダウンをキャンセル
@@ -431,7 +505,7 @@ class _AlarmRingScreenState extends State<AlarmRingScreen>
           ),
           elevation: 4,
         ),
-        child: _isProcessingPayment
+        child: _paymentCommitted && _isProcessingPayment
             ? const CircularProgressIndicator()
             : Row(
                 mainAxisAlignment: MainAxisAlignment.center,
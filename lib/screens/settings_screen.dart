@@ -5,6 +5,7 @@ import '../l10n/app_localizations.dart';
 import '../models/donation_recipient.dart';
 import '../providers/nwc_provider.dart';
 import '../providers/storage_provider.dart';
+import '../services/nwc_service.dart';
 import '../services/permission_service.dart';
 import '../app_theme.dart';
 
@@ -949,8 +950,15 @@ class _SettingsScreenState extends State<SettingsScreen> {
                       return;
                     }
                     
-                    // Lightning Address の形式チェック
-                    if (!address.contains('@')) {
+                    // 送金先の形式チェック（Lightning Address / LNURL / lightning: URI）
+                    final lowerAddress = address.toLowerCase();
+                    final isSupportedFormat = address.contains('@') ||
+                        lowerAddress.startsWith('lnurl1') ||
+                        lowerAddress.startsWith('lightning:') ||
+                        lowerAddress.startsWith('lnurlp://') ||
+                        lowerAddress.startsWith('lnbc') ||
+                        NwcService.isKeysendPubkey(address);
+                    if (!isSupportedFormat) {
                       ScaffoldMessenger.of(context).showSnackBar(
                         const SnackBar(
                           content: Text('Lightning Addressの形式が正しくありません'),
@@ -979,6 +987,29 @@ class _SettingsScreenState extends State<SettingsScreen> {
     );
     
     if (result != null && mounted) {
+      // LNURL-payエンドポイントに到達できるか確認してから保存（BOLT11インボイス・keysendは対象外）
+      try {
+        if (!NwcService.isBolt11Invoice(result.lightningAddress) &&
+            !NwcService.isKeysendPubkey(result.lightningAddress)) {
+          await ref.read(nwcServiceProvider).resolveLightningAddress(result.lightningAddress);
+        }
+      } catch (e) {
+        if (mounted) {
+          ScaffoldMessenger.of(context).showSnackBar(
+            SnackBar(
+              content: Text(l10n.addressUnreachable(e.toString())),
+              backgroundColor: AppTheme.errorColor,
+            ),
+          );
+        }
+        nameController.dispose();
+        addressController.dispose();
+        descriptionController.dispose();
+        return;
+      }
+      
+      if (!mounted) return;
+      
       final storage = ref.read(storageServiceProvider);
       final success = await storage.addCustomRecipient(result);
       
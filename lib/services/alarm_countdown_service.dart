@@ -1,23 +1,32 @@
 import 'dart:async';
 import 'package:flutter/material.dart';
-import 'package:shared_preferences/shared_preferences.dart';
 import 'package:alarm/alarm.dart';
 import '../models/alarm.dart' as app_models;
 import 'storage_service.dart';
 import 'nwc_service.dart';
 import '../models/donation_recipient.dart';
+import '../bridge_generated.dart/payment.dart';
+import '../bridge_generated.dart/penalty.dart';
+import '../bridge_generated.dart/progress.dart';
 
 /// アラームのバックグラウンドカウントダウンを管理するサービス
+///
+/// ペナルティの状態（鳴動中 → カウントダウン → 停止 / 送金中 → 送金済み・失敗・不明）は
+/// Rust側の PenaltySession が保持・永続化する。ここではタイマーで期限を待つだけ
 class AlarmCountdownService {
   static final AlarmCountdownService _instance = AlarmCountdownService._internal();
   factory AlarmCountdownService() => _instance;
   AlarmCountdownService._internal();
 
   final Map<int, Timer> _countdownTimers = {};
-  final Map<int, DateTime> _startTimes = {};
-  final Map<int, int> _timeoutSeconds = {};
+  final NwcService _nwcService = NwcService();
+  final _progressController =
+      StreamController<({int alarmId, PaymentProgress? progress, Object? error})>.broadcast();
   
-  static const String _keyPrefix = 'alarm_countdown_';
+  /// 自動送金の進捗（アラーム画面の表示用、送金失敗はエラーとして流れる）
+  Stream<PaymentProgress> progressOf(int alarmId) => _progressController.stream
+      .where((event) => event.alarmId == alarmId)
+      .map((event) => event.progress ?? (throw event.error!));
   
   /// アラームが鳴り始めたときに呼び出す
   /// カウントダウンを開始し、タイムアウト時に自動Zap処理を実行
@@ -28,7 +37,7 @@ class AlarmCountdownService {
     required NwcService nwcService,
   }) async {
     // 既存のタイマーをキャンセル
-    stopCountdown(alarmId);
+    _cancelTimer(alarmId);
     
     // 送金設定がない場合はカウントダウン不要
     if (alarm.amountSats == null) {
@@ -37,21 +46,99 @@ class AlarmCountdownService {
     }
     
     final timeoutSeconds = alarm.timeoutSeconds ?? 300;
-    final startTime = DateTime.now();
+    final nwcConnection = storageService.getGlobalNwcConnection();
+    if (nwcConnection == null || nwcConnection.isEmpty) {
+      // NWC設定がなければ送金せず、タイムアウトでアラームを止めるだけ
+      debugPrint('⚠️ アラームID=$alarmId: NWC接続が設定されていません');
+      _countdownTimers[alarmId] = Timer(Duration(seconds: timeoutSeconds), () async {
+        await Alarm.stop(alarmId);
+        await _cleanupAfterAlarm(alarmId, alarm, storageService);
+      });
+      return;
+    }
     
-    // 開始時刻とタイムアウト秒数を保存（メモリとSharedPreferences両方）
-    _startTimes[alarmId] = startTime;
-    _timeoutSeconds[alarmId] = timeoutSeconds;
+    // 送金先を取得
+    final recipientAddress = alarm.donationRecipient 
+        ?? storageService.getDonationRecipient() 
+        ?? DonationRecipients.defaultRecipientSync.lightningAddress;
     
-    final prefs = await SharedPreferences.getInstance();
-    await prefs.setInt('${_keyPrefix}${alarmId}_start', startTime.millisecondsSinceEpoch);
-    await prefs.setInt('${_keyPrefix}${alarmId}_timeout', timeoutSeconds);
+    // 同じアラーム発火なら再起動後も同じセッション（残り時間）を引き継ぐ
+    final session = await nwcService.startPenalty(
+      alarmId: alarmId,
+      scheduledAt: _scheduledFireTime(alarm, DateTime.now()),
+      recipient: recipientAddress,
+      amountSats: alarm.amountSats!,
+      timeoutSeconds: timeoutSeconds,
+      comment: 'donation from ZapClock',
+    );
     
     debugPrint('⏱️ アラームID=$alarmId: カウントダウン開始 (${timeoutSeconds}秒)');
     
+    // 送金時にリレー接続を待たないよう、NWCセッションを先に開いておく
+    unawaited(nwcService.warmUp(nwcConnection));
+    
+    await _armTimer(
+      alarmId: alarmId,
+      alarm: alarm,
+      remainingSeconds: (await nwcService.penaltyRemainingSeconds(alarmId)) ?? session.timeoutSecs.toInt(),
+      storageService: storageService,
+      nwcService: nwcService,
+    );
+  }
+  
+  /// アプリ再起動後にペナルティを再開
+  ///
+  /// 不在中に期限が来たもの・送金途中だったものはRust側で送金（再開）され、
+  /// まだカウントダウン中のものはタイマーを張り直す
+  Future<void> resumeAfterRestart({
+    required StorageService storageService,
+    required NwcService nwcService,
+  }) async {
+    final nwcConnection = storageService.getGlobalNwcConnection();
+    if (nwcConnection == null || nwcConnection.isEmpty) return;
+    
+    try {
+      final sessions = await nwcService.resumePenalties(nwcConnection);
+      final alarms = storageService.getAlarms();
+      for (final session in sessions) {
+        final alarm = alarms.where((a) => a.id == session.alarmId).firstOrNull;
+        if (alarm == null) continue;
+        
+        if (session.state == PenaltyState.countingDown) {
+          debugPrint('♻️ アラームID=${session.alarmId}: カウントダウンを再開');
+          await _armTimer(
+            alarmId: session.alarmId,
+            alarm: alarm,
+            remainingSeconds: (await nwcService.penaltyRemainingSeconds(session.alarmId)) ?? 0,
+            storageService: storageService,
+            nwcService: nwcService,
+          );
+        } else if (session.state == PenaltyState.paid) {
+          debugPrint('♻️ アラームID=${session.alarmId}: 再起動後に送金完了');
+          await Alarm.stop(session.alarmId);
+          await _cleanupAfterAlarm(session.alarmId, alarm, storageService);
+        }
+      }
+    } catch (e) {
+      debugPrint('❌ ペナルティ再開エラー: $e');
+    }
+  }
+  
+  /// 期限で自動Zap処理を実行するタイマーを設定
+  Future<void> _armTimer({
+    required int alarmId,
+    required app_models.Alarm alarm,
+    required int remainingSeconds,
+    required StorageService storageService,
+    required NwcService nwcService,
+  }) async {
+    _cancelTimer(alarmId);
+    
     // バックグラウンドでタイムアウトを監視
-    final timer = Timer(Duration(seconds: timeoutSeconds), () async {
+    _countdownTimers[alarmId] = Timer(Duration(seconds: remainingSeconds), () async {
       debugPrint('⏰ アラームID=$alarmId: タイムアウト到達！自動Zap処理を開始');
+      debugPrint('💰 金額: ${alarm.amountSats} sats');
+      
       await _executeAutoZap(
         alarmId: alarmId,
         alarm: alarm,
@@ -59,76 +146,49 @@ class AlarmCountdownService {
         nwcService: nwcService,
       );
     });
-    
-    _countdownTimers[alarmId] = timer;
   }
   
   /// カウントダウンを停止（手動でアラームを止めた場合）
-  void stopCountdown(int alarmId) async {
-    _countdownTimers[alarmId]?.cancel();
-    _countdownTimers.remove(alarmId);
-    _startTimes.remove(alarmId);
-    _timeoutSeconds.remove(alarmId);
-    
-    // SharedPreferencesからも削除
-    final prefs = await SharedPreferences.getInstance();
-    await prefs.remove('${_keyPrefix}${alarmId}_start');
-    await prefs.remove('${_keyPrefix}${alarmId}_timeout');
+  ///
+  /// 送金中でもリクエスト送信前なら送金を取り消す。
+  /// 止められなかった場合（送信済み・期限切れ）は false を返す。
+  Future<bool> stopCountdown(int alarmId) async {
+    _cancelTimer(alarmId);
     
-    debugPrint('⏹️ アラームID=$alarmId: カウントダウン停止');
+    try {
+      await _nwcService.dismissPenalty(alarmId);
+      debugPrint('⏹️ アラームID=$alarmId: カウントダウン停止');
+      return true;
+    } catch (e) {
+      // 期限切れ・送金リクエスト送信済みのペナルティは止められない
+      debugPrint('⚠️ アラームID=$alarmId: カウントダウンを停止できません: $e');
+      return false;
+    }
   }
   
   /// 残り時間を取得（秒）
   Future<int?> getRemainingSeconds(int alarmId) async {
-    // メモリ内の開始時刻とタイムアウト秒数を優先
-    DateTime? startTime = _startTimes[alarmId];
-    int? timeoutSeconds = _timeoutSeconds[alarmId];
-    
-    // メモリになければSharedPreferencesから取得
-    if (startTime == null || timeoutSeconds == null) {
-      final prefs = await SharedPreferences.getInstance();
-      final startMillis = prefs.getInt('${_keyPrefix}${alarmId}_start');
-      final storedTimeout = prefs.getInt('${_keyPrefix}${alarmId}_timeout');
+    try {
+      final remaining = await _nwcService.penaltyRemainingSeconds(alarmId);
       
-      if (startMillis == null || storedTimeout == null) {
-        debugPrint('⚠️ アラームID=$alarmId: カウントダウンデータが見つかりません');
-        return null; // カウントダウン未開始
+      // デバッグログは頻繁に呼ばれるので簡略化
+      if (remaining != null && (remaining % 10 == 0 || remaining <= 10)) {
+        debugPrint('⏱️ アラームID=$alarmId: 残り${remaining}秒');
       }
-      
-      startTime = DateTime.fromMillisecondsSinceEpoch(startMillis);
-      timeoutSeconds = storedTimeout;
-      
-      // メモリにキャッシュ
-      _startTimes[alarmId] = startTime;
-      _timeoutSeconds[alarmId] = timeoutSeconds;
-      
-      debugPrint('📱 アラームID=$alarmId: SharedPreferencesから復元 (開始: $startTime, タイムアウト: ${timeoutSeconds}秒)');
-    }
-    
-    final elapsed = DateTime.now().difference(startTime).inSeconds;
-    final remaining = timeoutSeconds - elapsed;
-    
-    // デバッグログは頻繁に呼ばれるので簡略化
-    if (remaining % 10 == 0 || remaining <= 10) {
-      debugPrint('⏱️ アラームID=$alarmId: 残り${remaining}秒');
+      return remaining;
+    } catch (e) {
+      debugPrint('⚠️ アラームID=$alarmId: カウントダウンデータが見つかりません: $e');
+      return null;
     }
-    
-    return remaining > 0 ? remaining : 0;
   }
   
   /// カウントダウン開始時刻を取得
   Future<DateTime?> getStartTime(int alarmId) async {
-    // メモリ内の開始時刻を優先
-    if (_startTimes.containsKey(alarmId)) {
-      return _startTimes[alarmId];
-    }
-    
-    // SharedPreferencesから取得
-    final prefs = await SharedPreferences.getInstance();
-    final startMillis = prefs.getInt('${_keyPrefix}${alarmId}_start');
-    if (startMillis == null) return null;
+    final session = await _nwcService.getPenalty(alarmId);
+    final startedAt = session?.countdownStartedAt;
+    if (startedAt == null) return null;
     
-    return DateTime.fromMillisecondsSinceEpoch(startMillis);
+    return DateTime.fromMillisecondsSinceEpoch(startedAt.toInt() * 1000);
   }
   
   /// 自動Zap処理を実行
@@ -150,29 +210,44 @@ class AlarmCountdownService {
         return;
       }
       
-      // 送金先を取得
-      final recipientAddress = alarm.donationRecipient 
-          ?? storageService.getDonationRecipient() 
-          ?? DonationRecipients.defaultRecipientSync.lightningAddress;
-      
       debugPrint('💳 アラームID=$alarmId: NWC経由で自動送金を開始');
-      debugPrint('📍 送金先: $recipientAddress');
-      debugPrint('💰 金額: ${alarm.amountSats} sats');
-      
-      // Lightning送金を実行
-      final paymentHash = await nwcService.payWithNwc(
-        connectionString: nwcConnection,
-        lightningAddress: recipientAddress,
-        amountSats: alarm.amountSats!,
-        comment: 'donation from ZapClock',
-      );
       
-      debugPrint('✅ アラームID=$alarmId: 自動送金成功 ($paymentHash)');
+      // Lightning送金を実行（Lightning Address / BOLT11インボイス / keysend）
+      // 同じアラーム発火に対しては二重送金しない
+      // 各段階の進捗をアラーム画面に流す
+      PaymentOutcome? outcome;
+      try {
+        await for (final progress in nwcService.payDuePenaltyWithProgress(
+          connectionString: nwcConnection,
+          alarmId: alarmId,
+        )) {
+          _progressController.add((alarmId: alarmId, progress: progress, error: null));
+          if (progress is PaymentProgress_Completed) {
+            outcome = progress.outcome;
+          }
+        }
+        debugPrint('✅ アラームID=$alarmId: 自動送金成功 (${outcome?.paymentHash})');
+      } catch (e) {
+        _progressController.add((alarmId: alarmId, progress: null, error: e));
+        // 状態不明の送金は次回起動時に再開される
+        final session = await nwcService.getPenalty(alarmId);
+        debugPrint('❌ アラームID=$alarmId: 自動送金${session?.state.name}: $e');
+      }
       
       // アラームを停止
       await Alarm.stop(alarmId);
       await _cleanupAfterAlarm(alarmId, alarm, storageService);
       
+      // Zapレシートの確認はアラーム停止後にバックグラウンドで行い、
+      // 結果はペナルティの送金結果として保存する
+      if (outcome?.zap != null) {
+        unawaited(nwcService.confirmPenaltyZapReceipt(alarmId).then(
+          (session) => debugPrint(
+              '⚡ アラームID=$alarmId: Zapレシート ${session?.outcome?.zap?.receipt}'),
+          onError: (e) => debugPrint('⚠️ アラームID=$alarmId: Zapレシート確認失敗: $e'),
+        ));
+      }
+      
     } catch (e) {
       debugPrint('❌ アラームID=$alarmId: 自動送金エラー: $e');
       
@@ -182,14 +257,36 @@ class AlarmCountdownService {
     }
   }
   
+  /// 鳴動中のアラームの予定発火時刻（送金の冪等キーに使う）
+  ///
+  /// 鳴り始めた時刻以前で最も近い hour:minute を返す
+  DateTime _scheduledFireTime(app_models.Alarm alarm, DateTime ringingSince) {
+    var scheduled = DateTime(
+      ringingSince.year,
+      ringingSince.month,
+      ringingSince.day,
+      alarm.hour,
+      alarm.minute,
+    );
+    if (scheduled.isAfter(ringingSince)) {
+      scheduled = scheduled.subtract(const Duration(days: 1));
+    }
+    return scheduled;
+  }
+  
+  void _cancelTimer(int alarmId) {
+    _countdownTimers[alarmId]?.cancel();
+    _countdownTimers.remove(alarmId);
+  }
+  
   /// アラーム停止後のクリーンアップ処理
   Future<void> _cleanupAfterAlarm(
     int alarmId,
     app_models.Alarm alarm,
     StorageService storageService,
   ) async {
-    // カウントダウンデータを削除
-    stopCountdown(alarmId);
+    // タイマーを削除（ペナルティは既に決着済み）
+    _cancelTimer(alarmId);
     
     // 繰り返しアラームの場合は次回をスケジュール
     if (alarm.hasRepeat && alarm.isEnabled) {
@@ -1,6 +1,16 @@
 import 'package:flutter/material.dart';
+import 'package:path_provider/path_provider.dart';
 import '../bridge_generated.dart/frb_generated.dart';
 import '../bridge_generated.dart/api.dart' as rust_api;
+import '../bridge_generated.dart/cancel.dart';
+import '../bridge_generated.dart/journal.dart';
+import '../bridge_generated.dart/lightning.dart';
+import '../bridge_generated.dart/network.dart';
+import '../bridge_generated.dart/nwc.dart';
+import '../bridge_generated.dart/payment.dart';
+import '../bridge_generated.dart/penalty.dart';
+import '../bridge_generated.dart/progress.dart';
+import '../bridge_generated.dart/zap.dart';
 
 /// NWC (Nostr Wallet Connect) service
 /// 
@@ -11,11 +21,53 @@ class NwcService {
     try {
       await RustLib.init();
       debugPrint('✅ Rust bridge initialized');
+      
+      // Payment records let an interrupted penalty resume instead of paying twice
+      final directory = await getApplicationSupportDirectory();
+      await rust_api.initPaymentStore(directory: directory.path);
+      await rust_api.initPaymentJournal(path: '${directory.path}/payment_journal.jsonl');
+      debugPrint('✅ Payment store ready');
+      
+      // Report payments a crash left between "request sent" and a verified answer
+      final unknown = await rust_api.recoverUnknownPayments();
+      for (final payment in unknown) {
+        debugPrint('⚠️ Payment ${payment.paymentKey} in unknown state (last stage: ${payment.lastStage.name})');
+      }
     } catch (e) {
       debugPrint('⚠️ Rust bridge initialization failed (using mock): $e');
     }
   }
   
+  /// Timeouts and retries used for NWC and LNURL calls
+  NetworkPolicy get networkPolicy => rust_api.getNetworkPolicy();
+  
+  /// Built-in network policy (starting point for custom settings)
+  NetworkPolicy get defaultNetworkPolicy => rust_api.defaultNetworkPolicy();
+  
+  /// Change timeouts and retries for calls made from now on
+  ///
+  /// Throws `ZapClockError.invalidNetworkPolicy` (keeping the current policy)
+  /// if a timeout or the deadline is 0.
+  void setNetworkPolicy(NetworkPolicy policy) {
+    try {
+      rust_api.setNetworkPolicy(policy: policy);
+      debugPrint('🌐 Network policy updated: deadline ${policy.deadlineSecs}s, ${policy.maxRetries} retries');
+    } catch (e) {
+      debugPrint('❌ Network policy rejected: $e');
+      rethrow;
+    }
+  }
+  
+  /// Open the NWC session ahead of time so a later payment skips the relay connect
+  Future<void> warmUp(String connectionString) async {
+    try {
+      await rust_api.connectNwc(connectionString: connectionString);
+      debugPrint('✅ NWC session ready');
+    } catch (e) {
+      debugPrint('⚠️ NWC session warm-up failed: $e');
+    }
+  }
+  
   /// Test NWC connection and get balance
   Future<int> testConnection(String connectionString) async {
     try {
@@ -31,8 +83,70 @@ class NwcService {
     }
   }
   
+  /// Get wallet capabilities (whether this connection may pay invoices, etc.)
+  Future<WalletInfo> getWalletInfo(String connectionString) async {
+    try {
+      final info = await rust_api.getWalletInfo(
+        connectionString: connectionString,
+      );
+      debugPrint('✅ Wallet info: methods=${info.methods}, network=${info.network}');
+      return info;
+    } catch (e) {
+      debugPrint('❌ Wallet info retrieval failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// List outgoing payments made from the wallet (newest first)
+  Future<List<WalletTransaction>> listPayments(
+    String connectionString, {
+    DateTime? since,
+    int limit = 20,
+  }) async {
+    try {
+      final transactions = await rust_api.listWalletTransactions(
+        connectionString: connectionString,
+        filter: TransactionFilter(
+          from: since == null ? null : BigInt.from(since.millisecondsSinceEpoch ~/ 1000),
+          limit: BigInt.from(limit),
+          transactionType: TransactionType.outgoing,
+        ),
+      );
+      debugPrint('✅ ${transactions.length} payments retrieved');
+      return transactions;
+    } catch (e) {
+      debugPrint('❌ Payment history retrieval failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Look up a recipient's description and avatar without paying
+  Future<ResolvedRecipient> resolveLightningAddress(String lightningAddress) async {
+    try {
+      final recipient = await rust_api.resolveLightningAddress(
+        lightningAddress: lightningAddress,
+      );
+      debugPrint('✅ Recipient resolved: ${recipient.metadata.description}');
+      return recipient;
+    } catch (e) {
+      debugPrint('❌ Recipient resolution failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Text to show for a LUD-09 success action (null if there is nothing to show)
+  static String? successActionText(SuccessAction? action) {
+    return switch (action) {
+      SuccessAction_Message(:final message) => message,
+      SuccessAction_Url(:final description, :final url) => '$description\n$url',
+      SuccessAction_Aes(:final description, :final plaintext) =>
+        plaintext == null ? description : '$description\n$plaintext',
+      null => null,
+    };
+  }
+  
   /// Pay Lightning Invoice (send to configured destination)
-  Future<String> payWithNwc({
+  Future<PaymentOutcome> payWithNwc({
     required String connectionString,
     required String lightningAddress,
     required int amountSats,
@@ -45,21 +159,427 @@ class NwcService {
       }
       
       // Use Rust bridge
-      final paymentHash = await rust_api.payLightningInvoice(
+      final outcome = await rust_api.payLightningInvoice(
         connectionString: connectionString,
         lightningAddress: lightningAddress,
         amountSats: BigInt.from(amountSats),
         comment: comment,
       );
       
-      debugPrint('✅ NWC payment successful: $paymentHash');
-      return paymentHash;
+      debugPrint('✅ NWC payment successful: ${outcome.paymentHash}');
+      final successText = successActionText(outcome.successAction);
+      if (successText != null) {
+        debugPrint('🎉 Success action: $successText');
+      }
+      return outcome;
     } catch (e) {
       debugPrint('❌ NWC payment failed: $e');
       rethrow; // Propagate error to caller
     }
   }
   
+  /// Whether a recipient string is a BOLT11 invoice rather than an LNURL recipient
+  static bool isBolt11Invoice(String recipient) {
+    var value = recipient.trim().toLowerCase();
+    if (value.startsWith('lightning:')) {
+      value = value.substring('lightning:'.length);
+    }
+    return value.startsWith('lnbc');
+  }
+  
+  /// Whether a recipient string is a node public key to pay with keysend
+  ///
+  /// Accepts a bare 33-byte hex key or a `keysend:` prefixed one.
+  static bool isKeysendPubkey(String recipient) {
+    var value = recipient.trim().toLowerCase();
+    if (value.startsWith('keysend:')) {
+      value = value.substring('keysend:'.length);
+    }
+    return RegExp(r'^0[23][0-9a-f]{64}$').hasMatch(value);
+  }
+  
+  /// Map a stored recipient string to the Rust recipient type
+  static PaymentRecipient recipientFromString(String recipient) {
+    final value = recipient.trim();
+    if (isKeysendPubkey(value)) {
+      final pubkey = value.toLowerCase().replaceFirst('keysend:', '');
+      return PaymentRecipient.keysend(pubkey: pubkey, tlvRecords: const []);
+    }
+    if (isBolt11Invoice(value)) {
+      return PaymentRecipient.bolt11(invoice: value);
+    }
+    return PaymentRecipient.lnurl(address: value);
+  }
+  
+  /// Pay a penalty to a Lightning address, LNURL, BOLT11 invoice or keysend node
+  Future<PaymentOutcome> payRecipient({
+    required String connectionString,
+    required String recipient,
+    required int amountSats,
+    String? comment,
+  }) async {
+    try {
+      debugPrint('🔄 Starting payment: $amountSats sats → $recipient');
+      
+      final outcome = await rust_api.payRecipient(
+        connectionString: connectionString,
+        recipient: recipientFromString(recipient),
+        amountSats: BigInt.from(amountSats),
+        comment: comment,
+      );
+      
+      debugPrint('✅ Payment successful: ${outcome.paymentHash}');
+      final successText = successActionText(outcome.successAction);
+      if (successText != null) {
+        debugPrint('🎉 Success action: $successText');
+      }
+      return outcome;
+    } catch (e) {
+      debugPrint('❌ Payment failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Handle for cancelling a payment started with `payRecipientCancellable`
+  PaymentCancel newPaymentCancel() => rust_api.newPaymentCancel();
+  
+  /// Cancel a payment; `tooLate` means its request already reached the wallet
+  CancelOutcome cancelPayment(PaymentCancel cancel) {
+    final outcome = rust_api.cancelPayment(cancel: cancel);
+    debugPrint('🛑 Payment cancel: $outcome');
+    return outcome;
+  }
+  
+  /// Pay a recipient with a payment that `cancel` can stop before it is sent
+  Future<PaymentOutcome> payRecipientCancellable({
+    required PaymentCancel cancel,
+    required String connectionString,
+    required String recipient,
+    required int amountSats,
+    String? comment,
+  }) async {
+    try {
+      debugPrint('🔄 Starting cancellable payment: $amountSats sats → $recipient');
+      
+      final outcome = await rust_api.payRecipientCancellable(
+        cancel: cancel,
+        connectionString: connectionString,
+        recipient: recipientFromString(recipient),
+        amountSats: BigInt.from(amountSats),
+        comment: comment,
+      );
+      
+      debugPrint('✅ Payment successful: ${outcome.paymentHash}');
+      return outcome;
+    } catch (e) {
+      debugPrint('❌ Payment failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Pay the penalty of one alarm occurrence at most once
+  ///
+  /// Retrying with the same alarm and scheduled time returns the recorded
+  /// outcome or resumes the interrupted payment.
+  Future<PaymentOutcome> payAlarmPenalty({
+    required String connectionString,
+    required int alarmId,
+    required DateTime scheduledAt,
+    required String recipient,
+    required int amountSats,
+    String? comment,
+  }) async {
+    try {
+      debugPrint('🔄 Starting alarm penalty: $amountSats sats → $recipient (alarm $alarmId @ $scheduledAt)');
+      
+      final outcome = await rust_api.payAlarmPenalty(
+        connectionString: connectionString,
+        alarmId: alarmId,
+        scheduledAt: BigInt.from(scheduledAt.millisecondsSinceEpoch ~/ 1000),
+        recipient: recipientFromString(recipient),
+        amountSats: BigInt.from(amountSats),
+        comment: comment,
+      );
+      
+      debugPrint('✅ Alarm penalty paid: ${outcome.paymentHash}');
+      final successText = successActionText(outcome.successAction);
+      if (successText != null) {
+        debugPrint('🎉 Success action: $successText');
+      }
+      return outcome;
+    } catch (e) {
+      debugPrint('❌ Alarm penalty failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Start the penalty countdown of a ringing alarm (returns the running one if already started)
+  Future<PenaltySession> startPenalty({
+    required int alarmId,
+    required DateTime scheduledAt,
+    required String recipient,
+    required int amountSats,
+    required int timeoutSeconds,
+    String? comment,
+  }) async {
+    try {
+      final session = await rust_api.startPenalty(
+        alarmId: alarmId,
+        scheduledAt: BigInt.from(scheduledAt.millisecondsSinceEpoch ~/ 1000),
+        recipient: recipientFromString(recipient),
+        amountSats: BigInt.from(amountSats),
+        comment: comment,
+        timeoutSecs: BigInt.from(timeoutSeconds),
+      );
+      debugPrint('✅ Penalty started: alarm $alarmId (${session.state.name})');
+      return session;
+    } catch (e) {
+      debugPrint('❌ Penalty start failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Stop an alarm's countdown in time (null if the alarm has no penalty)
+  ///
+  /// Also cancels a penalty payment whose request hasn't been sent yet.
+  Future<PenaltySession?> dismissPenalty(int alarmId) async {
+    try {
+      return await rust_api.dismissPenalty(alarmId: alarmId);
+    } catch (e) {
+      debugPrint('❌ Penalty dismiss failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Seconds left before an alarm's penalty is due (null if it has no penalty)
+  Future<int?> penaltyRemainingSeconds(int alarmId) async {
+    final remaining = await rust_api.penaltyRemainingSecs(alarmId: alarmId);
+    return remaining?.toInt();
+  }
+  
+  /// Current penalty session of an alarm
+  Future<PenaltySession?> getPenalty(int alarmId) {
+    return rust_api.getPenalty(alarmId: alarmId);
+  }
+  
+  /// Pay an alarm's penalty once its countdown has run out
+  ///
+  /// Check `state` of the result: paid, failed, or unknown (retry resumes it).
+  Future<PenaltySession> payDuePenalty({
+    required String connectionString,
+    required int alarmId,
+  }) async {
+    try {
+      debugPrint('🔄 Paying due penalty: alarm $alarmId');
+      final session = await rust_api.payDuePenalty(
+        connectionString: connectionString,
+        alarmId: alarmId,
+      );
+      if (session.state == PenaltyState.paid) {
+        debugPrint('✅ Penalty paid: ${session.outcome?.paymentHash}');
+      } else {
+        debugPrint('❌ Penalty ${session.state.name}: ${session.error}');
+      }
+      return session;
+    } catch (e) {
+      debugPrint('❌ Penalty payment failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Pay an alarm's due penalty, streaming each stage
+  ///
+  /// Ends with `PaymentProgress.completed`, or with the `ZapClockError` of
+  /// the payment as a stream error if the penalty was not paid (see
+  /// `getPenalty` for failed / unknown).
+  Stream<PaymentProgress> payDuePenaltyWithProgress({
+    required String connectionString,
+    required int alarmId,
+  }) {
+    debugPrint('🔄 Paying due penalty with progress: alarm $alarmId');
+    return rust_api
+        .payDuePenaltyWithProgress(connectionString: connectionString, alarmId: alarmId)
+        .map((progress) {
+      debugPrint('📶 Payment progress: $progress');
+      return _throwIfFailed(progress);
+    });
+  }
+  
+  /// Pay to a Lightning address, streaming each stage
+  ///
+  /// Ends with `PaymentProgress.completed`, or with the `ZapClockError` of
+  /// the payment as a stream error.
+  Stream<PaymentProgress> payWithProgress({
+    required String connectionString,
+    required String lightningAddress,
+    required int amountSats,
+    String? comment,
+  }) {
+    debugPrint('🔄 Starting NWC payment with progress: $amountSats sats → $lightningAddress');
+    return rust_api.payLightningInvoiceWithProgress(
+      connectionString: connectionString,
+      lightningAddress: lightningAddress,
+      amountSats: BigInt.from(amountSats),
+      comment: comment,
+    ).map(_throwIfFailed);
+  }
+  
+  /// Turn the final `failed` event into a typed stream error
+  PaymentProgress _throwIfFailed(PaymentProgress progress) => switch (progress) {
+        PaymentProgress_Failed(:final error) => throw error,
+        _ => progress,
+      };
+  
+  /// Pick up penalties after a restart; returns every unfinished session
+  Future<List<PenaltySession>> resumePenalties(String connectionString) async {
+    try {
+      final sessions = await rust_api.resumePenalties(connectionString: connectionString);
+      debugPrint('✅ ${sessions.length} unfinished penalties resumed');
+      return sessions;
+    } catch (e) {
+      debugPrint('❌ Penalty resume failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Latest attempt of every journaled payment, most recent first
+  Future<List<JournalPayment>> journalPayments() async {
+    try {
+      return await rust_api.listJournalPayments();
+    } catch (e) {
+      debugPrint('❌ Payment journal read failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Every recorded stage of one payment (or of all payments), oldest first
+  Future<List<JournalEntry>> journalEntries({String? paymentKey}) async {
+    try {
+      return await rust_api.listJournalEntries(paymentKey: paymentKey);
+    } catch (e) {
+      debugPrint('❌ Payment journal read failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Payments whose outcome is unknown (published, never verifiably answered)
+  Future<List<JournalPayment>> unknownPayments() async {
+    try {
+      return await rust_api.recoverUnknownPayments();
+    } catch (e) {
+      debugPrint('❌ Payment journal read failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Split a penalty across weighted recipients (e.g. {'a@x.com': 50, 'b@y.com': 30})
+  ///
+  /// Each share succeeds or fails on its own; check `outcome` / `error`.
+  Future<List<SplitShareOutcome>> paySplit({
+    required String connectionString,
+    required Map<String, int> weights,
+    required int amountSats,
+    String? comment,
+  }) async {
+    try {
+      debugPrint('🔄 Starting split payment: $amountSats sats → ${weights.length} recipients');
+      
+      final shares = await rust_api.paySplit(
+        connectionString: connectionString,
+        recipients: weights.entries
+            .map((e) => WeightedRecipient(address: e.key, weight: e.value))
+            .toList(),
+        amountSats: BigInt.from(amountSats),
+        comment: comment,
+      );
+      
+      for (final share in shares) {
+        if (share.outcome != null) {
+          debugPrint('✅ ${share.address}: ${share.amountSats} sats paid');
+        } else {
+          debugPrint('❌ ${share.address}: ${share.error}');
+        }
+      }
+      return shares;
+    } catch (e) {
+      debugPrint('❌ Split payment failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Pay a BOLT11 invoice directly (amount only needed for "any amount" invoices)
+  Future<PaymentOutcome> payBolt11({
+    required String connectionString,
+    required String bolt11,
+    int? amountSats,
+  }) async {
+    try {
+      debugPrint('🔄 Starting BOLT11 payment${amountSats != null ? ': $amountSats sats' : ''}');
+      
+      final outcome = await rust_api.payBolt11Invoice(
+        connectionString: connectionString,
+        bolt11: bolt11,
+        amountSats: amountSats != null ? BigInt.from(amountSats) : null,
+      );
+      
+      debugPrint('✅ BOLT11 payment successful: ${outcome.paymentHash}');
+      return outcome;
+    } catch (e) {
+      debugPrint('❌ BOLT11 payment failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Wait for the zap receipt (kind 9735) of a completed payment
+  Future<PaymentOutcome> confirmZapReceipt(
+    PaymentOutcome outcome, {
+    Duration timeout = const Duration(seconds: 30),
+  }) async {
+    if (outcome.zap == null) return outcome;
+    try {
+      final confirmed = await rust_api.confirmZapReceipt(
+        outcome: outcome,
+        timeoutSecs: BigInt.from(timeout.inSeconds),
+      );
+      if (confirmed.zap?.receipt is ZapReceiptStatus_Observed) {
+        debugPrint('⚡ Zap receipt observed: ${confirmed.zap!.requestId}');
+      } else {
+        debugPrint('⚠️ Zap receipt not observed: ${confirmed.zap?.requestId}');
+      }
+      return confirmed;
+    } catch (e) {
+      debugPrint('❌ Zap receipt check failed: $e');
+      rethrow;
+    }
+  }
+  
+  /// Wait for the zap receipt of an alarm's paid penalty and save it
+  ///
+  /// The receipt status is stored with the penalty session, so
+  /// `getPenalty` reports it afterwards.
+  Future<PenaltySession?> confirmPenaltyZapReceipt(
+    int alarmId, {
+    Duration timeout = const Duration(seconds: 30),
+  }) async {
+    try {
+      final session = await rust_api.confirmPenaltyZapReceipt(
+        alarmId: alarmId,
+        timeoutSecs: BigInt.from(timeout.inSeconds),
+      );
+      final zap = session?.outcome?.zap;
+      if (zap == null) return session;
+      if (zap.receipt is ZapReceiptStatus_Observed) {
+        debugPrint('⚡ Zap receipt observed: ${zap.requestId}');
+      } else {
+        debugPrint('⚠️ Zap receipt not observed: ${zap.requestId}');
+      }
+      return session;
+    } catch (e) {
+      debugPrint('❌ Zap receipt check failed: $e');
+      rethrow;
+    }
+  }
+  
   /// Pay Lightning Invoice (old method - kept for compatibility)
   Future<String> payInvoice({
     required String connectionString,
@@ -69,13 +589,13 @@ class NwcService {
   }) async {
     try {
       // Use Rust bridge
-      final paymentHash = await rust_api.payLightningInvoice(
+      final outcome = await rust_api.payLightningInvoice(
         connectionString: connectionString,
         lightningAddress: lightningAddress,
         amountSats: BigInt.from(amountSats),
         comment: comment,
       );
-      return paymentHash;
+      return outcome.paymentHash;
     } catch (e) {
       // Fallback to mock on error
       debugPrint('⚠️ Rust API failed, using mock: $e');
@@ -38,7 +38,11 @@ echo -e "${BLUE}ステップ1: Flutter Rust Bridge コード生成${NC}"
 flutter_rust_bridge_codegen generate
 
 echo ""
-echo -e "${BLUE}ステップ2: Android用Rustライブラリをビルド${NC}"
+echo -e "${BLUE}ステップ2: Dart sealed class 生成 (freezed)${NC}"
+fvm dart run build_runner build --delete-conflicting-outputs
+
+echo ""
+echo -e "${BLUE}ステップ3: Android用Rustライブラリをビルド${NC}"
 cd rust
 
 # Android用ビルド（複数アーキテクチャ）
@@ -1,71 +1,614 @@
-/// Module defining API functions called from Flutter side
+//! Module defining API functions called from Flutter side
 
-use crate::lightning::LightningPayment;
-use crate::nwc::NwcClient;
+pub use crate::error::ZapClockError;
+use crate::nwc::{
+    InvoiceLookup, NwcClient, NwcSession, TransactionFilter, WalletInfo, WalletTransaction,
+};
+use crate::lightning::{LightningPayment, ResolvedRecipient};
+use crate::payment::{
+    self, PaymentOutcome, PaymentRecipient, SplitShareOutcome, WeightedRecipient,
+};
+use crate::journal::{self, JournalEntry, JournalPayment};
+use crate::cancel::{CancelOutcome, PaymentCancel};
+use crate::network::{self, NetworkPolicy};
+use crate::penalty::{self, PenaltySession};
+use crate::progress::{PaymentProgress, ProgressReporter};
+use crate::store;
+use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
+use tracing::{error, info, instrument};
+use nostr_sdk::Timestamp;
+
+/// Open (or reuse) the NWC session for a connection string
+///
+/// The returned handle keeps the relay connection warm; later calls with
+/// the same connection string share it.
+#[frb]
+#[instrument(skip_all)]
+pub async fn connect_nwc(connection_string: String) -> Result<NwcSession, ZapClockError> {
+    info!("📞 connect_nwc called");
+    
+    let session = NwcSession::shared(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    session
+        .connect()
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Relay connection failed"))?;
+    
+    info!("✅ connect_nwc successful");
+    Ok(session)
+}
+
+/// Whether the session's relay connection is currently up
+#[frb(sync)]
+pub fn is_nwc_connected(session: &NwcSession) -> bool {
+    session.is_connected()
+}
+
+/// Close the session's relay connection
+#[frb(sync)]
+#[instrument(skip_all)]
+pub fn disconnect_nwc(session: &NwcSession) {
+    info!("📞 disconnect_nwc called");
+    session.disconnect();
+}
 
 /// Test NWC connection and get balance
 #[frb]
-pub async fn test_nwc_connection(connection_string: String) -> Result<u64, String> {
-    println!("📞 [API] test_nwc_connection called");
+#[instrument(skip_all)]
+pub async fn test_nwc_connection(connection_string: String) -> Result<u64, ZapClockError> {
+    info!("📞 test_nwc_connection called");
     
     let client = NwcClient::new(&connection_string)
-        .map_err(|e| {
-            println!("❌ [API] NWC connection initialization failed: {}", e);
-            format!("NWC connection initialization failed: {}", e)
-        })?;
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
     
     let balance = client.test_connection()
         .await
-        .map_err(|e| {
-            println!("❌ [API] Connection test failed: {}", e);
-            format!("Connection test failed: {}", e)
-        })?;
+        .inspect_err(|e| error!(error = %e, "❌ Connection test failed"))?;
     
-    println!("✅ [API] test_nwc_connection successful - balance: {} sats", balance);
+    info!(balance_sats = balance, "✅ test_nwc_connection successful");
     Ok(balance)
 }
 
+/// Get wallet capabilities (NIP-47 `get_info` and the wallet's info event)
+#[frb]
+#[instrument(skip_all)]
+pub async fn get_wallet_info(connection_string: String) -> Result<WalletInfo, ZapClockError> {
+    info!("📞 get_wallet_info called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let info = client
+        .get_wallet_info()
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Wallet info retrieval failed"))?;
+    
+    info!(can_pay_invoice = info.can_pay_invoice, "✅ get_wallet_info successful");
+    Ok(info)
+}
+
+/// List wallet transactions (e.g. to reconcile paid penalties)
+#[frb]
+#[instrument(skip(connection_string))]
+pub async fn list_wallet_transactions(
+    connection_string: String,
+    filter: TransactionFilter,
+) -> Result<Vec<WalletTransaction>, ZapClockError> {
+    info!("📞 list_wallet_transactions called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let transactions = client
+        .list_transactions(filter)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Transaction listing failed"))?;
+    
+    info!(transactions = transactions.len(), "✅ list_wallet_transactions successful");
+    Ok(transactions)
+}
+
+/// Look up an invoice by payment hash or bolt11
+#[frb]
+#[instrument(skip_all)]
+pub async fn lookup_wallet_invoice(
+    connection_string: String,
+    lookup: InvoiceLookup,
+) -> Result<WalletTransaction, ZapClockError> {
+    info!("📞 lookup_wallet_invoice called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let transaction = client
+        .lookup_invoice(lookup)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Invoice lookup failed"))?;
+    
+    info!("✅ lookup_wallet_invoice successful");
+    Ok(transaction)
+}
+
+/// Resolve a Lightning address without paying
+///
+/// Returns the sendable range, comment limit, metadata and zap support so
+/// recipients can be validated when they are added in settings.
+#[frb]
+#[instrument]
+pub async fn resolve_lightning_address(
+    lightning_address: String,
+) -> Result<ResolvedRecipient, ZapClockError> {
+    info!("📞 resolve_lightning_address called");
+    
+    let recipient = LightningPayment::new()
+        .resolve_address(&lightning_address)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Address resolution failed"))?;
+    
+    info!("✅ resolve_lightning_address successful");
+    Ok(recipient)
+}
+
 /// Execute Lightning payment
+///
+/// Returns a receipt with the invoice, payment hash and preimage.
 #[frb]
+#[instrument(skip(connection_string, comment))]
 pub async fn pay_lightning_invoice(
     connection_string: String,
     lightning_address: String,
     amount_sats: u64,
     comment: Option<String>,
-) -> Result<String, String> {
-    println!("📞 [API] pay_lightning_invoice called");
-    println!("   Address: {}", lightning_address);
-    println!("   Amount: {} sats", amount_sats);
-    if let Some(ref c) = comment {
-        println!("   Comment: {}", c);
+) -> Result<PaymentOutcome, ZapClockError> {
+    info!("📞 pay_lightning_invoice called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let outcome = payment::pay_to_address(&client, &lightning_address, amount_sats, comment)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Payment failed"))?;
+    
+    info!(payment_hash = %outcome.payment_hash, "✅ pay_lightning_invoice successful");
+    Ok(outcome)
+}
+
+/// Execute Lightning payment, streaming its progress
+///
+/// Emits an event per stage (resolving address, invoice received, relay
+/// connected, request published, response received, verified) and ends
+/// with `Completed`, or with `Failed` carrying the payment error.
+#[frb]
+#[instrument(skip(sink, connection_string, comment))]
+pub async fn pay_lightning_invoice_with_progress(
+    sink: StreamSink<PaymentProgress>,
+    connection_string: String,
+    lightning_address: String,
+    amount_sats: u64,
+    comment: Option<String>,
+) {
+    info!("📞 pay_lightning_invoice_with_progress called");
+    
+    let result = async {
+        let client = NwcClient::new(&connection_string)
+            .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?
+            .with_progress(progress_to(&sink));
+        payment::pay_to_address(&client, &lightning_address, amount_sats, comment)
+            .await
+            .inspect_err(|e| error!(error = %e, "❌ Payment failed"))
+    }
+    .await;
+    
+    if let Ok(outcome) = &result {
+        info!(payment_hash = %outcome.payment_hash, "✅ pay_lightning_invoice_with_progress successful");
+    }
+    end_stream(&sink, result);
+}
+
+/// End a progress stream with `Completed`, or `Failed` with the payment error
+///
+/// Errors of stream functions only reach Dart untyped, so they are sent
+/// as the last event instead.
+fn end_stream(sink: &StreamSink<PaymentProgress>, result: Result<PaymentOutcome, ZapClockError>) {
+    let last = match result {
+        Ok(outcome) => PaymentProgress::Completed {
+            outcome: Box::new(outcome),
+        },
+        Err(error) => PaymentProgress::Failed { error },
+    };
+    let _ = sink.add(last);
+}
+
+/// Forward payment progress to a Dart stream
+fn progress_to(sink: &StreamSink<PaymentProgress>) -> ProgressReporter {
+    let sink = sink.clone();
+    ProgressReporter::new(move |progress| {
+        // The Dart side may have stopped listening; the payment goes on
+        let _ = sink.add(progress);
+    })
+}
+
+/// Pay a BOLT11 invoice directly (e.g. a shared team pot)
+///
+/// `amount_sats` is only needed for invoices without an amount.
+#[frb]
+#[instrument(skip(connection_string, bolt11))]
+pub async fn pay_bolt11_invoice(
+    connection_string: String,
+    bolt11: String,
+    amount_sats: Option<u64>,
+) -> Result<PaymentOutcome, ZapClockError> {
+    info!("📞 pay_bolt11_invoice called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let outcome = payment::pay_bolt11(&client, &bolt11, amount_sats)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Payment failed"))?;
+    
+    info!(payment_hash = %outcome.payment_hash, "✅ pay_bolt11_invoice successful");
+    Ok(outcome)
+}
+
+/// Pay a penalty to any kind of recipient (LNURL, BOLT11 invoice or keysend)
+#[frb]
+#[instrument(
+    skip(connection_string, recipient, comment),
+    fields(recipient = %payment::recipient_label(&recipient)),
+)]
+pub async fn pay_recipient(
+    connection_string: String,
+    recipient: PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<PaymentOutcome, ZapClockError> {
+    info!("📞 pay_recipient called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let outcome = payment::pay_recipient(&client, &recipient, amount_sats, comment)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Payment failed"))?;
+    
+    info!(payment_hash = %outcome.payment_hash, "✅ pay_recipient successful");
+    Ok(outcome)
+}
+
+/// Set the directory payment records are kept in (call once at startup)
+#[frb]
+pub fn init_payment_store(directory: String) -> Result<(), ZapClockError> {
+    info!(directory, "📞 init_payment_store called");
+    store::set_directory(&directory)
+        .inspect_err(|e| error!(error = %e, "❌ Payment store initialization failed"))
+}
+
+/// Set the payment journal file (call once at startup)
+#[frb]
+pub fn init_payment_journal(path: String) -> Result<(), ZapClockError> {
+    info!(path, "📞 init_payment_journal called");
+    journal::set_path(&path)
+        .inspect_err(|e| error!(error = %e, "❌ Payment journal initialization failed"))
+}
+
+/// Set timeouts and retries for NWC and LNURL calls made from now on
+///
+/// Rejects policies with a zero timeout or deadline.
+#[frb(sync)]
+pub fn set_network_policy(policy: NetworkPolicy) -> Result<(), ZapClockError> {
+    info!(?policy, "📞 set_network_policy called");
+    network::set_policy(policy)
+        .inspect_err(|e| error!(error = %e, "❌ Network policy rejected"))
+}
+
+/// Network policy currently in use
+#[frb(sync)]
+pub fn get_network_policy() -> NetworkPolicy {
+    NetworkPolicy::current()
+}
+
+/// Built-in network policy (starting point for custom settings)
+#[frb(sync)]
+pub fn default_network_policy() -> NetworkPolicy {
+    NetworkPolicy::default()
+}
+
+/// Journal entries, oldest first (all payments, or only `payment_key`)
+#[frb]
+pub fn list_journal_entries(payment_key: Option<String>) -> Result<Vec<JournalEntry>, ZapClockError> {
+    journal::entries(payment_key.as_deref())
+        .inspect_err(|e| error!(error = %e, "❌ Journal read failed"))
+}
+
+/// Latest attempt of every journaled payment, most recent first
+#[frb]
+pub fn list_journal_payments() -> Result<Vec<JournalPayment>, ZapClockError> {
+    journal::payments().inspect_err(|e| error!(error = %e, "❌ Journal read failed"))
+}
+
+/// Payments left in an unknown state by a crash or lost connection
+///
+/// Call at startup; each may or may not have been paid. Paying the same
+/// alarm occurrence again with `pay_alarm_penalty` resumes it safely.
+#[frb]
+#[instrument]
+pub fn recover_unknown_payments() -> Result<Vec<JournalPayment>, ZapClockError> {
+    info!("📞 recover_unknown_payments called");
+    let payments = journal::unknown_payments()
+        .inspect_err(|e| error!(error = %e, "❌ Journal read failed"))?;
+    info!(unknown = payments.len(), "✅ recover_unknown_payments successful");
+    Ok(payments)
+}
+
+/// Pay the penalty of one alarm occurrence, at most once
+///
+/// `scheduled_at` is the unix time the alarm was scheduled to fire.
+/// Calling again for the same alarm occurrence (e.g. after a crash or a
+/// retry) returns the recorded outcome or resumes the in-flight payment
+/// instead of paying twice.
+#[frb]
+#[instrument(
+    skip(connection_string, recipient, comment),
+    fields(recipient = %payment::recipient_label(&recipient)),
+)]
+pub async fn pay_alarm_penalty(
+    connection_string: String,
+    alarm_id: i32,
+    scheduled_at: u64,
+    recipient: PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<PaymentOutcome, ZapClockError> {
+    let key = payment::idempotency_key(alarm_id, scheduled_at);
+    info!(payment_key = %key, "📞 pay_alarm_penalty called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let outcome = payment::pay_idempotent(&client, &key, &recipient, amount_sats, comment)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Payment failed"))?;
+    
+    info!(payment_hash = %outcome.payment_hash, "✅ pay_alarm_penalty successful");
+    Ok(outcome)
+}
+
+/// Start the penalty countdown of a ringing alarm
+///
+/// Calling again for the same alarm occurrence returns the running
+/// session, so the countdown is not reset by a restart.
+#[frb]
+#[instrument(skip(recipient, comment))]
+pub fn start_penalty(
+    alarm_id: i32,
+    scheduled_at: u64,
+    recipient: PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+    timeout_secs: u64,
+) -> Result<PenaltySession, ZapClockError> {
+    info!("📞 start_penalty called");
+    
+    let session = penalty::start(alarm_id, scheduled_at, recipient, amount_sats, comment, timeout_secs)
+        .inspect_err(|e| error!(error = %e, "❌ Penalty start failed"))?;
+    
+    info!(
+        remaining_secs = session.remaining_secs(Timestamp::now().as_u64()),
+        "✅ start_penalty successful"
+    );
+    Ok(session)
+}
+
+/// Stop an alarm's countdown in time (None if the alarm has no penalty)
+///
+/// A penalty whose payment has started is cancelled if its request hasn't
+/// been published yet; after that this fails with `PaymentCommitted`.
+#[frb]
+#[instrument]
+pub fn dismiss_penalty(alarm_id: i32) -> Result<Option<PenaltySession>, ZapClockError> {
+    info!("📞 dismiss_penalty called");
+    penalty::dismiss(alarm_id).inspect_err(|e| error!(error = %e, "❌ Penalty dismiss failed"))
+}
+
+/// Seconds left before an alarm's penalty is due (None if it has no penalty)
+#[frb]
+pub fn penalty_remaining_secs(alarm_id: i32) -> Result<Option<u64>, ZapClockError> {
+    penalty::remaining_secs(alarm_id)
+}
+
+/// Current penalty session of an alarm
+#[frb]
+pub fn get_penalty(alarm_id: i32) -> Result<Option<PenaltySession>, ZapClockError> {
+    penalty::load(alarm_id)
+}
+
+/// Pay an alarm's penalty once its countdown has run out
+///
+/// Payment failures are reported in the returned session's state
+/// (`Failed` or `Unknown`), not as an error.
+#[frb]
+#[instrument(skip(connection_string))]
+pub async fn pay_due_penalty(
+    connection_string: String,
+    alarm_id: i32,
+) -> Result<PenaltySession, ZapClockError> {
+    info!("📞 pay_due_penalty called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let session = penalty::pay_due(&client, alarm_id)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Penalty payment failed"))?;
+    
+    info!(state = ?session.state, "✅ pay_due_penalty finished");
+    Ok(session)
+}
+
+/// Pay an alarm's due penalty, streaming its progress
+///
+/// Same stages as `pay_lightning_invoice_with_progress`. If the penalty is
+/// not paid the stream ends with `Failed`; the session state
+/// (`Failed` / `Unknown`) can then be read with `get_penalty`.
+#[frb]
+#[instrument(skip(sink, connection_string))]
+pub async fn pay_due_penalty_with_progress(
+    sink: StreamSink<PaymentProgress>,
+    connection_string: String,
+    alarm_id: i32,
+) {
+    info!("📞 pay_due_penalty_with_progress called");
+    
+    let result = async {
+        let client = NwcClient::new(&connection_string)
+            .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?
+            .with_progress(progress_to(&sink));
+        let (session, result) = penalty::pay_due_with_result(&client, alarm_id)
+            .await
+            .inspect_err(|e| error!(error = %e, "❌ Penalty payment failed"))?;
+        info!(state = ?session.state, "✅ pay_due_penalty_with_progress finished");
+        result.inspect_err(|e| error!(state = %session.state, error = %e, "❌ Penalty payment did not succeed"))
     }
+    .await;
+    
+    end_stream(&sink, result);
+}
+
+/// Pick up penalty sessions after a process restart
+///
+/// Pays penalties that came due while the app was gone and resumes
+/// interrupted payments. Returns every unfinished session; countdowns
+/// still running must be re-armed by the caller.
+#[frb]
+#[instrument(skip_all)]
+pub async fn resume_penalties(connection_string: String) -> Result<Vec<PenaltySession>, ZapClockError> {
+    info!("📞 resume_penalties called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let sessions = penalty::resume(&client)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Penalty resume failed"))?;
+    
+    info!(sessions = sessions.len(), "✅ resume_penalties successful");
+    Ok(sessions)
+}
+
+/// Create a handle for cancelling a payment started with it
+#[frb(sync)]
+pub fn new_payment_cancel() -> PaymentCancel {
+    PaymentCancel::new()
+}
+
+/// Cancel a payment, if its request hasn't been published yet
+///
+/// `Cancelled` means nothing will be paid; `TooLate` means the request
+/// already reached the wallet and the payment may still complete.
+#[frb(sync)]
+#[instrument(skip_all)]
+pub fn cancel_payment(cancel: &PaymentCancel) -> CancelOutcome {
+    info!("📞 cancel_payment called");
+    let outcome = cancel.cancel();
+    info!(?outcome, "✅ cancel_payment finished");
+    outcome
+}
+
+/// Pay a recipient with a payment that `cancel` can stop
+///
+/// Fails with `Cancelled` if cancelled before the request was published.
+#[frb]
+#[instrument(
+    skip(cancel, connection_string, recipient, comment),
+    fields(recipient = %payment::recipient_label(&recipient)),
+)]
+pub async fn pay_recipient_cancellable(
+    cancel: &PaymentCancel,
+    connection_string: String,
+    recipient: PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<PaymentOutcome, ZapClockError> {
+    info!("📞 pay_recipient_cancellable called");
+    
+    let client = NwcClient::new(&connection_string)
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?
+        .with_cancel(cancel.clone());
     
-    // Get Invoice from Lightning address
-    let payment = LightningPayment::new();
-    let invoice = payment
-        .get_invoice_from_address(&lightning_address, amount_sats, comment)
+    let outcome = cancel
+        .run(payment::pay_recipient(&client, &recipient, amount_sats, comment))
         .await
-        .map_err(|e| {
-            println!("❌ [API] Invoice retrieval failed: {}", e);
-            format!("Invoice retrieval failed: {}", e)
-        })?;
+        .inspect_err(|e| error!(error = %e, "❌ Payment failed"))?;
+    
+    info!(payment_hash = %outcome.payment_hash, "✅ pay_recipient_cancellable successful");
+    Ok(outcome)
+}
+
+/// Split a penalty across weighted recipients (e.g. 50/30/20)
+///
+/// Returns one outcome per recipient; some shares may fail while others
+/// are paid.
+#[frb]
+#[instrument(skip(connection_string, recipients, comment), fields(recipients = recipients.len()))]
+pub async fn pay_split(
+    connection_string: String,
+    recipients: Vec<WeightedRecipient>,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<Vec<SplitShareOutcome>, ZapClockError> {
+    info!("📞 pay_split called");
     
-    // Pay via NWC
     let client = NwcClient::new(&connection_string)
-        .map_err(|e| {
-            println!("❌ [API] NWC connection initialization failed: {}", e);
-            format!("NWC connection initialization failed: {}", e)
-        })?;
+        .inspect_err(|e| error!(error = %e, "❌ NWC connection initialization failed"))?;
+    
+    let shares = payment::pay_split(&client, &recipients, amount_sats, comment)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Split payment failed"))?;
+    
+    let paid = shares.iter().filter(|s| s.outcome.is_some()).count();
+    info!(paid, shares = shares.len(), "✅ pay_split finished");
+    Ok(shares)
+}
+
+/// Wait for the zap receipt of an alarm's paid penalty and store it
+///
+/// The receipt status is saved with the penalty session and its payment
+/// record. Returns the updated session (None if the alarm has no penalty).
+#[frb]
+#[instrument]
+pub async fn confirm_penalty_zap_receipt(
+    alarm_id: i32,
+    timeout_secs: u64,
+) -> Result<Option<PenaltySession>, ZapClockError> {
+    info!("📞 confirm_penalty_zap_receipt called");
+    
+    let session = penalty::confirm_zap_receipt(alarm_id, timeout_secs)
+        .await
+        .inspect_err(|e| error!(error = %e, "❌ Zap receipt check failed"))?;
+    
+    info!("✅ confirm_penalty_zap_receipt finished");
+    Ok(session)
+}
+
+/// Wait for the NIP-57 zap receipt of a completed payment
+///
+/// Call after the alarm has been stopped; returns the outcome with its
+/// receipt status filled in (unchanged if the payment wasn't a zap).
+#[frb]
+#[instrument(skip(outcome), fields(payment_hash = %outcome.payment_hash))]
+pub async fn confirm_zap_receipt(
+    outcome: PaymentOutcome,
+    timeout_secs: u64,
+) -> Result<PaymentOutcome, ZapClockError> {
+    info!("📞 confirm_zap_receipt called");
     
-    let payment_hash = client
-        .pay_invoice(&invoice)
+    let outcome = payment::confirm_zap_receipt(outcome, timeout_secs)
         .await
-        .map_err(|e| {
-            println!("❌ [API] Payment failed: {}", e);
-            format!("Payment failed: {}", e)
-        })?;
+        .inspect_err(|e| error!(error = %e, "❌ Zap receipt check failed"))?;
     
-    println!("✅ [API] pay_lightning_invoice successful");
-    Ok(payment_hash)
+    info!("✅ confirm_zap_receipt finished");
+    Ok(outcome)
 }
@@ -0,0 +1,167 @@
+//! Cancellation of payments up to the point of no return
+//!
+//! A payment can be cancelled until its NIP-47 request is published.
+//! Publishing "commits" the handle; cancel and commit are decided by a
+//! single atomic swap, so a cancelled payment is never published.
+
+use crate::error::{Result, ZapClockError};
+use std::future::Future;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tracing::{info, warn};
+
+const ACTIVE: u8 = 0;
+const CANCELLED: u8 = 1;
+const COMMITTED: u8 = 2;
+
+/// What a cancel request achieved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelOutcome {
+    /// Stopped before the request was published: nothing will be paid
+    Cancelled,
+    /// Request already published: the payment may still complete
+    TooLate,
+    /// The payment had been cancelled before
+    AlreadyCancelled,
+}
+
+/// Handle to cancel an in-flight payment
+///
+/// Clones share the same state.
+#[derive(Clone, Default)]
+pub struct PaymentCancel {
+    inner: Arc<CancelState>,
+}
+
+#[derive(Default)]
+struct CancelState {
+    state: AtomicU8,
+    notify: Notify,
+}
+
+impl PaymentCancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the payment if it hasn't passed the point of no return
+    pub fn cancel(&self) -> CancelOutcome {
+        match self.swap(CANCELLED) {
+            Ok(()) => {
+                info!("🛑 Cancelled before the request was published");
+                self.inner.notify.notify_waiters();
+                CancelOutcome::Cancelled
+            }
+            Err(COMMITTED) => {
+                warn!("⚠️ Too late to cancel: request already published");
+                CancelOutcome::TooLate
+            }
+            Err(_) => CancelOutcome::AlreadyCancelled,
+        }
+    }
+
+    /// Pass the point of no return (just before publishing)
+    ///
+    /// Fails with `Cancelled` if the payment was cancelled first.
+    pub fn commit(&self) -> Result<()> {
+        match self.swap(COMMITTED) {
+            Ok(()) | Err(COMMITTED) => Ok(()),
+            Err(_) => Err(ZapClockError::Cancelled),
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.state.load(Ordering::SeqCst) == CANCELLED
+    }
+
+    /// Whether the point of no return was passed
+    pub fn is_committed(&self) -> bool {
+        self.inner.state.load(Ordering::SeqCst) == COMMITTED
+    }
+
+    /// Run `payment`, abandoning it as soon as the handle is cancelled
+    ///
+    /// Whatever `payment` was awaiting is dropped; since cancelling only
+    /// succeeds before `commit`, nothing it drops has been published.
+    pub async fn run<T>(&self, payment: impl Future<Output = Result<T>>) -> Result<T> {
+        tokio::select! {
+            result = payment => result,
+            _ = self.cancelled() => Err(ZapClockError::Cancelled),
+        }
+    }
+
+    /// Resolves once the handle is cancelled
+    async fn cancelled(&self) {
+        loop {
+            // Registered before the check, so a cancel in between isn't missed
+            let notified = self.inner.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Move from ACTIVE to `state`, or return the current state
+    fn swap(&self, state: u8) -> std::result::Result<(), u8> {
+        self.inner
+            .state
+            .compare_exchange(ACTIVE, state, Ordering::SeqCst, Ordering::SeqCst)
+            .map(|_| ())
+    }
+}
+
+impl std::fmt::Debug for PaymentCancel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaymentCancel")
+            .field("state", &self.inner.state.load(Ordering::SeqCst))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn cancel_before_commit_stops_the_payment() {
+        let cancel = PaymentCancel::new();
+        assert_eq!(cancel.cancel(), CancelOutcome::Cancelled);
+        assert!(cancel.is_cancelled());
+        assert!(matches!(cancel.commit(), Err(ZapClockError::Cancelled)));
+        assert!(!cancel.is_committed());
+        assert_eq!(cancel.cancel(), CancelOutcome::AlreadyCancelled);
+    }
+
+    #[test]
+    fn cancel_after_commit_is_too_late() {
+        let cancel = PaymentCancel::new();
+        assert!(cancel.commit().is_ok());
+        assert!(cancel.commit().is_ok());
+        assert_eq!(cancel.clone().cancel(), CancelOutcome::TooLate);
+        assert!(cancel.is_committed());
+        assert!(!cancel.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn cancel_wakes_a_running_payment() {
+        let cancel = PaymentCancel::new();
+        let handle = cancel.clone();
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            handle.cancel()
+        });
+
+        let result = cancel.run(std::future::pending::<Result<()>>()).await;
+        assert!(matches!(result, Err(ZapClockError::Cancelled)));
+        assert_eq!(canceller.await.unwrap(), CancelOutcome::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn run_returns_the_payment_result_when_not_cancelled() {
+        let cancel = PaymentCancel::new();
+        assert_eq!(cancel.run(async { Ok(21) }).await.unwrap(), 21);
+    }
+}
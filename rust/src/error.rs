@@ -0,0 +1,328 @@
+//! Error types shared across the Flutter boundary
+
+use flutter_rust_bridge::frb;
+use nostr::nips::nip47;
+use std::fmt;
+use thiserror::Error;
+
+/// Errors returned from the Rust core to the Flutter side
+///
+/// Exposed to Dart as a sealed class so the app can decide
+/// (keep ringing / retry / give up) without matching on strings.
+#[derive(Debug, Clone, Error)]
+pub enum ZapClockError {
+    /// Lightning address could not be parsed
+    #[error("Invalid Lightning address format: {address}")]
+    InvalidAddress { address: String },
+
+    /// HTTP error while talking to the LNURL-pay server
+    #[error("LNURL request failed ({url}): {message}")]
+    LnurlHttp {
+        url: String,
+        status: Option<u16>,
+        message: String,
+    },
+
+    /// LNURL server answered with a LUD-06 `{"status":"ERROR"}` response
+    #[error("LNURL server error ({url}): {reason}")]
+    LnurlServer { url: String, reason: String },
+
+    /// LNURL server answered with something that isn't a valid LNURL-pay response
+    #[error("Invalid LNURL response: {message}")]
+    InvalidLnurlResponse { message: String },
+
+    /// Requested amount is outside the recipient's sendable range
+    #[error("Amount out of range ({min_sats}-{max_sats} sats)")]
+    AmountOutOfRange {
+        amount_sats: u64,
+        min_sats: u64,
+        max_sats: u64,
+    },
+
+    /// Split recipients can't share the amount (no recipients or all weights zero)
+    #[error("Invalid split: {message}")]
+    InvalidSplit { message: String },
+
+    /// BOLT11 invoice could not be decoded
+    #[error("Invalid invoice: {message}")]
+    InvalidInvoice { message: String },
+
+    /// Keysend node key, preimage or TLV record is malformed
+    #[error("Invalid keysend payment: {message}")]
+    InvalidKeysend { message: String },
+
+    /// Invoice amount differs from the amount that was requested
+    #[error("Invoice amount mismatch (requested {expected_msats} msats, invoice {actual_msats:?} msats)")]
+    InvoiceAmountMismatch {
+        expected_msats: u64,
+        actual_msats: Option<u64>,
+    },
+
+    /// Invoice is for a different network (e.g. testnet instead of mainnet)
+    #[error("Invoice network mismatch (expected {expected}, got {actual})")]
+    InvoiceNetworkMismatch { expected: String, actual: String },
+
+    /// Invoice expired before it could be paid
+    #[error("Invoice expired at {expired_at}")]
+    InvoiceExpired { expired_at: u64 },
+
+    /// Invoice description hash doesn't commit to the LNURL metadata
+    #[error("Invoice description hash mismatch (expected {expected}, got {actual:?})")]
+    InvoiceDescriptionHashMismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+
+    /// Wallet reported success but the preimage doesn't match the invoice
+    ///
+    /// The payment may or may not have gone out; it must not be retried
+    /// automatically.
+    #[error("Unverified payment: preimage does not match payment hash {payment_hash}")]
+    PreimageMismatch {
+        payment_hash: String,
+        preimage: String,
+    },
+
+    /// NWC connection string could not be parsed
+    #[error("Invalid NWC connection string: {message}")]
+    InvalidConnectionString { message: String },
+
+    /// No response to a read-only request within the timeout
+    #[error("NWC request timed out ({seconds} seconds)")]
+    NwcTimeout { seconds: u64 },
+
+    /// Payment request may have reached the wallet, but no answer came back
+    ///
+    /// The payment may or may not have gone out. Sending it again could pay
+    /// twice; resume it with the same idempotency key instead.
+    #[error("Payment outcome unknown: {message}")]
+    PaymentUnknown { message: String },
+
+    /// Wallet service answered with a NIP-47 error code
+    #[error("Wallet error {code}: {message}")]
+    Wallet {
+        code: WalletErrorCode,
+        message: String,
+        /// Whether the same request may succeed if sent again later
+        retryable: bool,
+    },
+
+    /// Operation still failing when its overall deadline ran out
+    #[error("Gave up after {seconds} seconds")]
+    DeadlineExceeded { seconds: u64 },
+
+    /// Network policy with a zero timeout or deadline
+    #[error("Invalid network policy: {message}")]
+    InvalidNetworkPolicy { message: String },
+
+    /// Relay connection or publish failure
+    #[error("Relay error: {message}")]
+    Relay { message: String },
+
+    /// Payment was cancelled before its request was published
+    #[error("Payment cancelled before it was sent")]
+    Cancelled,
+
+    /// Payment request was already published and can't be cancelled
+    #[error("Payment request already sent; it can no longer be cancelled")]
+    PaymentCommitted,
+
+    /// Penalty session is not in a state that allows the action
+    #[error("Cannot {action} a penalty that is {state}")]
+    InvalidPenaltyTransition { state: String, action: String },
+
+    /// No penalty session exists for the alarm
+    #[error("No penalty session for alarm {alarm_id}")]
+    PenaltyNotFound { alarm_id: i32 },
+
+    /// Payment records could not be read or written
+    #[error("Payment store error: {message}")]
+    Storage { message: String },
+
+    /// Wallet response could not be decrypted or decoded
+    #[error("Invalid wallet response: {message}")]
+    InvalidResponse { message: String },
+}
+
+pub type Result<T, E = ZapClockError> = std::result::Result<T, E>;
+
+/// NIP-47 error codes returned by the wallet service
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletErrorCode {
+    /// The client is sending commands too fast
+    RateLimited,
+    /// The command is not known or is intentionally not implemented
+    NotImplemented,
+    /// Not enough funds to cover the payment amount and fee reserve
+    InsufficientBalance,
+    /// Payment failed (no route, timeout, insufficient capacity, ...)
+    PaymentFailed,
+    /// The invoice could not be found
+    NotFound,
+    /// The connection's spending budget is exhausted
+    QuotaExceeded,
+    /// This connection is not allowed to do this operation
+    Restricted,
+    /// No wallet is connected to this connection
+    Unauthorized,
+    /// Internal wallet error
+    Internal,
+    /// Other error
+    Other,
+}
+
+impl WalletErrorCode {
+    /// Whether a request rejected with this code is worth retrying
+    ///
+    /// Rate limits, routing failures and internal errors are transient.
+    /// Everything else (budget, balance, permissions) will fail again
+    /// until the user changes something in their wallet.
+    #[frb(sync)]
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited | Self::PaymentFailed | Self::Internal
+        )
+    }
+}
+
+impl fmt::Display for WalletErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Self::RateLimited => "RATE_LIMITED",
+            Self::NotImplemented => "NOT_IMPLEMENTED",
+            Self::InsufficientBalance => "INSUFFICIENT_BALANCE",
+            Self::PaymentFailed => "PAYMENT_FAILED",
+            Self::NotFound => "NOT_FOUND",
+            Self::QuotaExceeded => "QUOTA_EXCEEDED",
+            Self::Restricted => "RESTRICTED",
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::Internal => "INTERNAL",
+            Self::Other => "OTHER",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+impl From<nip47::ErrorCode> for WalletErrorCode {
+    fn from(code: nip47::ErrorCode) -> Self {
+        match code {
+            nip47::ErrorCode::RateLimited => Self::RateLimited,
+            nip47::ErrorCode::NotImplemented => Self::NotImplemented,
+            nip47::ErrorCode::InsufficientBalance => Self::InsufficientBalance,
+            nip47::ErrorCode::PaymentFailed => Self::PaymentFailed,
+            nip47::ErrorCode::NotFound => Self::NotFound,
+            nip47::ErrorCode::QuotaExceeded => Self::QuotaExceeded,
+            nip47::ErrorCode::Restricted => Self::Restricted,
+            nip47::ErrorCode::Unauthorized => Self::Unauthorized,
+            nip47::ErrorCode::Internal => Self::Internal,
+            nip47::ErrorCode::Other => Self::Other,
+        }
+    }
+}
+
+impl From<&str> for WalletErrorCode {
+    /// Parse a NIP-47 error code string (unknown codes map to `Other`)
+    fn from(code: &str) -> Self {
+        match code {
+            "RATE_LIMITED" => Self::RateLimited,
+            "NOT_IMPLEMENTED" => Self::NotImplemented,
+            "INSUFFICIENT_BALANCE" => Self::InsufficientBalance,
+            "PAYMENT_FAILED" => Self::PaymentFailed,
+            "NOT_FOUND" => Self::NotFound,
+            "QUOTA_EXCEEDED" => Self::QuotaExceeded,
+            "RESTRICTED" => Self::Restricted,
+            "UNAUTHORIZED" => Self::Unauthorized,
+            "INTERNAL" => Self::Internal,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<nip47::NIP47Error> for ZapClockError {
+    fn from(e: nip47::NIP47Error) -> Self {
+        Self::wallet(e.code.into(), e.message)
+    }
+}
+
+impl From<reqwest::Error> for ZapClockError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::LnurlHttp {
+            url: e.url().map(|u| u.to_string()).unwrap_or_default(),
+            status: e.status().map(|s| s.as_u16()),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl ZapClockError {
+    /// Build a wallet error, classifying it as retryable or terminal
+    pub(crate) fn wallet(code: WalletErrorCode, message: String) -> Self {
+        Self::Wallet {
+            code,
+            message,
+            retryable: code.is_retryable(),
+        }
+    }
+
+    /// Payment request failure once the request may have been published
+    pub(crate) fn payment_unknown(e: impl fmt::Display) -> Self {
+        Self::PaymentUnknown {
+            message: e.to_string(),
+        }
+    }
+
+    /// Whether the failed operation may succeed if attempted again
+    ///
+    /// Timeouts and relay failures of payment requests are reported as
+    /// `PaymentUnknown` once the request may have been published, so a
+    /// retryable error never hides a payment that may have gone out.
+    #[frb(sync)]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Wallet { retryable, .. } => *retryable,
+            Self::LnurlHttp { status, .. } => status.map_or(true, |s| s >= 500 || s == 429),
+            Self::NwcTimeout { .. } | Self::DeadlineExceeded { .. } | Self::Relay { .. } => true,
+            Self::InvalidAddress { .. }
+            | Self::LnurlServer { .. }
+            | Self::InvalidLnurlResponse { .. }
+            | Self::AmountOutOfRange { .. }
+            | Self::InvalidSplit { .. }
+            | Self::InvalidInvoice { .. }
+            | Self::InvalidKeysend { .. }
+            | Self::InvoiceAmountMismatch { .. }
+            | Self::InvoiceNetworkMismatch { .. }
+            | Self::InvoiceExpired { .. }
+            | Self::InvoiceDescriptionHashMismatch { .. }
+            | Self::PreimageMismatch { .. }
+            | Self::PaymentUnknown { .. }
+            | Self::InvalidConnectionString { .. }
+            | Self::InvalidNetworkPolicy { .. }
+            | Self::Cancelled
+            | Self::PaymentCommitted
+            | Self::InvalidPenaltyTransition { .. }
+            | Self::PenaltyNotFound { .. }
+            | Self::Storage { .. }
+            | Self::InvalidResponse { .. } => false,
+        }
+    }
+}
+
+impl From<nip47::Error> for ZapClockError {
+    fn from(e: nip47::Error) -> Self {
+        match e {
+            nip47::Error::ErrorCode(err) => err.into(),
+            other => Self::InvalidResponse {
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+impl From<nostr_sdk::pool::relay::Error> for ZapClockError {
+    fn from(e: nostr_sdk::pool::relay::Error) -> Self {
+        Self::Relay {
+            message: e.to_string(),
+        }
+    }
+}
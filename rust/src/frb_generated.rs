@@ -25,6 +25,8 @@
 
 // Section: imports
 
+use crate::cancel::*;
+use crate::nwc::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -37,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1368788448;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -2100985885;
 
 // Section: executor
 
@@ -45,7 +47,56 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__pay_lightning_invoice_impl(
+fn wire__crate__api__cancel_payment_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_payment",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cancel = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_cancel_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_cancel,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_cancel_guard = Some(api_cancel.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_cancel_guard = api_cancel_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::cancel_payment(&*api_cancel_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__confirm_penalty_zap_receipt_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +104,84 @@ fn wire__crate__api__pay_lightning_invoice_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pay_lightning_invoice",
+            debug_name: "confirm_penalty_zap_receipt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::confirm_penalty_zap_receipt(api_alarm_id, api_timeout_secs)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__confirm_zap_receipt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "confirm_zap_receipt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_outcome = <crate::payment::PaymentOutcome>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::confirm_zap_receipt(api_outcome, api_timeout_secs).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connect_nwc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "connect_nwc",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -68,20 +196,11 @@ fn wire__crate__api__pay_lightning_invoice_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_connection_string = <String>::sse_decode(&mut deserializer);
-            let api_lightning_address = <String>::sse_decode(&mut deserializer);
-            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
-            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::error::ZapClockError>(
                     (move || async move {
-                        let output_ok = crate::api::pay_lightning_invoice(
-                            api_connection_string,
-                            api_lightning_address,
-                            api_amount_sats,
-                            api_comment,
-                        )
-                        .await?;
+                        let output_ok = crate::api::connect_nwc(api_connection_string).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -90,7 +209,181 @@ fn wire__crate__api__pay_lightning_invoice_impl(
         },
     )
 }
-fn wire__crate__api__test_nwc_connection_impl(
+fn wire__crate__api__default_network_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_network_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_network_policy())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__disconnect_nwc_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "disconnect_nwc",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_session_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_session,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_session_guard = Some(api_session.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_session_guard = api_session_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::disconnect_nwc(&*api_session_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__dismiss_penalty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dismiss_penalty",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::dismiss_penalty(api_alarm_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_network_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_network_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_network_policy())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_penalty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_penalty",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::get_penalty(api_alarm_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_wallet_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -98,7 +391,7 @@ fn wire__crate__api__test_nwc_connection_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_nwc_connection",
+            debug_name: "get_wallet_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -115,10 +408,9 @@ fn wire__crate__api__test_nwc_connection_impl(
             let api_connection_string = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::error::ZapClockError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::test_nwc_connection(api_connection_string).await?;
+                        let output_ok = crate::api::get_wallet_info(api_connection_string).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -127,124 +419,3757 @@ fn wire__crate__api__test_nwc_connection_impl(
         },
     )
 }
+fn wire__crate__api__init_payment_journal_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_payment_journal",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::init_payment_journal(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init_payment_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_payment_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_directory = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::init_payment_store(api_directory)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__is_nwc_connected_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_nwc_connected",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_session_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_session,
+                            0,
+                            false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_session_guard = Some(api_session.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_session_guard = api_session_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::is_nwc_connected(&*api_session_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__list_journal_entries_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_journal_entries",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_payment_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::list_journal_entries(api_payment_key)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__list_journal_payments_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_journal_payments",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::list_journal_payments()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__list_wallet_transactions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_wallet_transactions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_filter = <crate::nwc::TransactionFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::list_wallet_transactions(api_connection_string, api_filter)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__lookup_wallet_invoice_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lookup_wallet_invoice",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_lookup = <crate::nwc::InvoiceLookup>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::lookup_wallet_invoice(api_connection_string, api_lookup)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__new_payment_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_payment_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::new_payment_cancel())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__pay_alarm_penalty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_alarm_penalty",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            let api_scheduled_at = <u64>::sse_decode(&mut deserializer);
+            let api_recipient = <crate::payment::PaymentRecipient>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok = crate::api::pay_alarm_penalty(
+                            api_connection_string,
+                            api_alarm_id,
+                            api_scheduled_at,
+                            api_recipient,
+                            api_amount_sats,
+                            api_comment,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_bolt11_invoice_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_bolt11_invoice",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_bolt11 = <String>::sse_decode(&mut deserializer);
+            let api_amount_sats = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok = crate::api::pay_bolt11_invoice(
+                            api_connection_string,
+                            api_bolt11,
+                            api_amount_sats,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_due_penalty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_due_penalty",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::pay_due_penalty(api_connection_string, api_alarm_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_due_penalty_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_due_penalty_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::progress::PaymentProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::pay_due_penalty_with_progress(
+                                api_sink,
+                                api_connection_string,
+                                api_alarm_id,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_lightning_invoice_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_lightning_invoice",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_lightning_address = <String>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok = crate::api::pay_lightning_invoice(
+                            api_connection_string,
+                            api_lightning_address,
+                            api_amount_sats,
+                            api_comment,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_lightning_invoice_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_lightning_invoice_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::progress::PaymentProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_lightning_address = <String>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::pay_lightning_invoice_with_progress(
+                                api_sink,
+                                api_connection_string,
+                                api_lightning_address,
+                                api_amount_sats,
+                                api_comment,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_recipient_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_recipient",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_recipient = <crate::payment::PaymentRecipient>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok = crate::api::pay_recipient(
+                            api_connection_string,
+                            api_recipient,
+                            api_amount_sats,
+                            api_comment,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_recipient_cancellable_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_recipient_cancellable",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cancel = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>,
+            >>::sse_decode(&mut deserializer);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_recipient = <crate::payment::PaymentRecipient>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let mut api_cancel_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_cancel,
+                                    0,
+                                    false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_cancel_guard =
+                                        Some(api_cancel.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_cancel_guard = api_cancel_guard.unwrap();
+                        let output_ok = crate::api::pay_recipient_cancellable(
+                            &*api_cancel_guard,
+                            api_connection_string,
+                            api_recipient,
+                            api_amount_sats,
+                            api_comment,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pay_split_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pay_split",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            let api_recipients =
+                <Vec<crate::payment::WeightedRecipient>>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok = crate::api::pay_split(
+                            api_connection_string,
+                            api_recipients,
+                            api_amount_sats,
+                            api_comment,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__penalty_remaining_secs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "penalty_remaining_secs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::penalty_remaining_secs(api_alarm_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recover_unknown_payments_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recover_unknown_payments",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::recover_unknown_payments()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__resolve_lightning_address_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_lightning_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_lightning_address = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::resolve_lightning_address(api_lightning_address).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__resume_penalties_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_penalties",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok = crate::api::resume_penalties(api_connection_string).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_network_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_network_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <crate::network::NetworkPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                let output_ok = crate::api::set_network_policy(api_policy)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__start_penalty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_penalty",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alarm_id = <i32>::sse_decode(&mut deserializer);
+            let api_scheduled_at = <u64>::sse_decode(&mut deserializer);
+            let api_recipient = <crate::payment::PaymentRecipient>::sse_decode(&mut deserializer);
+            let api_amount_sats = <u64>::sse_decode(&mut deserializer);
+            let api_comment = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::ZapClockError>((move || {
+                    let output_ok = crate::api::start_penalty(
+                        api_alarm_id,
+                        api_scheduled_at,
+                        api_recipient,
+                        api_amount_sats,
+                        api_comment,
+                        api_timeout_secs,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__test_nwc_connection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_nwc_connection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_connection_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::ZapClockError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::test_nwc_connection(api_connection_string).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__error__wallet_error_code_is_retryable_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_error_code_is_retryable",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::error::WalletErrorCode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::error::WalletErrorCode::is_retryable(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__error__zap_clock_error_is_retryable_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "zap_clock_error_is_retryable",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::error::ZapClockError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::error::ZapClockError::is_retryable(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for NwcSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for PaymentCancel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::progress::PaymentProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for Box<crate::payment::PaymentOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return Box::new(<crate::payment::PaymentOutcome>::sse_decode(deserializer));
+    }
+}
+
+impl SseDecode for crate::cancel::CancelOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::cancel::CancelOutcome::Cancelled,
+            1 => crate::cancel::CancelOutcome::TooLate,
+            2 => crate::cancel::CancelOutcome::AlreadyCancelled,
+            _ => unreachable!("Invalid variant for CancelOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::nwc::InvoiceLookup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::nwc::InvoiceLookup::PaymentHash(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::nwc::InvoiceLookup::Bolt11(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::journal::JournalEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentKey = <String>::sse_decode(deserializer);
+        let mut var_stage = <crate::journal::JournalStage>::sse_decode(deserializer);
+        let mut var_at = <u64>::sse_decode(deserializer);
+        let mut var_paymentHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_amountMsats = <Option<u64>>::sse_decode(deserializer);
+        let mut var_detail = <Option<String>>::sse_decode(deserializer);
+        return crate::journal::JournalEntry {
+            payment_key: var_paymentKey,
+            stage: var_stage,
+            at: var_at,
+            payment_hash: var_paymentHash,
+            amount_msats: var_amountMsats,
+            detail: var_detail,
+        };
+    }
+}
+
+impl SseDecode for crate::journal::JournalPayment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentKey = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::journal::JournalPaymentState>::sse_decode(deserializer);
+        let mut var_lastStage = <crate::journal::JournalStage>::sse_decode(deserializer);
+        let mut var_paymentHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_amountMsats = <Option<u64>>::sse_decode(deserializer);
+        let mut var_recipient = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_startedAt = <u64>::sse_decode(deserializer);
+        let mut var_updatedAt = <u64>::sse_decode(deserializer);
+        return crate::journal::JournalPayment {
+            payment_key: var_paymentKey,
+            state: var_state,
+            last_stage: var_lastStage,
+            payment_hash: var_paymentHash,
+            amount_msats: var_amountMsats,
+            recipient: var_recipient,
+            error: var_error,
+            started_at: var_startedAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::journal::JournalPaymentState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::journal::JournalPaymentState::NotSent,
+            1 => crate::journal::JournalPaymentState::Unknown,
+            2 => crate::journal::JournalPaymentState::Paid,
+            3 => crate::journal::JournalPaymentState::Failed,
+            _ => unreachable!("Invalid variant for JournalPaymentState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::journal::JournalStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::journal::JournalStage::Started,
+            1 => crate::journal::JournalStage::AddressResolved,
+            2 => crate::journal::JournalStage::InvoiceFetched,
+            3 => crate::journal::JournalStage::RequestSent,
+            4 => crate::journal::JournalStage::ResponseReceived,
+            5 => crate::journal::JournalStage::PreimageVerified,
+            6 => crate::journal::JournalStage::Failed,
+            _ => unreachable!("Invalid variant for JournalStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::journal::JournalEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::journal::JournalEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::journal::JournalPayment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::journal::JournalPayment>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::penalty::PenaltySession> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::penalty::PenaltySession>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::payment::SplitShareOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::payment::SplitShareOutcome>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::nwc::TlvRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::nwc::TlvRecord>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::nwc::WalletTransaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::nwc::WalletTransaction>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::payment::WeightedRecipient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::payment::WeightedRecipient>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::lightning::LnurlImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        return crate::lightning::LnurlImage {
+            mime_type: var_mimeType,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for crate::lightning::LnurlMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_longDescription = <Option<String>>::sse_decode(deserializer);
+        let mut var_image = <Option<crate::lightning::LnurlImage>>::sse_decode(deserializer);
+        let mut var_identifier = <Option<String>>::sse_decode(deserializer);
+        let mut var_email = <Option<String>>::sse_decode(deserializer);
+        return crate::lightning::LnurlMetadata {
+            description: var_description,
+            long_description: var_longDescription,
+            image: var_image,
+            identifier: var_identifier,
+            email: var_email,
+        };
+    }
+}
+
+impl SseDecode for crate::network::NetworkPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_relayConnectTimeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_relayQueryTimeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_walletTimeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_paymentTimeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_httpConnectTimeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_httpTimeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_maxRetries = <u32>::sse_decode(deserializer);
+        let mut var_retryBackoffMs = <u64>::sse_decode(deserializer);
+        let mut var_deadlineSecs = <u64>::sse_decode(deserializer);
+        return crate::network::NetworkPolicy {
+            relay_connect_timeout_secs: var_relayConnectTimeoutSecs,
+            relay_query_timeout_secs: var_relayQueryTimeoutSecs,
+            wallet_timeout_secs: var_walletTimeoutSecs,
+            payment_timeout_secs: var_paymentTimeoutSecs,
+            http_connect_timeout_secs: var_httpConnectTimeoutSecs,
+            http_timeout_secs: var_httpTimeoutSecs,
+            max_retries: var_maxRetries,
+            retry_backoff_ms: var_retryBackoffMs,
+            deadline_secs: var_deadlineSecs,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::lightning::LnurlImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::lightning::LnurlImage>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::payment::PaymentOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::payment::PaymentOutcome>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::penalty::PenaltySession> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::penalty::PenaltySession>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::lightning::SuccessAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::lightning::SuccessAction>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::nwc::TransactionType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::nwc::TransactionType>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::error::ZapClockError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::error::ZapClockError>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::zap::ZapInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::zap::ZapInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::payment::PaymentOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bolt11 = <Option<String>>::sse_decode(deserializer);
+        let mut var_paymentHash = <String>::sse_decode(deserializer);
+        let mut var_preimage = <String>::sse_decode(deserializer);
+        let mut var_amountMsats = <u64>::sse_decode(deserializer);
+        let mut var_feesPaidMsats = <Option<u64>>::sse_decode(deserializer);
+        let mut var_recipient = <String>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        let mut var_successAction =
+            <Option<crate::lightning::SuccessAction>>::sse_decode(deserializer);
+        let mut var_zap = <Option<crate::zap::ZapInfo>>::sse_decode(deserializer);
+        let mut var_startedAt = <u64>::sse_decode(deserializer);
+        let mut var_paidAt = <u64>::sse_decode(deserializer);
+        return crate::payment::PaymentOutcome {
+            bolt11: var_bolt11,
+            payment_hash: var_paymentHash,
+            preimage: var_preimage,
+            amount_msats: var_amountMsats,
+            fees_paid_msats: var_feesPaidMsats,
+            recipient: var_recipient,
+            comment: var_comment,
+            success_action: var_successAction,
+            zap: var_zap,
+            started_at: var_startedAt,
+            paid_at: var_paidAt,
+        };
+    }
+}
+
+impl SseDecode for crate::progress::PaymentProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_address = <String>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::ResolvingAddress {
+                    address: var_address,
+                };
+            }
+            1 => {
+                let mut var_amountMsats = <u64>::sse_decode(deserializer);
+                let mut var_paymentHash = <String>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::InvoiceReceived {
+                    amount_msats: var_amountMsats,
+                    payment_hash: var_paymentHash,
+                };
+            }
+            2 => {
+                let mut var_relay = <String>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::RelayConnected { relay: var_relay };
+            }
+            3 => {
+                let mut var_requestId = <String>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::RequestPublished {
+                    request_id: var_requestId,
+                };
+            }
+            4 => {
+                return crate::progress::PaymentProgress::ResponseReceived;
+            }
+            5 => {
+                let mut var_paymentHash = <String>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::Verified {
+                    payment_hash: var_paymentHash,
+                };
+            }
+            6 => {
+                let mut var_outcome =
+                    <Box<crate::payment::PaymentOutcome>>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::Completed {
+                    outcome: var_outcome,
+                };
+            }
+            7 => {
+                let mut var_error = <crate::error::ZapClockError>::sse_decode(deserializer);
+                return crate::progress::PaymentProgress::Failed { error: var_error };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::payment::PaymentRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_address = <String>::sse_decode(deserializer);
+                return crate::payment::PaymentRecipient::Lnurl {
+                    address: var_address,
+                };
+            }
+            1 => {
+                let mut var_invoice = <String>::sse_decode(deserializer);
+                return crate::payment::PaymentRecipient::Bolt11 {
+                    invoice: var_invoice,
+                };
+            }
+            2 => {
+                let mut var_pubkey = <String>::sse_decode(deserializer);
+                let mut var_tlvRecords = <Vec<crate::nwc::TlvRecord>>::sse_decode(deserializer);
+                let mut var_preimage = <Option<String>>::sse_decode(deserializer);
+                return crate::payment::PaymentRecipient::Keysend {
+                    pubkey: var_pubkey,
+                    tlv_records: var_tlvRecords,
+                    preimage: var_preimage,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::penalty::PenaltySession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_alarmId = <i32>::sse_decode(deserializer);
+        let mut var_scheduledAt = <u64>::sse_decode(deserializer);
+        let mut var_state = <crate::penalty::PenaltyState>::sse_decode(deserializer);
+        let mut var_recipient = <crate::payment::PaymentRecipient>::sse_decode(deserializer);
+        let mut var_amountSats = <u64>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        let mut var_timeoutSecs = <u64>::sse_decode(deserializer);
+        let mut var_ringingSince = <u64>::sse_decode(deserializer);
+        let mut var_countdownStartedAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_outcome = <Option<crate::payment::PaymentOutcome>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <u64>::sse_decode(deserializer);
+        return crate::penalty::PenaltySession {
+            alarm_id: var_alarmId,
+            scheduled_at: var_scheduledAt,
+            state: var_state,
+            recipient: var_recipient,
+            amount_sats: var_amountSats,
+            comment: var_comment,
+            timeout_secs: var_timeoutSecs,
+            ringing_since: var_ringingSince,
+            countdown_started_at: var_countdownStartedAt,
+            outcome: var_outcome,
+            error: var_error,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::penalty::PenaltyState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::penalty::PenaltyState::Ringing,
+            1 => crate::penalty::PenaltyState::CountingDown,
+            2 => crate::penalty::PenaltyState::Dismissed,
+            3 => crate::penalty::PenaltyState::Paying,
+            4 => crate::penalty::PenaltyState::Paid,
+            5 => crate::penalty::PenaltyState::Failed,
+            6 => crate::penalty::PenaltyState::Unknown,
+            _ => unreachable!("Invalid variant for PenaltyState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::lightning::ResolvedRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_endpoint = <String>::sse_decode(deserializer);
+        let mut var_metadata = <crate::lightning::LnurlMetadata>::sse_decode(deserializer);
+        let mut var_minSendableMsats = <u64>::sse_decode(deserializer);
+        let mut var_maxSendableMsats = <u64>::sse_decode(deserializer);
+        let mut var_commentAllowed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_supportsZaps = <bool>::sse_decode(deserializer);
+        let mut var_nostrPubkey = <Option<String>>::sse_decode(deserializer);
+        return crate::lightning::ResolvedRecipient {
+            address: var_address,
+            endpoint: var_endpoint,
+            metadata: var_metadata,
+            min_sendable_msats: var_minSendableMsats,
+            max_sendable_msats: var_maxSendableMsats,
+            comment_allowed: var_commentAllowed,
+            supports_zaps: var_supportsZaps,
+            nostr_pubkey: var_nostrPubkey,
+        };
+    }
+}
+
+impl SseDecode for crate::payment::SplitShareOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_amountSats = <u64>::sse_decode(deserializer);
+        let mut var_outcome = <Option<crate::payment::PaymentOutcome>>::sse_decode(deserializer);
+        let mut var_error = <Option<crate::error::ZapClockError>>::sse_decode(deserializer);
+        return crate::payment::SplitShareOutcome {
+            address: var_address,
+            amount_sats: var_amountSats,
+            outcome: var_outcome,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::lightning::SuccessAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::lightning::SuccessAction::Message {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_description = <String>::sse_decode(deserializer);
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::lightning::SuccessAction::Url {
+                    description: var_description,
+                    url: var_url,
+                };
+            }
+            2 => {
+                let mut var_description = <String>::sse_decode(deserializer);
+                let mut var_plaintext = <Option<String>>::sse_decode(deserializer);
+                return crate::lightning::SuccessAction::Aes {
+                    description: var_description,
+                    plaintext: var_plaintext,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::nwc::TlvRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_recordType = <u64>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::nwc::TlvRecord {
+            record_type: var_recordType,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::nwc::TransactionFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_from = <Option<u64>>::sse_decode(deserializer);
+        let mut var_until = <Option<u64>>::sse_decode(deserializer);
+        let mut var_limit = <Option<u64>>::sse_decode(deserializer);
+        let mut var_offset = <Option<u64>>::sse_decode(deserializer);
+        let mut var_unpaid = <Option<bool>>::sse_decode(deserializer);
+        let mut var_transactionType =
+            <Option<crate::nwc::TransactionType>>::sse_decode(deserializer);
+        return crate::nwc::TransactionFilter {
+            from: var_from,
+            until: var_until,
+            limit: var_limit,
+            offset: var_offset,
+            unpaid: var_unpaid,
+            transaction_type: var_transactionType,
+        };
+    }
+}
+
+impl SseDecode for crate::nwc::TransactionType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::nwc::TransactionType::Incoming,
+            1 => crate::nwc::TransactionType::Outgoing,
+            _ => unreachable!("Invalid variant for TransactionType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::error::WalletErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::error::WalletErrorCode::RateLimited,
+            1 => crate::error::WalletErrorCode::NotImplemented,
+            2 => crate::error::WalletErrorCode::InsufficientBalance,
+            3 => crate::error::WalletErrorCode::PaymentFailed,
+            4 => crate::error::WalletErrorCode::NotFound,
+            5 => crate::error::WalletErrorCode::QuotaExceeded,
+            6 => crate::error::WalletErrorCode::Restricted,
+            7 => crate::error::WalletErrorCode::Unauthorized,
+            8 => crate::error::WalletErrorCode::Internal,
+            9 => crate::error::WalletErrorCode::Other,
+            _ => unreachable!("Invalid variant for WalletErrorCode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::nwc::WalletInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_alias = <Option<String>>::sse_decode(deserializer);
+        let mut var_color = <Option<String>>::sse_decode(deserializer);
+        let mut var_pubkey = <Option<String>>::sse_decode(deserializer);
+        let mut var_network = <Option<String>>::sse_decode(deserializer);
+        let mut var_blockHeight = <Option<u32>>::sse_decode(deserializer);
+        let mut var_blockHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_methods = <Vec<String>>::sse_decode(deserializer);
+        let mut var_notifications = <Vec<String>>::sse_decode(deserializer);
+        let mut var_encryption = <Vec<String>>::sse_decode(deserializer);
+        let mut var_getInfoSupported = <bool>::sse_decode(deserializer);
+        let mut var_canPayInvoice = <bool>::sse_decode(deserializer);
+        return crate::nwc::WalletInfo {
+            alias: var_alias,
+            color: var_color,
+            pubkey: var_pubkey,
+            network: var_network,
+            block_height: var_blockHeight,
+            block_hash: var_blockHash,
+            methods: var_methods,
+            notifications: var_notifications,
+            encryption: var_encryption,
+            get_info_supported: var_getInfoSupported,
+            can_pay_invoice: var_canPayInvoice,
+        };
+    }
+}
+
+impl SseDecode for crate::nwc::WalletTransaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_transactionType =
+            <Option<crate::nwc::TransactionType>>::sse_decode(deserializer);
+        let mut var_invoice = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_descriptionHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_preimage = <Option<String>>::sse_decode(deserializer);
+        let mut var_paymentHash = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_feesPaid = <u64>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_expiresAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_settledAt = <Option<u64>>::sse_decode(deserializer);
+        return crate::nwc::WalletTransaction {
+            transaction_type: var_transactionType,
+            invoice: var_invoice,
+            description: var_description,
+            description_hash: var_descriptionHash,
+            preimage: var_preimage,
+            payment_hash: var_paymentHash,
+            amount: var_amount,
+            fees_paid: var_feesPaid,
+            created_at: var_createdAt,
+            expires_at: var_expiresAt,
+            settled_at: var_settledAt,
+        };
+    }
+}
+
+impl SseDecode for crate::payment::WeightedRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_weight = <u32>::sse_decode(deserializer);
+        return crate::payment::WeightedRecipient {
+            address: var_address,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for crate::error::ZapClockError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_address = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidAddress {
+                    address: var_address,
+                };
+            }
+            1 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                let mut var_status = <Option<u16>>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::LnurlHttp {
+                    url: var_url,
+                    status: var_status,
+                    message: var_message,
+                };
+            }
+            2 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::LnurlServer {
+                    url: var_url,
+                    reason: var_reason,
+                };
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidLnurlResponse {
+                    message: var_message,
+                };
+            }
+            4 => {
+                let mut var_amountSats = <u64>::sse_decode(deserializer);
+                let mut var_minSats = <u64>::sse_decode(deserializer);
+                let mut var_maxSats = <u64>::sse_decode(deserializer);
+                return crate::error::ZapClockError::AmountOutOfRange {
+                    amount_sats: var_amountSats,
+                    min_sats: var_minSats,
+                    max_sats: var_maxSats,
+                };
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidSplit {
+                    message: var_message,
+                };
+            }
+            6 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidInvoice {
+                    message: var_message,
+                };
+            }
+            7 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidKeysend {
+                    message: var_message,
+                };
+            }
+            8 => {
+                let mut var_expectedMsats = <u64>::sse_decode(deserializer);
+                let mut var_actualMsats = <Option<u64>>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvoiceAmountMismatch {
+                    expected_msats: var_expectedMsats,
+                    actual_msats: var_actualMsats,
+                };
+            }
+            9 => {
+                let mut var_expected = <String>::sse_decode(deserializer);
+                let mut var_actual = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvoiceNetworkMismatch {
+                    expected: var_expected,
+                    actual: var_actual,
+                };
+            }
+            10 => {
+                let mut var_expiredAt = <u64>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvoiceExpired {
+                    expired_at: var_expiredAt,
+                };
+            }
+            11 => {
+                let mut var_expected = <String>::sse_decode(deserializer);
+                let mut var_actual = <Option<String>>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvoiceDescriptionHashMismatch {
+                    expected: var_expected,
+                    actual: var_actual,
+                };
+            }
+            12 => {
+                let mut var_paymentHash = <String>::sse_decode(deserializer);
+                let mut var_preimage = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::PreimageMismatch {
+                    payment_hash: var_paymentHash,
+                    preimage: var_preimage,
+                };
+            }
+            13 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidConnectionString {
+                    message: var_message,
+                };
+            }
+            14 => {
+                let mut var_seconds = <u64>::sse_decode(deserializer);
+                return crate::error::ZapClockError::NwcTimeout {
+                    seconds: var_seconds,
+                };
+            }
+            15 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::PaymentUnknown {
+                    message: var_message,
+                };
+            }
+            16 => {
+                let mut var_code = <crate::error::WalletErrorCode>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                let mut var_retryable = <bool>::sse_decode(deserializer);
+                return crate::error::ZapClockError::Wallet {
+                    code: var_code,
+                    message: var_message,
+                    retryable: var_retryable,
+                };
+            }
+            17 => {
+                let mut var_seconds = <u64>::sse_decode(deserializer);
+                return crate::error::ZapClockError::DeadlineExceeded {
+                    seconds: var_seconds,
+                };
+            }
+            18 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidNetworkPolicy {
+                    message: var_message,
+                };
+            }
+            19 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::Relay {
+                    message: var_message,
+                };
+            }
+            20 => {
+                return crate::error::ZapClockError::Cancelled;
+            }
+            21 => {
+                return crate::error::ZapClockError::PaymentCommitted;
+            }
+            22 => {
+                let mut var_state = <String>::sse_decode(deserializer);
+                let mut var_action = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidPenaltyTransition {
+                    state: var_state,
+                    action: var_action,
+                };
+            }
+            23 => {
+                let mut var_alarmId = <i32>::sse_decode(deserializer);
+                return crate::error::ZapClockError::PenaltyNotFound {
+                    alarm_id: var_alarmId,
+                };
+            }
+            24 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::Storage {
+                    message: var_message,
+                };
+            }
+            25 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::ZapClockError::InvalidResponse {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::zap::ZapInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_requestId = <String>::sse_decode(deserializer);
+        let mut var_requestJson = <String>::sse_decode(deserializer);
+        let mut var_serverPubkey = <String>::sse_decode(deserializer);
+        let mut var_receipt = <crate::zap::ZapReceiptStatus>::sse_decode(deserializer);
+        return crate::zap::ZapInfo {
+            request_id: var_requestId,
+            request_json: var_requestJson,
+            server_pubkey: var_serverPubkey,
+            receipt: var_receipt,
+        };
+    }
+}
+
+impl SseDecode for crate::zap::ZapReceiptStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::zap::ZapReceiptStatus::Pending;
+            }
+            1 => {
+                let mut var_receiptId = <String>::sse_decode(deserializer);
+                return crate::zap::ZapReceiptStatus::Observed {
+                    receipt_id: var_receiptId,
+                };
+            }
+            2 => {
+                return crate::zap::ZapReceiptStatus::NotObserved;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__confirm_penalty_zap_receipt_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__confirm_zap_receipt_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__connect_nwc_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__dismiss_penalty_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__get_penalty_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__get_wallet_info_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__init_payment_journal_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__init_payment_store_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__list_journal_entries_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__list_journal_payments_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__list_wallet_transactions_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__lookup_wallet_invoice_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__pay_alarm_penalty_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__pay_bolt11_invoice_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__pay_due_penalty_impl(port, ptr, rust_vec_len, data_len),
+        22 => {
+            wire__crate__api__pay_due_penalty_with_progress_impl(port, ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__pay_lightning_invoice_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__pay_lightning_invoice_with_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__pay_recipient_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__pay_recipient_cancellable_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__pay_split_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__penalty_remaining_secs_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__recover_unknown_payments_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__resolve_lightning_address_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__resume_penalties_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__start_penalty_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__test_nwc_connection_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__cancel_payment_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__default_network_policy_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__disconnect_nwc_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__get_network_policy_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__is_nwc_connected_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__new_payment_cancel_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__set_network_policy_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__error__wallet_error_code_is_retryable_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__error__zap_clock_error_is_retryable_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<NwcSession> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<NwcSession> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<NwcSession>> for NwcSession {
+    fn into_into_dart(self) -> FrbWrapper<NwcSession> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<PaymentCancel> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PaymentCancel> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PaymentCancel>> for PaymentCancel {
+    fn into_into_dart(self) -> FrbWrapper<PaymentCancel> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::cancel::CancelOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Cancelled => 0.into_dart(),
+            Self::TooLate => 1.into_dart(),
+            Self::AlreadyCancelled => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::cancel::CancelOutcome {}
+impl flutter_rust_bridge::IntoIntoDart<crate::cancel::CancelOutcome>
+    for crate::cancel::CancelOutcome
+{
+    fn into_into_dart(self) -> crate::cancel::CancelOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::nwc::InvoiceLookup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::nwc::InvoiceLookup::PaymentHash(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::nwc::InvoiceLookup::Bolt11(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::nwc::InvoiceLookup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::nwc::InvoiceLookup> for crate::nwc::InvoiceLookup {
+    fn into_into_dart(self) -> crate::nwc::InvoiceLookup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::journal::JournalEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment_key.into_into_dart().into_dart(),
+            self.stage.into_into_dart().into_dart(),
+            self.at.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.amount_msats.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::journal::JournalEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::journal::JournalEntry>
+    for crate::journal::JournalEntry
+{
+    fn into_into_dart(self) -> crate::journal::JournalEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::journal::JournalPayment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment_key.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.last_stage.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.amount_msats.into_into_dart().into_dart(),
+            self.recipient.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::journal::JournalPayment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::journal::JournalPayment>
+    for crate::journal::JournalPayment
+{
+    fn into_into_dart(self) -> crate::journal::JournalPayment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::journal::JournalPaymentState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NotSent => 0.into_dart(),
+            Self::Unknown => 1.into_dart(),
+            Self::Paid => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::journal::JournalPaymentState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::journal::JournalPaymentState>
+    for crate::journal::JournalPaymentState
+{
+    fn into_into_dart(self) -> crate::journal::JournalPaymentState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::journal::JournalStage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Started => 0.into_dart(),
+            Self::AddressResolved => 1.into_dart(),
+            Self::InvoiceFetched => 2.into_dart(),
+            Self::RequestSent => 3.into_dart(),
+            Self::ResponseReceived => 4.into_dart(),
+            Self::PreimageVerified => 5.into_dart(),
+            Self::Failed => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::journal::JournalStage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::journal::JournalStage>
+    for crate::journal::JournalStage
+{
+    fn into_into_dart(self) -> crate::journal::JournalStage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::lightning::LnurlImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mime_type.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::lightning::LnurlImage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::lightning::LnurlImage>
+    for crate::lightning::LnurlImage
+{
+    fn into_into_dart(self) -> crate::lightning::LnurlImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::lightning::LnurlMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.description.into_into_dart().into_dart(),
+            self.long_description.into_into_dart().into_dart(),
+            self.image.into_into_dart().into_dart(),
+            self.identifier.into_into_dart().into_dart(),
+            self.email.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::lightning::LnurlMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::lightning::LnurlMetadata>
+    for crate::lightning::LnurlMetadata
+{
+    fn into_into_dart(self) -> crate::lightning::LnurlMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::network::NetworkPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.relay_connect_timeout_secs.into_into_dart().into_dart(),
+            self.relay_query_timeout_secs.into_into_dart().into_dart(),
+            self.wallet_timeout_secs.into_into_dart().into_dart(),
+            self.payment_timeout_secs.into_into_dart().into_dart(),
+            self.http_connect_timeout_secs.into_into_dart().into_dart(),
+            self.http_timeout_secs.into_into_dart().into_dart(),
+            self.max_retries.into_into_dart().into_dart(),
+            self.retry_backoff_ms.into_into_dart().into_dart(),
+            self.deadline_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::network::NetworkPolicy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::network::NetworkPolicy>
+    for crate::network::NetworkPolicy
+{
+    fn into_into_dart(self) -> crate::network::NetworkPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::payment::PaymentOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bolt11.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.preimage.into_into_dart().into_dart(),
+            self.amount_msats.into_into_dart().into_dart(),
+            self.fees_paid_msats.into_into_dart().into_dart(),
+            self.recipient.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.success_action.into_into_dart().into_dart(),
+            self.zap.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.paid_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::payment::PaymentOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::payment::PaymentOutcome>
+    for crate::payment::PaymentOutcome
+{
+    fn into_into_dart(self) -> crate::payment::PaymentOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::progress::PaymentProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::progress::PaymentProgress::ResolvingAddress { address } => {
+                [0.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            crate::progress::PaymentProgress::InvoiceReceived {
+                amount_msats,
+                payment_hash,
+            } => [
+                1.into_dart(),
+                amount_msats.into_into_dart().into_dart(),
+                payment_hash.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::progress::PaymentProgress::RelayConnected { relay } => {
+                [2.into_dart(), relay.into_into_dart().into_dart()].into_dart()
+            }
+            crate::progress::PaymentProgress::RequestPublished { request_id } => {
+                [3.into_dart(), request_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::progress::PaymentProgress::ResponseReceived => [4.into_dart()].into_dart(),
+            crate::progress::PaymentProgress::Verified { payment_hash } => {
+                [5.into_dart(), payment_hash.into_into_dart().into_dart()].into_dart()
+            }
+            crate::progress::PaymentProgress::Completed { outcome } => {
+                [6.into_dart(), outcome.into_into_dart().into_dart()].into_dart()
+            }
+            crate::progress::PaymentProgress::Failed { error } => {
+                [7.into_dart(), error.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::progress::PaymentProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::progress::PaymentProgress>
+    for crate::progress::PaymentProgress
+{
+    fn into_into_dart(self) -> crate::progress::PaymentProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::payment::PaymentRecipient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::payment::PaymentRecipient::Lnurl { address } => {
+                [0.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            crate::payment::PaymentRecipient::Bolt11 { invoice } => {
+                [1.into_dart(), invoice.into_into_dart().into_dart()].into_dart()
+            }
+            crate::payment::PaymentRecipient::Keysend {
+                pubkey,
+                tlv_records,
+                preimage,
+            } => [
+                2.into_dart(),
+                pubkey.into_into_dart().into_dart(),
+                tlv_records.into_into_dart().into_dart(),
+                preimage.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::payment::PaymentRecipient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::payment::PaymentRecipient>
+    for crate::payment::PaymentRecipient
+{
+    fn into_into_dart(self) -> crate::payment::PaymentRecipient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::penalty::PenaltySession {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.alarm_id.into_into_dart().into_dart(),
+            self.scheduled_at.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.recipient.into_into_dart().into_dart(),
+            self.amount_sats.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.timeout_secs.into_into_dart().into_dart(),
+            self.ringing_since.into_into_dart().into_dart(),
+            self.countdown_started_at.into_into_dart().into_dart(),
+            self.outcome.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::penalty::PenaltySession
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::penalty::PenaltySession>
+    for crate::penalty::PenaltySession
+{
+    fn into_into_dart(self) -> crate::penalty::PenaltySession {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::penalty::PenaltyState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Ringing => 0.into_dart(),
+            Self::CountingDown => 1.into_dart(),
+            Self::Dismissed => 2.into_dart(),
+            Self::Paying => 3.into_dart(),
+            Self::Paid => 4.into_dart(),
+            Self::Failed => 5.into_dart(),
+            Self::Unknown => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::penalty::PenaltyState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::penalty::PenaltyState>
+    for crate::penalty::PenaltyState
+{
+    fn into_into_dart(self) -> crate::penalty::PenaltyState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::lightning::ResolvedRecipient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.endpoint.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.min_sendable_msats.into_into_dart().into_dart(),
+            self.max_sendable_msats.into_into_dart().into_dart(),
+            self.comment_allowed.into_into_dart().into_dart(),
+            self.supports_zaps.into_into_dart().into_dart(),
+            self.nostr_pubkey.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::lightning::ResolvedRecipient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::lightning::ResolvedRecipient>
+    for crate::lightning::ResolvedRecipient
+{
+    fn into_into_dart(self) -> crate::lightning::ResolvedRecipient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::payment::SplitShareOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.amount_sats.into_into_dart().into_dart(),
+            self.outcome.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::payment::SplitShareOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::payment::SplitShareOutcome>
+    for crate::payment::SplitShareOutcome
+{
+    fn into_into_dart(self) -> crate::payment::SplitShareOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::lightning::SuccessAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::lightning::SuccessAction::Message { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::lightning::SuccessAction::Url { description, url } => [
+                1.into_dart(),
+                description.into_into_dart().into_dart(),
+                url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::lightning::SuccessAction::Aes {
+                description,
+                plaintext,
+            } => [
+                2.into_dart(),
+                description.into_into_dart().into_dart(),
+                plaintext.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::lightning::SuccessAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::lightning::SuccessAction>
+    for crate::lightning::SuccessAction
+{
+    fn into_into_dart(self) -> crate::lightning::SuccessAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::nwc::TlvRecord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.record_type.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::nwc::TlvRecord {}
+impl flutter_rust_bridge::IntoIntoDart<crate::nwc::TlvRecord> for crate::nwc::TlvRecord {
+    fn into_into_dart(self) -> crate::nwc::TlvRecord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::nwc::TransactionFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from.into_into_dart().into_dart(),
+            self.until.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.unpaid.into_into_dart().into_dart(),
+            self.transaction_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::nwc::TransactionFilter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::nwc::TransactionFilter>
+    for crate::nwc::TransactionFilter
+{
+    fn into_into_dart(self) -> crate::nwc::TransactionFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::nwc::TransactionType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Incoming => 0.into_dart(),
+            Self::Outgoing => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::nwc::TransactionType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::nwc::TransactionType>
+    for crate::nwc::TransactionType
+{
+    fn into_into_dart(self) -> crate::nwc::TransactionType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::WalletErrorCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::RateLimited => 0.into_dart(),
+            Self::NotImplemented => 1.into_dart(),
+            Self::InsufficientBalance => 2.into_dart(),
+            Self::PaymentFailed => 3.into_dart(),
+            Self::NotFound => 4.into_dart(),
+            Self::QuotaExceeded => 5.into_dart(),
+            Self::Restricted => 6.into_dart(),
+            Self::Unauthorized => 7.into_dart(),
+            Self::Internal => 8.into_dart(),
+            Self::Other => 9.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::WalletErrorCode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::WalletErrorCode>
+    for crate::error::WalletErrorCode
+{
+    fn into_into_dart(self) -> crate::error::WalletErrorCode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::nwc::WalletInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.alias.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.block_height.into_into_dart().into_dart(),
+            self.block_hash.into_into_dart().into_dart(),
+            self.methods.into_into_dart().into_dart(),
+            self.notifications.into_into_dart().into_dart(),
+            self.encryption.into_into_dart().into_dart(),
+            self.get_info_supported.into_into_dart().into_dart(),
+            self.can_pay_invoice.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::nwc::WalletInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::nwc::WalletInfo> for crate::nwc::WalletInfo {
+    fn into_into_dart(self) -> crate::nwc::WalletInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::nwc::WalletTransaction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.transaction_type.into_into_dart().into_dart(),
+            self.invoice.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.description_hash.into_into_dart().into_dart(),
+            self.preimage.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fees_paid.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+            self.settled_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::nwc::WalletTransaction {}
+impl flutter_rust_bridge::IntoIntoDart<crate::nwc::WalletTransaction>
+    for crate::nwc::WalletTransaction
+{
+    fn into_into_dart(self) -> crate::nwc::WalletTransaction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::payment::WeightedRecipient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::payment::WeightedRecipient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::payment::WeightedRecipient>
+    for crate::payment::WeightedRecipient
+{
+    fn into_into_dart(self) -> crate::payment::WeightedRecipient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::ZapClockError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::error::ZapClockError::InvalidAddress { address } => {
+                [0.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::LnurlHttp {
+                url,
+                status,
+                message,
+            } => [
+                1.into_dart(),
+                url.into_into_dart().into_dart(),
+                status.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::LnurlServer { url, reason } => [
+                2.into_dart(),
+                url.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::InvalidLnurlResponse { message } => {
+                [3.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::AmountOutOfRange {
+                amount_sats,
+                min_sats,
+                max_sats,
+            } => [
+                4.into_dart(),
+                amount_sats.into_into_dart().into_dart(),
+                min_sats.into_into_dart().into_dart(),
+                max_sats.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::InvalidSplit { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::InvalidInvoice { message } => {
+                [6.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::InvalidKeysend { message } => {
+                [7.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::InvoiceAmountMismatch {
+                expected_msats,
+                actual_msats,
+            } => [
+                8.into_dart(),
+                expected_msats.into_into_dart().into_dart(),
+                actual_msats.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::InvoiceNetworkMismatch { expected, actual } => [
+                9.into_dart(),
+                expected.into_into_dart().into_dart(),
+                actual.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::InvoiceExpired { expired_at } => {
+                [10.into_dart(), expired_at.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::InvoiceDescriptionHashMismatch { expected, actual } => [
+                11.into_dart(),
+                expected.into_into_dart().into_dart(),
+                actual.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::PreimageMismatch {
+                payment_hash,
+                preimage,
+            } => [
+                12.into_dart(),
+                payment_hash.into_into_dart().into_dart(),
+                preimage.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::InvalidConnectionString { message } => {
+                [13.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::NwcTimeout { seconds } => {
+                [14.into_dart(), seconds.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::PaymentUnknown { message } => {
+                [15.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::Wallet {
+                code,
+                message,
+                retryable,
+            } => [
+                16.into_dart(),
+                code.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+                retryable.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::DeadlineExceeded { seconds } => {
+                [17.into_dart(), seconds.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::InvalidNetworkPolicy { message } => {
+                [18.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::Relay { message } => {
+                [19.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::Cancelled => [20.into_dart()].into_dart(),
+            crate::error::ZapClockError::PaymentCommitted => [21.into_dart()].into_dart(),
+            crate::error::ZapClockError::InvalidPenaltyTransition { state, action } => [
+                22.into_dart(),
+                state.into_into_dart().into_dart(),
+                action.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::ZapClockError::PenaltyNotFound { alarm_id } => {
+                [23.into_dart(), alarm_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::Storage { message } => {
+                [24.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::ZapClockError::InvalidResponse { message } => {
+                [25.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::ZapClockError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::ZapClockError>
+    for crate::error::ZapClockError
+{
+    fn into_into_dart(self) -> crate::error::ZapClockError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::zap::ZapInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.request_id.into_into_dart().into_dart(),
+            self.request_json.into_into_dart().into_dart(),
+            self.server_pubkey.into_into_dart().into_dart(),
+            self.receipt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::zap::ZapInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::zap::ZapInfo> for crate::zap::ZapInfo {
+    fn into_into_dart(self) -> crate::zap::ZapInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::zap::ZapReceiptStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::zap::ZapReceiptStatus::Pending => [0.into_dart()].into_dart(),
+            crate::zap::ZapReceiptStatus::Observed { receipt_id } => {
+                [1.into_dart(), receipt_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::zap::ZapReceiptStatus::NotObserved => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::zap::ZapReceiptStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::zap::ZapReceiptStatus>
+    for crate::zap::ZapReceiptStatus
+{
+    fn into_into_dart(self) -> crate::zap::ZapReceiptStatus {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for NwcSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for PaymentCancel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::progress::PaymentProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for Box<crate::payment::PaymentOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::payment::PaymentOutcome>::sse_encode(*self, serializer);
+    }
+}
+
+impl SseEncode for crate::cancel::CancelOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::cancel::CancelOutcome::Cancelled => 0,
+                crate::cancel::CancelOutcome::TooLate => 1,
+                crate::cancel::CancelOutcome::AlreadyCancelled => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::nwc::InvoiceLookup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::nwc::InvoiceLookup::PaymentHash(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::nwc::InvoiceLookup::Bolt11(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::journal::JournalEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.payment_key, serializer);
+        <crate::journal::JournalStage>::sse_encode(self.stage, serializer);
+        <u64>::sse_encode(self.at, serializer);
+        <Option<String>>::sse_encode(self.payment_hash, serializer);
+        <Option<u64>>::sse_encode(self.amount_msats, serializer);
+        <Option<String>>::sse_encode(self.detail, serializer);
+    }
+}
+
+impl SseEncode for crate::journal::JournalPayment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.payment_key, serializer);
+        <crate::journal::JournalPaymentState>::sse_encode(self.state, serializer);
+        <crate::journal::JournalStage>::sse_encode(self.last_stage, serializer);
+        <Option<String>>::sse_encode(self.payment_hash, serializer);
+        <Option<u64>>::sse_encode(self.amount_msats, serializer);
+        <Option<String>>::sse_encode(self.recipient, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <u64>::sse_encode(self.started_at, serializer);
+        <u64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::journal::JournalPaymentState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::journal::JournalPaymentState::NotSent => 0,
+                crate::journal::JournalPaymentState::Unknown => 1,
+                crate::journal::JournalPaymentState::Paid => 2,
+                crate::journal::JournalPaymentState::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::journal::JournalStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::journal::JournalStage::Started => 0,
+                crate::journal::JournalStage::AddressResolved => 1,
+                crate::journal::JournalStage::InvoiceFetched => 2,
+                crate::journal::JournalStage::RequestSent => 3,
+                crate::journal::JournalStage::ResponseReceived => 4,
+                crate::journal::JournalStage::PreimageVerified => 5,
+                crate::journal::JournalStage::Failed => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::journal::JournalEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::journal::JournalEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::journal::JournalPayment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::journal::JournalPayment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::penalty::PenaltySession> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::penalty::PenaltySession>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::payment::SplitShareOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::payment::SplitShareOutcome>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::nwc::TlvRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::nwc::TlvRecord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::nwc::WalletTransaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::nwc::WalletTransaction>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::payment::WeightedRecipient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::payment::WeightedRecipient>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::lightning::LnurlImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mime_type, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
+    }
+}
+
+impl SseEncode for crate::lightning::LnurlMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.long_description, serializer);
+        <Option<crate::lightning::LnurlImage>>::sse_encode(self.image, serializer);
+        <Option<String>>::sse_encode(self.identifier, serializer);
+        <Option<String>>::sse_encode(self.email, serializer);
+    }
+}
+
+impl SseEncode for crate::network::NetworkPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.relay_connect_timeout_secs, serializer);
+        <u64>::sse_encode(self.relay_query_timeout_secs, serializer);
+        <u64>::sse_encode(self.wallet_timeout_secs, serializer);
+        <u64>::sse_encode(self.payment_timeout_secs, serializer);
+        <u64>::sse_encode(self.http_connect_timeout_secs, serializer);
+        <u64>::sse_encode(self.http_timeout_secs, serializer);
+        <u32>::sse_encode(self.max_retries, serializer);
+        <u64>::sse_encode(self.retry_backoff_ms, serializer);
+        <u64>::sse_encode(self.deadline_secs, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
 
-// Section: dart2rust
+impl SseEncode for Option<crate::lightning::LnurlImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::lightning::LnurlImage>::sse_encode(value, serializer);
+        }
+    }
+}
 
-impl SseDecode for String {
+impl SseEncode for Option<crate::payment::PaymentOutcome> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::payment::PaymentOutcome>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Option<crate::penalty::PenaltySession> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::penalty::PenaltySession>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Option<crate::lightning::SuccessAction> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::lightning::SuccessAction>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Option<crate::nwc::TransactionType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::nwc::TransactionType>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__pay_lightning_invoice_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__test_nwc_connection_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<crate::error::ZapClockError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::error::ZapClockError>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Option<crate::zap::ZapInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::zap::ZapInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::payment::PaymentOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.bolt11, serializer);
+        <String>::sse_encode(self.payment_hash, serializer);
+        <String>::sse_encode(self.preimage, serializer);
+        <u64>::sse_encode(self.amount_msats, serializer);
+        <Option<u64>>::sse_encode(self.fees_paid_msats, serializer);
+        <String>::sse_encode(self.recipient, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+        <Option<crate::lightning::SuccessAction>>::sse_encode(self.success_action, serializer);
+        <Option<crate::zap::ZapInfo>>::sse_encode(self.zap, serializer);
+        <u64>::sse_encode(self.started_at, serializer);
+        <u64>::sse_encode(self.paid_at, serializer);
+    }
+}
 
-impl SseEncode for String {
+impl SseEncode for crate::progress::PaymentProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        match self {
+            crate::progress::PaymentProgress::ResolvingAddress { address } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(address, serializer);
+            }
+            crate::progress::PaymentProgress::InvoiceReceived {
+                amount_msats,
+                payment_hash,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <u64>::sse_encode(amount_msats, serializer);
+                <String>::sse_encode(payment_hash, serializer);
+            }
+            crate::progress::PaymentProgress::RelayConnected { relay } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(relay, serializer);
+            }
+            crate::progress::PaymentProgress::RequestPublished { request_id } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(request_id, serializer);
+            }
+            crate::progress::PaymentProgress::ResponseReceived => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::progress::PaymentProgress::Verified { payment_hash } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(payment_hash, serializer);
+            }
+            crate::progress::PaymentProgress::Completed { outcome } => {
+                <i32>::sse_encode(6, serializer);
+                <Box<crate::payment::PaymentOutcome>>::sse_encode(outcome, serializer);
+            }
+            crate::progress::PaymentProgress::Failed { error } => {
+                <i32>::sse_encode(7, serializer);
+                <crate::error::ZapClockError>::sse_encode(error, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::payment::PaymentRecipient {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        match self {
+            crate::payment::PaymentRecipient::Lnurl { address } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(address, serializer);
+            }
+            crate::payment::PaymentRecipient::Bolt11 { invoice } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(invoice, serializer);
+            }
+            crate::payment::PaymentRecipient::Keysend {
+                pubkey,
+                tlv_records,
+                preimage,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(pubkey, serializer);
+                <Vec<crate::nwc::TlvRecord>>::sse_encode(tlv_records, serializer);
+                <Option<String>>::sse_encode(preimage, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::penalty::PenaltySession {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+        <i32>::sse_encode(self.alarm_id, serializer);
+        <u64>::sse_encode(self.scheduled_at, serializer);
+        <crate::penalty::PenaltyState>::sse_encode(self.state, serializer);
+        <crate::payment::PaymentRecipient>::sse_encode(self.recipient, serializer);
+        <u64>::sse_encode(self.amount_sats, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+        <u64>::sse_encode(self.timeout_secs, serializer);
+        <u64>::sse_encode(self.ringing_since, serializer);
+        <Option<u64>>::sse_encode(self.countdown_started_at, serializer);
+        <Option<crate::payment::PaymentOutcome>>::sse_encode(self.outcome, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <u64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::penalty::PenaltyState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::penalty::PenaltyState::Ringing => 0,
+                crate::penalty::PenaltyState::CountingDown => 1,
+                crate::penalty::PenaltyState::Dismissed => 2,
+                crate::penalty::PenaltyState::Paying => 3,
+                crate::penalty::PenaltyState::Paid => 4,
+                crate::penalty::PenaltyState::Failed => 5,
+                crate::penalty::PenaltyState::Unknown => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::lightning::ResolvedRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.endpoint, serializer);
+        <crate::lightning::LnurlMetadata>::sse_encode(self.metadata, serializer);
+        <u64>::sse_encode(self.min_sendable_msats, serializer);
+        <u64>::sse_encode(self.max_sendable_msats, serializer);
+        <Option<u64>>::sse_encode(self.comment_allowed, serializer);
+        <bool>::sse_encode(self.supports_zaps, serializer);
+        <Option<String>>::sse_encode(self.nostr_pubkey, serializer);
+    }
+}
+
+impl SseEncode for crate::payment::SplitShareOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u64>::sse_encode(self.amount_sats, serializer);
+        <Option<crate::payment::PaymentOutcome>>::sse_encode(self.outcome, serializer);
+        <Option<crate::error::ZapClockError>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::lightning::SuccessAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::lightning::SuccessAction::Message { message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::lightning::SuccessAction::Url { description, url } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(description, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::lightning::SuccessAction::Aes {
+                description,
+                plaintext,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(description, serializer);
+                <Option<String>>::sse_encode(plaintext, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
+impl SseEncode for crate::nwc::TlvRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.record_type, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::nwc::TransactionFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.from, serializer);
+        <Option<u64>>::sse_encode(self.until, serializer);
+        <Option<u64>>::sse_encode(self.limit, serializer);
+        <Option<u64>>::sse_encode(self.offset, serializer);
+        <Option<bool>>::sse_encode(self.unpaid, serializer);
+        <Option<crate::nwc::TransactionType>>::sse_encode(self.transaction_type, serializer);
+    }
+}
+
+impl SseEncode for crate::nwc::TransactionType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::nwc::TransactionType::Incoming => 0,
+                crate::nwc::TransactionType::Outgoing => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -259,17 +4184,258 @@ impl SseEncode for u8 {
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::error::WalletErrorCode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::error::WalletErrorCode::RateLimited => 0,
+                crate::error::WalletErrorCode::NotImplemented => 1,
+                crate::error::WalletErrorCode::InsufficientBalance => 2,
+                crate::error::WalletErrorCode::PaymentFailed => 3,
+                crate::error::WalletErrorCode::NotFound => 4,
+                crate::error::WalletErrorCode::QuotaExceeded => 5,
+                crate::error::WalletErrorCode::Restricted => 6,
+                crate::error::WalletErrorCode::Unauthorized => 7,
+                crate::error::WalletErrorCode::Internal => 8,
+                crate::error::WalletErrorCode::Other => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::nwc::WalletInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.alias, serializer);
+        <Option<String>>::sse_encode(self.color, serializer);
+        <Option<String>>::sse_encode(self.pubkey, serializer);
+        <Option<String>>::sse_encode(self.network, serializer);
+        <Option<u32>>::sse_encode(self.block_height, serializer);
+        <Option<String>>::sse_encode(self.block_hash, serializer);
+        <Vec<String>>::sse_encode(self.methods, serializer);
+        <Vec<String>>::sse_encode(self.notifications, serializer);
+        <Vec<String>>::sse_encode(self.encryption, serializer);
+        <bool>::sse_encode(self.get_info_supported, serializer);
+        <bool>::sse_encode(self.can_pay_invoice, serializer);
+    }
+}
+
+impl SseEncode for crate::nwc::WalletTransaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::nwc::TransactionType>>::sse_encode(self.transaction_type, serializer);
+        <Option<String>>::sse_encode(self.invoice, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.description_hash, serializer);
+        <Option<String>>::sse_encode(self.preimage, serializer);
+        <String>::sse_encode(self.payment_hash, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.fees_paid, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+        <Option<u64>>::sse_encode(self.expires_at, serializer);
+        <Option<u64>>::sse_encode(self.settled_at, serializer);
+    }
+}
+
+impl SseEncode for crate::payment::WeightedRecipient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u32>::sse_encode(self.weight, serializer);
+    }
+}
+
+impl SseEncode for crate::error::ZapClockError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::error::ZapClockError::InvalidAddress { address } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(address, serializer);
+            }
+            crate::error::ZapClockError::LnurlHttp {
+                url,
+                status,
+                message,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(url, serializer);
+                <Option<u16>>::sse_encode(status, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::LnurlServer { url, reason } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(url, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::error::ZapClockError::InvalidLnurlResponse { message } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::AmountOutOfRange {
+                amount_sats,
+                min_sats,
+                max_sats,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <u64>::sse_encode(amount_sats, serializer);
+                <u64>::sse_encode(min_sats, serializer);
+                <u64>::sse_encode(max_sats, serializer);
+            }
+            crate::error::ZapClockError::InvalidSplit { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::InvalidInvoice { message } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::InvalidKeysend { message } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::InvoiceAmountMismatch {
+                expected_msats,
+                actual_msats,
+            } => {
+                <i32>::sse_encode(8, serializer);
+                <u64>::sse_encode(expected_msats, serializer);
+                <Option<u64>>::sse_encode(actual_msats, serializer);
+            }
+            crate::error::ZapClockError::InvoiceNetworkMismatch { expected, actual } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(expected, serializer);
+                <String>::sse_encode(actual, serializer);
+            }
+            crate::error::ZapClockError::InvoiceExpired { expired_at } => {
+                <i32>::sse_encode(10, serializer);
+                <u64>::sse_encode(expired_at, serializer);
+            }
+            crate::error::ZapClockError::InvoiceDescriptionHashMismatch { expected, actual } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(expected, serializer);
+                <Option<String>>::sse_encode(actual, serializer);
+            }
+            crate::error::ZapClockError::PreimageMismatch {
+                payment_hash,
+                preimage,
+            } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(payment_hash, serializer);
+                <String>::sse_encode(preimage, serializer);
+            }
+            crate::error::ZapClockError::InvalidConnectionString { message } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::NwcTimeout { seconds } => {
+                <i32>::sse_encode(14, serializer);
+                <u64>::sse_encode(seconds, serializer);
+            }
+            crate::error::ZapClockError::PaymentUnknown { message } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::Wallet {
+                code,
+                message,
+                retryable,
+            } => {
+                <i32>::sse_encode(16, serializer);
+                <crate::error::WalletErrorCode>::sse_encode(code, serializer);
+                <String>::sse_encode(message, serializer);
+                <bool>::sse_encode(retryable, serializer);
+            }
+            crate::error::ZapClockError::DeadlineExceeded { seconds } => {
+                <i32>::sse_encode(17, serializer);
+                <u64>::sse_encode(seconds, serializer);
+            }
+            crate::error::ZapClockError::InvalidNetworkPolicy { message } => {
+                <i32>::sse_encode(18, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::Relay { message } => {
+                <i32>::sse_encode(19, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::Cancelled => {
+                <i32>::sse_encode(20, serializer);
+            }
+            crate::error::ZapClockError::PaymentCommitted => {
+                <i32>::sse_encode(21, serializer);
+            }
+            crate::error::ZapClockError::InvalidPenaltyTransition { state, action } => {
+                <i32>::sse_encode(22, serializer);
+                <String>::sse_encode(state, serializer);
+                <String>::sse_encode(action, serializer);
+            }
+            crate::error::ZapClockError::PenaltyNotFound { alarm_id } => {
+                <i32>::sse_encode(23, serializer);
+                <i32>::sse_encode(alarm_id, serializer);
+            }
+            crate::error::ZapClockError::Storage { message } => {
+                <i32>::sse_encode(24, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::ZapClockError::InvalidResponse { message } => {
+                <i32>::sse_encode(25, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::zap::ZapInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.request_id, serializer);
+        <String>::sse_encode(self.request_json, serializer);
+        <String>::sse_encode(self.server_pubkey, serializer);
+        <crate::zap::ZapReceiptStatus>::sse_encode(self.receipt, serializer);
+    }
+}
+
+impl SseEncode for crate::zap::ZapReceiptStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::zap::ZapReceiptStatus::Pending => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::zap::ZapReceiptStatus::Observed { receipt_id } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(receipt_id, serializer);
+            }
+            crate::zap::ZapReceiptStatus::NotObserved => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
@@ -281,6 +4447,8 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::cancel::*;
+    use crate::nwc::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -290,6 +4458,34 @@ mod io {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_zap_clock_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_zap_clock_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_zap_clock_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_zap_clock_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>::decrement_strong_count(ptr as _);
+    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
@@ -303,6 +4499,8 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::cancel::*;
+    use crate::nwc::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -314,6 +4512,34 @@ mod web {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNwcSession(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NwcSession>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaymentCancel(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaymentCancel>>::decrement_strong_count(ptr as _);
+    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;
@@ -0,0 +1,300 @@
+//! BOLT11 invoice decoding
+
+use crate::error::{Result, ZapClockError};
+use lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription, Currency};
+use nostr::hashes::{sha256, Hash};
+use nostr_sdk::Timestamp;
+use std::str::FromStr;
+
+/// Fields of a BOLT11 invoice needed to pay and verify it
+#[derive(Debug, Clone)]
+pub struct DecodedInvoice {
+    pub bolt11: String,
+    /// Hex-encoded payment hash
+    pub payment_hash: String,
+    /// Hex-encoded node id of the payee
+    pub payee: String,
+    /// Network the invoice is payable on
+    pub network: Currency,
+    /// Amount requested by the invoice (None for "any amount" invoices)
+    pub amount_msats: Option<u64>,
+    pub description: Option<String>,
+    /// Hex-encoded description hash
+    pub description_hash: Option<String>,
+    /// Unix timestamp the invoice was created
+    pub created_at: u64,
+    /// Unix timestamp after which the invoice can no longer be paid
+    pub expires_at: u64,
+}
+
+impl DecodedInvoice {
+    /// Decode a BOLT11 invoice string
+    ///
+    /// Accepts a `lightning:` URI prefix and the upper-case form used in QR codes.
+    pub fn parse(bolt11: &str) -> Result<Self> {
+        let mut bolt11 = bolt11.trim();
+        if bolt11.get(..10).is_some_and(|prefix| prefix.eq_ignore_ascii_case("lightning:")) {
+            bolt11 = &bolt11[10..];
+        }
+        let bolt11 = bolt11.to_ascii_lowercase();
+        let invoice = Bolt11Invoice::from_str(&bolt11).map_err(|e| ZapClockError::InvalidInvoice {
+            message: e.to_string(),
+        })?;
+
+        let (description, description_hash) = match invoice.description() {
+            Bolt11InvoiceDescription::Direct(d) => (Some(d.to_string()), None),
+            Bolt11InvoiceDescription::Hash(h) => (None, Some(h.0.to_string())),
+        };
+        let created_at = invoice.duration_since_epoch().as_secs();
+
+        Ok(Self {
+            payment_hash: invoice.payment_hash().to_string(),
+            payee: invoice.get_payee_pub_key().to_string(),
+            network: invoice.currency(),
+            amount_msats: invoice.amount_milli_satoshis(),
+            description,
+            description_hash,
+            created_at,
+            expires_at: created_at.saturating_add(invoice.expiry_time().as_secs()),
+            bolt11,
+        })
+    }
+
+    /// Whether the invoice can no longer be paid
+    pub fn is_expired(&self) -> bool {
+        Timestamp::now().as_u64() >= self.expires_at
+    }
+
+    /// Reject expired invoices
+    pub fn check_not_expired(&self) -> Result<()> {
+        if self.is_expired() {
+            return Err(ZapClockError::InvoiceExpired {
+                expired_at: self.expires_at,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reject invoices whose amount differs from what was requested
+    pub fn check_amount(&self, expected_msats: u64) -> Result<()> {
+        if self.amount_msats != Some(expected_msats) {
+            return Err(ZapClockError::InvoiceAmountMismatch {
+                expected_msats,
+                actual_msats: self.amount_msats,
+            });
+        }
+        Ok(())
+    }
+
+    /// Amount to pay, given an optional caller-supplied amount
+    ///
+    /// "Any amount" invoices need the override; invoices with an amount
+    /// only accept an override that matches it.
+    pub fn payable_amount(&self, amount_override_msats: Option<u64>) -> Result<u64> {
+        match (self.amount_msats, amount_override_msats) {
+            (Some(amount), None) => Ok(amount),
+            (Some(_), Some(expected_msats)) => {
+                self.check_amount(expected_msats)?;
+                Ok(expected_msats)
+            }
+            (None, Some(amount)) if amount > 0 => Ok(amount),
+            (None, _) => Err(ZapClockError::InvalidInvoice {
+                message: "Invoice has no amount and no amount was given".to_string(),
+            }),
+        }
+    }
+
+    /// Reject invoices for another network
+    pub fn check_network(&self, expected: Currency) -> Result<()> {
+        if self.network != expected {
+            return Err(ZapClockError::InvoiceNetworkMismatch {
+                expected: network_name(&expected).to_string(),
+                actual: network_name(&self.network).to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Reject invoices whose description hash isn't SHA-256 of `description`
+    pub fn check_description_hash(&self, description: &str) -> Result<()> {
+        let expected = sha256::Hash::hash(description.as_bytes()).to_string();
+        if self.description_hash.as_deref() != Some(expected.as_str()) {
+            return Err(ZapClockError::InvoiceDescriptionHashMismatch {
+                expected,
+                actual: self.description_hash.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that `preimage` hashes (SHA-256) to this invoice's payment hash
+    pub fn verify_preimage(&self, preimage: &str) -> Result<()> {
+        verify_preimage(&self.payment_hash, preimage)
+    }
+}
+
+/// Check that a hex `preimage` hashes (SHA-256) to a hex `payment_hash`
+pub fn verify_preimage(payment_hash: &str, preimage: &str) -> Result<()> {
+    let mismatch = || ZapClockError::PreimageMismatch {
+        payment_hash: payment_hash.to_string(),
+        preimage: preimage.to_string(),
+    };
+
+    let bytes = hex::decode(preimage.trim()).map_err(|_| mismatch())?;
+    if bytes.len() != 32 || sha256::Hash::hash(&bytes).to_string() != payment_hash {
+        return Err(mismatch());
+    }
+    Ok(())
+}
+
+/// Hex payment hash (SHA-256) of a raw 32-byte preimage
+///
+/// Takes the decoded preimage bytes, not its hex encoding.
+pub fn payment_hash_of(preimage: &[u8]) -> String {
+    sha256::Hash::hash(preimage).to_string()
+}
+
+/// Human readable name of an invoice network
+fn network_name(currency: &Currency) -> &'static str {
+    match currency {
+        Currency::Bitcoin => "mainnet",
+        Currency::BitcoinTestnet => "testnet",
+        Currency::Regtest => "regtest",
+        Currency::Simnet => "simnet",
+        Currency::Signet => "signet",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invoice(amount_msats: Option<u64>) -> DecodedInvoice {
+        let now = Timestamp::now().as_u64();
+        DecodedInvoice {
+            bolt11: "lnbc1test".to_string(),
+            payment_hash: payment_hash_of(&[1u8; 32]),
+            payee: "02".repeat(33),
+            network: Currency::Bitcoin,
+            amount_msats,
+            description: None,
+            description_hash: Some(sha256::Hash::hash(b"metadata").to_string()),
+            created_at: now,
+            expires_at: now + 3600,
+        }
+    }
+
+    #[test]
+    fn check_amount_rejects_another_amount() {
+        let invoice = invoice(Some(21_000));
+        assert!(invoice.check_amount(21_000).is_ok());
+        assert!(matches!(
+            invoice.check_amount(42_000),
+            Err(ZapClockError::InvoiceAmountMismatch {
+                expected_msats: 42_000,
+                actual_msats: Some(21_000),
+            })
+        ));
+    }
+
+    #[test]
+    fn check_amount_rejects_an_amountless_invoice() {
+        assert!(matches!(
+            invoice(None).check_amount(21_000),
+            Err(ZapClockError::InvoiceAmountMismatch { actual_msats: None, .. })
+        ));
+    }
+
+    #[test]
+    fn check_network_rejects_another_network() {
+        let mut invoice = invoice(Some(21_000));
+        assert!(invoice.check_network(Currency::Bitcoin).is_ok());
+        invoice.network = Currency::BitcoinTestnet;
+        match invoice.check_network(Currency::Bitcoin) {
+            Err(ZapClockError::InvoiceNetworkMismatch { expected, actual }) => {
+                assert_eq!(expected, "mainnet");
+                assert_eq!(actual, "testnet");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_not_expired_rejects_an_expired_invoice() {
+        let mut invoice = invoice(Some(21_000));
+        assert!(invoice.check_not_expired().is_ok());
+        invoice.expires_at = Timestamp::now().as_u64() - 1;
+        assert!(matches!(
+            invoice.check_not_expired(),
+            Err(ZapClockError::InvoiceExpired { .. })
+        ));
+    }
+
+    #[test]
+    fn check_description_hash_rejects_another_description() {
+        let invoice = invoice(Some(21_000));
+        assert!(invoice.check_description_hash("metadata").is_ok());
+        assert!(matches!(
+            invoice.check_description_hash("other metadata"),
+            Err(ZapClockError::InvoiceDescriptionHashMismatch { actual: Some(_), .. })
+        ));
+    }
+
+    #[test]
+    fn check_description_hash_rejects_a_direct_description() {
+        let mut invoice = invoice(Some(21_000));
+        invoice.description = Some("metadata".to_string());
+        invoice.description_hash = None;
+        assert!(matches!(
+            invoice.check_description_hash("metadata"),
+            Err(ZapClockError::InvoiceDescriptionHashMismatch { actual: None, .. })
+        ));
+    }
+
+    #[test]
+    fn payable_amount_uses_the_invoice_amount() {
+        let invoice = invoice(Some(21_000));
+        assert_eq!(invoice.payable_amount(None).unwrap(), 21_000);
+        assert_eq!(invoice.payable_amount(Some(21_000)).unwrap(), 21_000);
+    }
+
+    #[test]
+    fn payable_amount_rejects_a_mismatching_override() {
+        assert!(matches!(
+            invoice(Some(21_000)).payable_amount(Some(42_000)),
+            Err(ZapClockError::InvoiceAmountMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn payable_amount_takes_the_override_for_amountless_invoices() {
+        assert_eq!(invoice(None).payable_amount(Some(42_000)).unwrap(), 42_000);
+    }
+
+    #[test]
+    fn payable_amount_rejects_amountless_invoices_without_an_override() {
+        let invoice = invoice(None);
+        assert!(matches!(invoice.payable_amount(None), Err(ZapClockError::InvalidInvoice { .. })));
+        assert!(matches!(invoice.payable_amount(Some(0)), Err(ZapClockError::InvalidInvoice { .. })));
+    }
+
+    #[test]
+    fn parse_rejects_multibyte_input() {
+        assert!(matches!(
+            DecodedInvoice::parse("あいうえおか"),
+            Err(ZapClockError::InvalidInvoice { .. })
+        ));
+        assert!(matches!(
+            DecodedInvoice::parse("lightning:あいうえお"),
+            Err(ZapClockError::InvalidInvoice { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_preimage_checks_hash() {
+        let preimage = [7u8; 32];
+        let payment_hash = payment_hash_of(&preimage);
+        assert!(verify_preimage(&payment_hash, &hex::encode(preimage)).is_ok());
+        assert!(verify_preimage(&payment_hash, &hex::encode([8u8; 32])).is_err());
+    }
+}
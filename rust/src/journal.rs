@@ -0,0 +1,383 @@
+//! Append-only on-disk journal of payment stages
+//!
+//! Every stage of an alarm payment is appended as one JSON line, so the
+//! app can show what happened and, after a crash, find payments that were
+//! published to the wallet but never answered.
+
+use crate::error::{Result, ZapClockError};
+use nostr_sdk::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{field, info, warn, Span};
+
+/// Journal file set by the app at startup
+static JOURNAL_PATH: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Serializes appends, in the order they were requested
+static APPEND_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Stage a payment reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JournalStage {
+    /// Payment attempt started (or restarted with a new invoice)
+    Started,
+    /// LNURL-pay parameters read from the recipient
+    AddressResolved,
+    /// Invoice obtained and validated
+    InvoiceFetched,
+    /// NIP-47 request published; the payment may go out from here on
+    RequestSent,
+    /// Wallet answered the request
+    ResponseReceived,
+    /// Preimage matches the payment hash: the payment is proven
+    PreimageVerified,
+    /// Payment definitely did not go out
+    Failed,
+}
+
+/// One line of the journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Idempotency key of the payment (alarm id + scheduled fire time)
+    pub payment_key: String,
+    pub stage: JournalStage,
+    /// Unix timestamp of the stage
+    pub at: u64,
+    /// Hex-encoded payment hash, once known
+    pub payment_hash: Option<String>,
+    pub amount_msats: Option<u64>,
+    /// Recipient, error message or other detail of the stage
+    pub detail: Option<String>,
+}
+
+impl JournalEntry {
+    /// Entry for `stage` of a payment, timestamped now
+    pub fn new(payment_key: &str, stage: JournalStage) -> Self {
+        Self {
+            payment_key: payment_key.to_string(),
+            stage,
+            at: Timestamp::now().as_u64(),
+            payment_hash: None,
+            amount_msats: None,
+            detail: None,
+        }
+    }
+
+    pub fn payment_hash(mut self, payment_hash: &str) -> Self {
+        self.payment_hash = Some(payment_hash.to_string());
+        self
+    }
+
+    pub fn amount_msats(mut self, amount_msats: u64) -> Self {
+        self.amount_msats = Some(amount_msats);
+        self
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Where a payment stands according to the journal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalPaymentState {
+    /// Request not published yet (or the app stopped before publishing)
+    NotSent,
+    /// Request published but no verified answer: the payment may or may not have gone out
+    Unknown,
+    Paid,
+    Failed,
+}
+
+/// Latest attempt of one payment, summarized from its journal entries
+#[derive(Debug, Clone)]
+pub struct JournalPayment {
+    pub payment_key: String,
+    pub state: JournalPaymentState,
+    pub last_stage: JournalStage,
+    pub payment_hash: Option<String>,
+    pub amount_msats: Option<u64>,
+    /// Recipient given when the attempt started
+    pub recipient: Option<String>,
+    /// Error of a failed attempt
+    pub error: Option<String>,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+impl JournalPayment {
+    fn started(entry: &JournalEntry) -> Self {
+        Self {
+            payment_key: entry.payment_key.clone(),
+            state: JournalPaymentState::NotSent,
+            last_stage: entry.stage,
+            payment_hash: None,
+            amount_msats: None,
+            recipient: entry.detail.clone(),
+            error: None,
+            started_at: entry.at,
+            updated_at: entry.at,
+        }
+    }
+
+    fn apply(&mut self, entry: &JournalEntry) {
+        self.last_stage = entry.stage;
+        self.updated_at = entry.at;
+        if entry.payment_hash.is_some() {
+            self.payment_hash.clone_from(&entry.payment_hash);
+        }
+        if entry.amount_msats.is_some() {
+            self.amount_msats = entry.amount_msats;
+        }
+        match entry.stage {
+            JournalStage::RequestSent | JournalStage::ResponseReceived => {
+                self.state = JournalPaymentState::Unknown;
+            }
+            JournalStage::PreimageVerified => self.state = JournalPaymentState::Paid,
+            JournalStage::Failed => {
+                self.state = JournalPaymentState::Failed;
+                self.error.clone_from(&entry.detail);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Set the journal file (created on first write)
+pub fn set_path(path: &str) -> Result<()> {
+    let path = PathBuf::from(path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(storage_error)?;
+    }
+    *JOURNAL_PATH.lock().unwrap_or_else(|e| e.into_inner()) = Some(path);
+    Ok(())
+}
+
+/// Append an entry, flushed to disk before returning
+///
+/// The write and flush run on the blocking pool. Does nothing if no
+/// journal file has been set.
+pub async fn append(entry: &JournalEntry) -> Result<()> {
+    let Some(path) = JOURNAL_PATH.lock().unwrap_or_else(|e| e.into_inner()).clone() else {
+        return Ok(());
+    };
+    let mut line = serde_json::to_string(entry).map_err(storage_error)?;
+    line.push('\n');
+
+    let _appending = APPEND_LOCK.lock().await;
+    tokio::task::spawn_blocking(move || append_line(&path, line))
+        .await
+        .map_err(storage_error)?
+}
+
+/// Append a serialized entry to `path` and flush it
+fn append_line(path: &Path, mut line: String) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(path)
+        .map_err(storage_error)?;
+
+    // Terminate a line torn by a crash so it doesn't swallow this entry
+    let len = file.metadata().map_err(storage_error)?.len();
+    if len > 0 {
+        let mut last = [0u8; 1];
+        file.seek(SeekFrom::Start(len - 1)).map_err(storage_error)?;
+        file.read_exact(&mut last).map_err(storage_error)?;
+        if last[0] != b'\n' {
+            line.insert(0, '\n');
+        }
+    }
+    file.write_all(line.as_bytes()).map_err(storage_error)?;
+    file.sync_data().map_err(storage_error)
+}
+
+/// Append an entry, logging instead of failing
+///
+/// A journal write error must not abort (or hide the result of) a payment.
+/// The stage is also logged and set on the current span's `stage` field.
+pub async fn record(entry: JournalEntry) {
+    Span::current().record("stage", field::debug(&entry.stage));
+    info!(
+        payment_key = %entry.payment_key,
+        stage = ?entry.stage,
+        detail = entry.detail.as_deref(),
+        "📒 Payment stage reached"
+    );
+    if let Err(e) = append(&entry).await {
+        warn!(payment_key = %entry.payment_key, stage = ?entry.stage, error = %e, "⚠️ Could not record journal entry");
+    }
+}
+
+/// All entries, oldest first (optionally only those of one payment)
+pub fn entries(payment_key: Option<&str>) -> Result<Vec<JournalEntry>> {
+    let path = JOURNAL_PATH
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or_else(|| ZapClockError::Storage {
+            message: "Payment journal path not set".to_string(),
+        })?;
+
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(storage_error(e)),
+    };
+
+    let mut entries = parse_entries(&contents);
+    if let Some(payment_key) = payment_key {
+        entries.retain(|entry| entry.payment_key == payment_key);
+    }
+    Ok(entries)
+}
+
+/// Entries of a journal file's contents, skipping unreadable lines
+fn parse_entries(contents: &str) -> Vec<JournalEntry> {
+    let mut entries = Vec::new();
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        // A crash mid-write can leave a torn last line
+        match serde_json::from_str::<JournalEntry>(line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => warn!("⚠️ Skipping unreadable entry: {}", e),
+        }
+    }
+    entries
+}
+
+/// Latest attempt of every journaled payment, most recent first
+pub fn payments() -> Result<Vec<JournalPayment>> {
+    Ok(fold_payments(entries(None)?))
+}
+
+/// Fold entries (oldest first) into the latest attempt of each payment
+fn fold_payments(entries: Vec<JournalEntry>) -> Vec<JournalPayment> {
+    let mut payments: HashMap<String, JournalPayment> = HashMap::new();
+    for entry in entries {
+        match payments.get_mut(&entry.payment_key) {
+            Some(payment) if entry.stage != JournalStage::Started => payment.apply(&entry),
+            _ => {
+                let mut payment = JournalPayment::started(&entry);
+                payment.apply(&entry);
+                payments.insert(entry.payment_key.clone(), payment);
+            }
+        }
+    }
+    let mut payments: Vec<JournalPayment> = payments.into_values().collect();
+    payments.sort_by_key(|payment| std::cmp::Reverse(payment.updated_at));
+    payments
+}
+
+/// Payments whose request was published but never verifiably answered
+///
+/// Meant to be called at startup: these may or may not have been paid and
+/// should be resumed or checked in the wallet.
+pub fn unknown_payments() -> Result<Vec<JournalPayment>> {
+    let mut payments = payments()?;
+    payments.retain(|payment| payment.state == JournalPaymentState::Unknown);
+    for payment in &payments {
+        warn!(payment_key = %payment.payment_key, last_stage = ?payment.last_stage, "⚠️ Payment in unknown state");
+    }
+    Ok(payments)
+}
+
+fn storage_error(e: impl std::fmt::Display) -> ZapClockError {
+    ZapClockError::Storage {
+        message: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(payment_key: &str, stage: JournalStage, at: u64) -> JournalEntry {
+        JournalEntry {
+            at,
+            ..JournalEntry::new(payment_key, stage)
+        }
+    }
+
+    fn payment<'a>(payments: &'a [JournalPayment], payment_key: &str) -> &'a JournalPayment {
+        payments.iter().find(|p| p.payment_key == payment_key).unwrap()
+    }
+
+    #[test]
+    fn fold_payments_tracks_the_state_of_each_payment() {
+        let payments = fold_payments(vec![
+            entry("paid", JournalStage::Started, 1).detail("alice@example.com"),
+            entry("not_sent", JournalStage::Started, 2),
+            entry("unknown", JournalStage::Started, 3),
+            entry("failed", JournalStage::Started, 4),
+            entry("paid", JournalStage::InvoiceFetched, 5).payment_hash("ab").amount_msats(21_000),
+            entry("not_sent", JournalStage::AddressResolved, 6),
+            entry("unknown", JournalStage::RequestSent, 7),
+            entry("paid", JournalStage::RequestSent, 8),
+            entry("failed", JournalStage::Failed, 9).detail("no route"),
+            entry("paid", JournalStage::ResponseReceived, 10),
+            entry("paid", JournalStage::PreimageVerified, 11),
+        ]);
+
+        let keys: Vec<_> = payments.iter().map(|p| p.payment_key.as_str()).collect();
+        assert_eq!(keys, ["paid", "failed", "unknown", "not_sent"]);
+
+        let paid = payment(&payments, "paid");
+        assert_eq!(paid.state, JournalPaymentState::Paid);
+        assert_eq!(paid.last_stage, JournalStage::PreimageVerified);
+        assert_eq!(paid.payment_hash.as_deref(), Some("ab"));
+        assert_eq!(paid.amount_msats, Some(21_000));
+        assert_eq!(paid.recipient.as_deref(), Some("alice@example.com"));
+        assert_eq!((paid.started_at, paid.updated_at), (1, 11));
+
+        assert_eq!(payment(&payments, "not_sent").state, JournalPaymentState::NotSent);
+        assert_eq!(payment(&payments, "unknown").state, JournalPaymentState::Unknown);
+        let failed = payment(&payments, "failed");
+        assert_eq!(failed.state, JournalPaymentState::Failed);
+        assert_eq!(failed.error.as_deref(), Some("no route"));
+    }
+
+    #[test]
+    fn fold_payments_keeps_only_the_latest_attempt() {
+        let payments = fold_payments(vec![
+            entry("alarm_1_100", JournalStage::Started, 1),
+            entry("alarm_1_100", JournalStage::Failed, 2).detail("invoice expired unpaid"),
+            entry("alarm_1_100", JournalStage::Started, 3),
+            entry("alarm_1_100", JournalStage::RequestSent, 4),
+        ]);
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].state, JournalPaymentState::Unknown);
+        assert_eq!(payments[0].error, None);
+        assert_eq!(payments[0].started_at, 3);
+    }
+
+    #[test]
+    fn parse_entries_skips_a_torn_last_line() {
+        let complete = serde_json::to_string(&entry("alarm_1_100", JournalStage::Started, 1)).unwrap();
+        let torn = serde_json::to_string(&entry("alarm_1_100", JournalStage::RequestSent, 2)).unwrap();
+        let contents = format!("{}\n{}", complete, &torn[..torn.len() / 2]);
+
+        let entries = parse_entries(&contents);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].stage, JournalStage::Started);
+    }
+
+    #[tokio::test]
+    async fn append_terminates_a_torn_last_line() {
+        let path = std::env::temp_dir().join(format!("zapclock-journal-{}.jsonl", std::process::id()));
+        fs::write(&path, "{\"payment_key\":\"alarm_1_100\",\"sta").unwrap();
+        set_path(path.to_str().unwrap()).unwrap();
+
+        append(&entry("alarm_2_200", JournalStage::Started, 1)).await.unwrap();
+        append(&entry("alarm_2_200", JournalStage::Failed, 2)).await.unwrap();
+
+        let stages: Vec<_> = entries(Some("alarm_2_200")).unwrap().into_iter().map(|e| e.stage).collect();
+        assert_eq!(stages, [JournalStage::Started, JournalStage::Failed]);
+        let _ = fs::remove_file(&path);
+    }
+}
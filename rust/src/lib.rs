@@ -2,11 +2,22 @@ mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be
 pub mod api;
 pub mod nwc;
 pub mod lightning;
+pub mod error;
+pub mod invoice;
+pub mod payment;
+pub mod zap;
+pub mod store;
+pub mod journal;
+pub mod penalty;
+pub mod progress;
+pub mod cancel;
+pub mod network;
+pub mod redact;
 
 use flutter_rust_bridge::frb;
 use std::sync::Once;
 use tracing_subscriber::{
-    fmt,
+    fmt::{self, format::FmtSpan},
     layer::SubscriberExt,
     util::SubscriberInitExt,
     EnvFilter,
@@ -23,6 +34,7 @@ static INIT: Once = Once::new();
 /// - Android Logcat integration
 /// - Performance trace support
 /// - Compatibility with existing log crate
+/// - Secret redaction (connection strings, preimages, invoices) on every output
 #[frb(sync)]
 pub fn init() {
     INIT.call_once(|| {
@@ -39,13 +51,12 @@ pub fn init() {
                     .add_directive("zap_clock=trace".parse().unwrap())
                     // nostr-sdk logs at info level only
                     .add_directive("nostr_sdk=info".parse().unwrap())
-                    // nwc logs at debug level and above
-                    .add_directive("nwc=debug".parse().unwrap())
             });
 
         #[cfg(target_os = "android")]
         {
             // Android environment: output to Logcat
+            // (formats fields itself: only the call sites' own redaction applies)
             let android_layer = tracing_android::layer("zap_clock")
                 .expect("Failed to initialize Android logging");
 
@@ -67,12 +78,14 @@ pub fn init() {
                 .with(env_filter)
                 .with(
                     fmt::layer()
+                        .fmt_fields(redact::RedactedFields)  // Mask secrets in event and span fields
                         .with_target(true)  // Display module name
                         .with_thread_ids(true)  // Display thread ID
                         .with_thread_names(true)  // Display thread name
                         .with_line_number(true)  // Display line number
                         .with_file(true)  // Display file name
                         .with_ansi(true)  // Color output
+                        .with_span_events(FmtSpan::CLOSE)  // Display span durations
                         .compact()  // Compact output format
                 )
                 .init();
@@ -1,7 +1,23 @@
-/// Lightning payment processing implementation (LNURL-pay support)
+//! Lightning payment processing implementation (LNURL-pay support)
 
-use anyhow::{Context, Result};
+use crate::error::{Result, ZapClockError};
+use crate::invoice::DecodedInvoice;
+use crate::network::NetworkPolicy;
+use crate::redact;
+use crate::zap::ZapRequest;
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use base64::Engine;
+use lightning_invoice::Currency;
+use nostr::PublicKey;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use tracing::{debug, error, info, instrument, warn};
+
+/// Maximum characters of an HTTP error body kept in error messages
+const ERROR_BODY_EXCERPT_CHARS: usize = 200;
+
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct LnurlPayResponse {
@@ -14,112 +30,701 @@ struct LnurlPayResponse {
     tag: String,
     #[serde(rename = "commentAllowed")]
     comment_allowed: Option<u64>, // Maximum comment characters (optional)
+    #[serde(rename = "allowsNostr", default)]
+    allows_nostr: bool, // NIP-57 zap support
+    #[serde(rename = "nostrPubkey")]
+    nostr_pubkey: Option<String>, // Key that signs zap receipts (hex)
+}
+
+/// NIP-05 `nostr.json` document
+#[derive(Debug, Deserialize)]
+struct Nip05Response {
+    #[serde(default)]
+    names: std::collections::HashMap<String, String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct LnurlPayInvoiceResponse {
     pr: String,
     routes: Option<Vec<String>>,
+    #[serde(rename = "successAction")]
+    success_action: Option<LnurlSuccessAction>,
+}
+
+/// LUD-09 success action as sent by the LNURL server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "tag", rename_all = "lowercase")]
+enum LnurlSuccessAction {
+    Message {
+        message: String,
+    },
+    Url {
+        description: String,
+        url: String,
+    },
+    /// LUD-10: `ciphertext` is AES-256-CBC encrypted with the payment preimage
+    Aes {
+        description: String,
+        ciphertext: String,
+        iv: String,
+    },
+}
+
+/// Success action to show once the invoice has been paid (LUD-09 / LUD-10)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SuccessAction {
+    /// Plain message from the recipient
+    Message { message: String },
+    /// Link the recipient wants to show (same domain as the LNURL callback)
+    Url { description: String, url: String },
+    /// Secret decrypted with the payment preimage
+    ///
+    /// `plaintext` is None until the invoice is paid, or if decryption failed.
+    Aes {
+        description: String,
+        plaintext: Option<String>,
+    },
+}
+
+/// Success action received with an invoice, waiting for the preimage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingSuccessAction(LnurlSuccessAction);
+
+impl PendingSuccessAction {
+    /// Validate a success action against LUD-09 (None if it must be ignored)
+    fn validate(action: LnurlSuccessAction, callback: &str) -> Option<Self> {
+        if let LnurlSuccessAction::Url { url, .. } = &action {
+            let host = |u: &str| reqwest::Url::parse(u).ok()?.host_str().map(str::to_string);
+            if host(url).is_none() || host(url) != host(callback) {
+                warn!(url = %url, "⚠️ Ignoring success action URL on a different domain");
+                return None;
+            }
+        }
+        Some(Self(action))
+    }
+
+    /// Resolve the action with the preimage returned by the wallet
+    pub fn resolve(self, preimage: &str) -> SuccessAction {
+        match self.0 {
+            LnurlSuccessAction::Message { message } => SuccessAction::Message { message },
+            LnurlSuccessAction::Url { description, url } => SuccessAction::Url { description, url },
+            LnurlSuccessAction::Aes {
+                description,
+                ciphertext,
+                iv,
+            } => {
+                let plaintext = decrypt_aes_action(preimage, &ciphertext, &iv)
+                    .inspect_err(|e| warn!(error = %e, "⚠️ Could not decrypt success action"))
+                    .ok();
+                SuccessAction::Aes {
+                    description,
+                    plaintext,
+                }
+            }
+        }
+    }
+}
+
+/// Decrypt a LUD-10 `aes` success action using the hex preimage as key
+fn decrypt_aes_action(preimage: &str, ciphertext: &str, iv: &str) -> Result<String> {
+    let invalid = |message: String| ZapClockError::InvalidLnurlResponse { message };
+    let base64 = base64::engine::general_purpose::STANDARD;
+
+    let key = hex::decode(preimage).map_err(|e| invalid(format!("Invalid preimage: {}", e)))?;
+    let iv = base64
+        .decode(iv)
+        .map_err(|e| invalid(format!("Invalid success action IV: {}", e)))?;
+    let ciphertext = base64
+        .decode(ciphertext)
+        .map_err(|e| invalid(format!("Invalid success action ciphertext: {}", e)))?;
+
+    let plaintext = Aes256CbcDec::new_from_slices(&key, &iv)
+        .map_err(|e| invalid(format!("Invalid success action key/IV length: {}", e)))?
+        .decrypt_padded_vec_mut::<Pkcs7>(&ciphertext)
+        .map_err(|e| invalid(format!("Success action decryption failed: {}", e)))?;
+
+    String::from_utf8(plaintext).map_err(|e| invalid(format!("Success action is not UTF-8: {}", e)))
+}
+
+/// Avatar image embedded in LNURL metadata
+#[derive(Debug, Clone)]
+pub struct LnurlImage {
+    /// `image/png` or `image/jpeg`
+    pub mime_type: String,
+    /// Decoded image bytes
+    pub data: Vec<u8>,
+}
+
+/// Parsed LUD-06 metadata of a recipient
+#[derive(Debug, Clone, Default)]
+pub struct LnurlMetadata {
+    /// Short description (`text/plain`)
+    pub description: String,
+    /// Long description (`text/long-desc`)
+    pub long_description: Option<String>,
+    /// Avatar (`image/png;base64` or `image/jpeg;base64`)
+    pub image: Option<LnurlImage>,
+    /// Internet identifier such as a Lightning address (`text/identifier`)
+    pub identifier: Option<String>,
+    /// Contact email (`text/email`)
+    pub email: Option<String>,
+}
+
+impl LnurlMetadata {
+    /// Parse the `metadata` string of an LNURL-pay response
+    ///
+    /// The string is a JSON array of `[mime type, content]` pairs;
+    /// unknown entries are ignored.
+    pub fn parse(metadata: &str) -> Result<Self> {
+        let invalid = |message: String| ZapClockError::InvalidLnurlResponse { message };
+
+        let entries: Vec<Vec<serde_json::Value>> = serde_json::from_str(metadata)
+            .map_err(|e| invalid(format!("Invalid metadata: {}", e)))?;
+
+        let mut parsed = Self::default();
+        let mut has_description = false;
+        for entry in entries {
+            let (Some(kind), Some(content)) = (
+                entry.first().and_then(|v| v.as_str()),
+                entry.get(1).and_then(|v| v.as_str()),
+            ) else {
+                continue;
+            };
+
+            match kind {
+                "text/plain" => {
+                    parsed.description = content.to_string();
+                    has_description = true;
+                }
+                "text/long-desc" => parsed.long_description = Some(content.to_string()),
+                "text/identifier" => parsed.identifier = Some(content.to_string()),
+                "text/email" => parsed.email = Some(content.to_string()),
+                "image/png;base64" | "image/jpeg;base64" => {
+                    match base64::engine::general_purpose::STANDARD.decode(content) {
+                        Ok(data) => {
+                            parsed.image = Some(LnurlImage {
+                                mime_type: kind.trim_end_matches(";base64").to_string(),
+                                data,
+                            })
+                        }
+                        Err(e) => warn!(error = %e, "⚠️ Ignoring undecodable metadata image"),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !has_description {
+            return Err(invalid("Metadata has no text/plain entry".to_string()));
+        }
+        Ok(parsed)
+    }
+}
+
+/// Payment recipient normalized to its LNURL-pay endpoint
+///
+/// Accepts Lightning addresses (LUD-16), bech32 `lnurl1...` strings
+/// (LUD-01), LUD-17 `lnurlp://` URLs, any of these behind a `lightning:`
+/// URI prefix, and the upper-case forms used in QR codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LnurlRecipient {
+    /// URL of the LNURL-pay endpoint (step 1)
+    pub endpoint: String,
+    /// `user@domain` form, if the endpoint is a Lightning address
+    pub lightning_address: Option<String>,
+}
+
+impl LnurlRecipient {
+    /// Parse a recipient string into its LNURL-pay endpoint
+    pub fn parse(input: &str) -> Result<Self> {
+        let invalid = || {
+            error!(input, "❌ Unsupported recipient format");
+            ZapClockError::InvalidAddress {
+                address: input.to_string(),
+            }
+        };
+
+        let mut recipient = input.trim();
+        if recipient.get(..10).is_some_and(|prefix| prefix.eq_ignore_ascii_case("lightning:")) {
+            recipient = &recipient[10..];
+        }
+        // QR codes use upper case for a denser alphanumeric encoding
+        let recipient = if recipient.chars().any(|c| c.is_ascii_lowercase()) {
+            recipient.to_string()
+        } else {
+            recipient.to_ascii_lowercase()
+        };
+
+        let endpoint = if recipient.starts_with("lnurl1") {
+            // LUD-01: bech32-encoded URL
+            let (hrp, data) = bech32::decode(&recipient).map_err(|_| invalid())?;
+            if hrp.to_lowercase() != "lnurl" {
+                return Err(invalid());
+            }
+            String::from_utf8(data).map_err(|_| invalid())?
+        } else if let Some(rest) = recipient.strip_prefix("lnurlp://") {
+            // LUD-17: clearnet over https, onion services over http
+            let host = rest.split(['/', '?']).next().unwrap_or_default();
+            let scheme = if host.ends_with(".onion") { "http" } else { "https" };
+            format!("{}://{}", scheme, rest)
+        } else if recipient.contains("://") {
+            return Err(invalid());
+        } else {
+            let (username, domain) = split_address(&recipient)?;
+            return Ok(Self {
+                endpoint: format!("https://{}/.well-known/lnurlp/{}", domain, username),
+                lightning_address: Some(format!("{}@{}", username, domain)),
+            });
+        };
+
+        let url = reqwest::Url::parse(&endpoint).map_err(|_| invalid())?;
+        let host = url.host_str().ok_or_else(invalid)?;
+        let is_onion = host.ends_with(".onion");
+        if url.scheme() != "https" && !(url.scheme() == "http" && is_onion) {
+            return Err(invalid());
+        }
+
+        // A bech32 LNURL may just wrap a Lightning address endpoint
+        let lightning_address = url
+            .path()
+            .strip_prefix("/.well-known/lnurlp/")
+            .filter(|username| !username.is_empty() && !username.contains('/'))
+            .map(|username| format!("{}@{}", username, host));
+
+        Ok(Self {
+            endpoint,
+            lightning_address,
+        })
+    }
+}
+
+/// Recipient details read from the LNURL-pay endpoint, without paying
+#[derive(Debug, Clone)]
+pub struct ResolvedRecipient {
+    /// Recipient string that was resolved
+    pub address: String,
+    /// LNURL-pay endpoint the recipient resolved to
+    pub endpoint: String,
+    pub metadata: LnurlMetadata,
+    /// Minimum amount the recipient accepts (msats)
+    pub min_sendable_msats: u64,
+    /// Maximum amount the recipient accepts (msats)
+    pub max_sendable_msats: u64,
+    /// Maximum comment length (None if comments aren't accepted)
+    pub comment_allowed: Option<u64>,
+    /// Whether the recipient accepts NIP-57 zap requests
+    pub supports_zaps: bool,
+    /// Key the recipient signs zap receipts with (hex, only set with zap support)
+    pub nostr_pubkey: Option<String>,
+}
+
+/// Network penalty invoices must be payable on
+pub(crate) const EXPECTED_NETWORK: Currency = Currency::Bitcoin;
+
+/// LNURL-pay endpoint whose pay parameters have been read
+#[derive(Debug)]
+pub struct PayEndpoint {
+    recipient: LnurlRecipient,
+    lnurl_response: LnurlPayResponse,
+}
+
+/// Invoice obtained from an LNURL-pay server
+#[derive(Debug, Clone)]
+pub struct FetchedInvoice {
+    /// Decoded and validated BOLT11 invoice
+    pub invoice: DecodedInvoice,
+    /// Amount requested from the server
+    pub amount_msats: u64,
+    /// Comment actually sent with the request (None if omitted)
+    pub comment: Option<String>,
+    /// LUD-09 success action to resolve once the invoice is paid
+    pub success_action: Option<PendingSuccessAction>,
+    /// NIP-57 zap request the invoice was issued for (None for plain LNURL-pay)
+    pub zap_request: Option<ZapRequest>,
 }
 
 pub struct LightningPayment {
     client: reqwest::Client,
+    policy: NetworkPolicy,
 }
 
 impl LightningPayment {
+    /// Create a client with the app-wide network policy
     pub fn new() -> Self {
-        Self {
-            client: reqwest::Client::new(),
-        }
+        Self::with_policy(NetworkPolicy::current())
     }
     
-    /// Get Invoice from Lightning address
-    pub async fn get_invoice_from_address(
-        &self,
-        lightning_address: &str,
-        amount_sats: u64,
-        comment: Option<String>,
-    ) -> Result<String> {
-        println!("🔍 [Lightning] Starting Invoice retrieval: {} sats → {}", amount_sats, lightning_address);
+    /// Create a client with its own timeouts and retries
+    pub fn with_policy(policy: NetworkPolicy) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(policy.http_connect_timeout())
+            .timeout(policy.http_timeout())
+            .build()
+            // Same failure as `reqwest::Client::new()`: the TLS backend can't start
+            .expect("Failed to initialize HTTP client");
+        
+        Self { client, policy }
+    }
+    
+    /// Resolve a recipient without paying it
+    #[instrument(skip_all, fields(address = lightning_address))]
+    pub async fn resolve_address(&self, lightning_address: &str) -> Result<ResolvedRecipient> {
+        info!("🔍 Resolving Lightning address");
+        
+        let recipient = LnurlRecipient::parse(lightning_address)?;
+        let lnurl_response = self.fetch_pay_params(&recipient).await?;
+        let metadata = LnurlMetadata::parse(&lnurl_response.metadata)?;
+        
+        // NIP-57: a zap endpoint is only usable with the receipt signing key
+        let supports_zaps = lnurl_response.allows_nostr && lnurl_response.nostr_pubkey.is_some();
         
-        // Parse Lightning address and convert to LNURL-pay endpoint
-        let parts: Vec<&str> = lightning_address.split('@').collect();
-        if parts.len() != 2 {
-            println!("❌ [Lightning] Invalid Lightning address format: {}", lightning_address);
-            anyhow::bail!("Invalid Lightning address format");
+        info!(
+            description = %metadata.description,
+            min_sats = lnurl_response.min_sendable / 1000,
+            max_sats = lnurl_response.max_sendable / 1000,
+            supports_zaps,
+            "✅ Lightning address resolved"
+        );
+        Ok(ResolvedRecipient {
+            address: lightning_address.to_string(),
+            endpoint: recipient.endpoint,
+            metadata,
+            min_sendable_msats: lnurl_response.min_sendable,
+            max_sendable_msats: lnurl_response.max_sendable,
+            comment_allowed: lnurl_response.comment_allowed.filter(|&len| len > 0),
+            supports_zaps,
+            nostr_pubkey: lnurl_response.nostr_pubkey.filter(|_| supports_zaps),
+        })
+    }
+    
+    /// Step 1 of LNURL-pay: fetch the pay parameters of a recipient
+    async fn fetch_pay_params(&self, recipient: &LnurlRecipient) -> Result<LnurlPayResponse> {
+        debug!(endpoint = %recipient.endpoint, "📡 Fetching LNURL-pay parameters");
+        
+        let value: serde_json::Value = self
+            .get_lnurl_json(self.client.get(&recipient.endpoint))
+            .await?;
+        pay_params(value)
+    }
+    
+    /// Build a zap request if the recipient supports NIP-57
+    ///
+    /// Any problem here falls back to a plain LNURL-pay payment: the
+    /// penalty must go through even if it can't be shown as a zap.
+    async fn build_zap_request(
+        &self,
+        recipient: &LnurlRecipient,
+        lnurl_response: &LnurlPayResponse,
+        amount_msats: u64,
+        comment: Option<&str>,
+    ) -> Option<ZapRequest> {
+        if !lnurl_response.allows_nostr {
+            return None;
         }
+        let Some(server_pubkey) = lnurl_response
+            .nostr_pubkey
+            .as_deref()
+            .and_then(|key| PublicKey::from_hex(key).ok())
+        else {
+            warn!("⚠️ allowsNostr set without a valid nostrPubkey, sending without zap");
+            return None;
+        };
         
-        let username = parts[0];
-        let domain = parts[1];
+        // The `p` tag should be the recipient's own key; fall back to the
+        // server key when the address isn't also a NIP-05 identifier
+        let recipient_pubkey = match &recipient.lightning_address {
+            Some(lightning_address) => self.lookup_nostr_pubkey(lightning_address).await,
+            None => None,
+        }
+        .unwrap_or(server_pubkey);
         
-        // Step 1: Get LNURL-pay information
-        let lnurl_endpoint = format!("https://{}/.well-known/lnurlp/{}", domain, username);
-        println!("📡 [Lightning] LNURL-pay endpoint: {}", lnurl_endpoint);
+        match ZapRequest::anonymous(
+            recipient_pubkey,
+            server_pubkey,
+            &recipient.endpoint,
+            amount_msats,
+            comment,
+        ) {
+            Ok(zap_request) => {
+                info!(zap_request_id = %zap_request.event.id, "⚡ Zap request created");
+                Some(zap_request)
+            }
+            Err(e) => {
+                warn!(error = %e, "⚠️ Sending without zap");
+                None
+            }
+        }
+    }
+    
+    /// Look up the Nostr key behind a Lightning address via NIP-05
+    async fn lookup_nostr_pubkey(&self, lightning_address: &str) -> Option<PublicKey> {
+        let (username, domain) = split_address(lightning_address).ok()?;
+        let nip05_endpoint = format!("https://{}/.well-known/nostr.json", domain);
         
-        let lnurl_response: LnurlPayResponse = self
+        let response: Nip05Response = self
             .client
-            .get(&lnurl_endpoint)
+            .get(&nip05_endpoint)
+            .query(&[("name", username)])
             .send()
             .await
-            .context("Request to LNURL-pay endpoint failed")?
+            .ok()?
+            .error_for_status()
+            .ok()?
             .json()
             .await
-            .context("Failed to parse LNURL-pay response")?;
+            .ok()?;
+        
+        let pubkey = PublicKey::from_hex(response.names.get(username)?).ok()?;
+        debug!(pubkey = %pubkey, "🔑 Recipient Nostr key found via NIP-05");
+        Some(pubkey)
+    }
+    
+    /// Send an LNURL request and decode the JSON answer
+    ///
+    /// Recognizes the LUD-06 error envelope (`{"status":"ERROR","reason":...}`)
+    /// and keeps the status code and a body excerpt for non-2xx responses.
+    /// Timeouts, connection failures and 5xx/429 answers are retried per the policy.
+    async fn get_lnurl_json<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T> {
+        self.policy
+            .retry("LNURL request", || async {
+                // GET requests have no body, so they can always be cloned
+                let request = request.try_clone().ok_or_else(|| ZapClockError::InvalidLnurlResponse {
+                    message: "LNURL request can't be repeated".to_string(),
+                })?;
+                self.send_lnurl_request(request).await
+            })
+            .await
+    }
+    
+    /// Single attempt of `get_lnurl_json`
+    async fn send_lnurl_request<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T> {
+        let response = request.send().await?;
+        let url = response.url().to_string();
+        let status = response.status();
+        let body = response.text().await?;
+        let value = lnurl_body(url, status, &body)?;
         
-        println!("✅ [Lightning] LNURL-pay information retrieved successfully");
-        println!("   Min: {} sats, Max: {} sats", 
-            lnurl_response.min_sendable / 1000, 
-            lnurl_response.max_sendable / 1000
+        serde_json::from_value(value).map_err(|e| ZapClockError::InvalidLnurlResponse {
+            message: e.to_string(),
+        })
+    }
+    
+    /// Get Invoice from a recipient (Lightning address, LNURL or `lightning:` URI)
+    pub async fn get_invoice_from_address(
+        &self,
+        lightning_address: &str,
+        amount_sats: u64,
+        comment: Option<String>,
+    ) -> Result<FetchedInvoice> {
+        info!(amount_sats, address = lightning_address, "🔍 Starting Invoice retrieval");
+        
+        let endpoint = self.resolve_pay_endpoint(lightning_address).await?;
+        self.fetch_invoice(&endpoint, amount_sats, comment).await
+    }
+    
+    /// Step 1 of LNURL-pay: read the pay parameters of a recipient
+    #[instrument(skip_all, fields(address = lightning_address))]
+    pub async fn resolve_pay_endpoint(&self, lightning_address: &str) -> Result<PayEndpoint> {
+        let started = Instant::now();
+        let recipient = LnurlRecipient::parse(lightning_address)?;
+        let lnurl_response = self.fetch_pay_params(&recipient).await?;
+        
+        info!(
+            min_sats = lnurl_response.min_sendable / 1000,
+            max_sats = lnurl_response.max_sendable / 1000,
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "✅ LNURL-pay information retrieved successfully"
         );
         
+        Ok(PayEndpoint {
+            recipient,
+            lnurl_response,
+        })
+    }
+    
+    /// Step 2 of LNURL-pay: request and validate an invoice from the callback
+    #[instrument(skip(self, endpoint, comment))]
+    pub async fn fetch_invoice(
+        &self,
+        endpoint: &PayEndpoint,
+        amount_sats: u64,
+        comment: Option<String>,
+    ) -> Result<FetchedInvoice> {
+        let PayEndpoint {
+            recipient,
+            lnurl_response,
+        } = endpoint;
+        
         // Amount validation
         let amount_msats = amount_sats * 1000;
         if amount_msats < lnurl_response.min_sendable
             || amount_msats > lnurl_response.max_sendable
         {
-            println!("❌ [Lightning] Amount out of range: {} sats (range: {}-{} sats)",
-                amount_sats,
-                lnurl_response.min_sendable / 1000,
-                lnurl_response.max_sendable / 1000
-            );
-            anyhow::bail!(
-                "Amount out of range ({}-{} sats)",
-                lnurl_response.min_sendable / 1000,
-                lnurl_response.max_sendable / 1000
+            error!(
+                min_sats = lnurl_response.min_sendable / 1000,
+                max_sats = lnurl_response.max_sendable / 1000,
+                "❌ Amount out of range"
             );
+            return Err(ZapClockError::AmountOutOfRange {
+                amount_sats,
+                min_sats: lnurl_response.min_sendable / 1000,
+                max_sats: lnurl_response.max_sendable / 1000,
+            });
         }
         
         // Step 2: Get Invoice
-        println!("📡 [Lightning] Invoice request: {} msats", amount_msats);
+        let started = Instant::now();
+        debug!(amount_msats, "📡 Invoice request");
         
         // Comment processing
         let mut query_params = vec![("amount", amount_msats.to_string())];
+        let mut comment_sent = None;
         if let Some(comment_text) = comment {
             if let Some(max_comment_len) = lnurl_response.comment_allowed {
                 if comment_text.len() <= max_comment_len as usize {
-                    println!("💬 [Lightning] Adding comment: {}", comment_text);
-                    query_params.push(("comment", comment_text));
+                    debug!(chars = comment_text.chars().count(), "💬 Adding comment");
+                    query_params.push(("comment", comment_text.clone()));
+                    comment_sent = Some(comment_text);
                 } else {
-                    println!("⚠️ [Lightning] Comment too long, omitted (max {} chars)", max_comment_len);
+                    warn!(max_chars = max_comment_len, "⚠️ Comment too long, omitted");
                 }
             } else {
-                println!("⚠️ [Lightning] Recipient does not support comments");
+                warn!("⚠️ Recipient does not support comments");
+            }
+        }
+        
+        // NIP-57: zap-capable recipients get a signed zap request
+        let zap_request = self
+            .build_zap_request(
+                recipient,
+                lnurl_response,
+                amount_msats,
+                comment_sent.as_deref(),
+            )
+            .await;
+        if let Some(zap_request) = &zap_request {
+            query_params.push(("nostr", zap_request.to_json()));
+            if let Some(lnurl) = zap_request.event.tags.find(nostr::TagKind::Lnurl) {
+                if let Some(lnurl) = lnurl.content() {
+                    query_params.push(("lnurl", lnurl.to_string()));
+                }
             }
         }
         
         let invoice_response: LnurlPayInvoiceResponse = self
-            .client
-            .get(&lnurl_response.callback)
-            .query(&query_params)
-            .send()
-            .await
-            .context("Invoice request failed")?
-            .json()
-            .await
-            .context("Failed to parse Invoice response")?;
+            .get_lnurl_json(self.client.get(&lnurl_response.callback).query(&query_params))
+            .await?;
+        
+        // Don't trust the server: the invoice must be exactly what we asked for (LUD-06)
+        let invoice = DecodedInvoice::parse(&invoice_response.pr)?;
+        info!(
+            invoice_prefix = %redact::invoice(&invoice.bolt11),
+            payment_hash = %invoice.payment_hash,
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "✅ Invoice retrieved successfully"
+        );
+        invoice.check_amount(amount_msats)?;
+        invoice.check_network(EXPECTED_NETWORK)?;
+        invoice.check_not_expired()?;
+        // Zap invoices commit to the zap request instead of the metadata (NIP-57)
+        match &zap_request {
+            Some(zap_request) => invoice.check_description_hash(&zap_request.to_json())?,
+            None => invoice.check_description_hash(&lnurl_response.metadata)?,
+        }
+        info!("✅ Invoice validated (amount, network, expiry, description hash)");
+        
+        let success_action = invoice_response
+            .success_action
+            .and_then(|action| PendingSuccessAction::validate(action, &lnurl_response.callback));
         
-        println!("✅ [Lightning] Invoice retrieved successfully: {}", &invoice_response.pr[..20]);
-        Ok(invoice_response.pr)
+        Ok(FetchedInvoice {
+            invoice,
+            amount_msats,
+            comment: comment_sent,
+            success_action,
+            zap_request,
+        })
+    }
+}
+
+/// Split a Lightning address into user name and domain
+fn split_address(lightning_address: &str) -> Result<(&str, &str)> {
+    match lightning_address.split_once('@') {
+        Some((username, domain))
+            if !username.is_empty() && !domain.is_empty() && !domain.contains('@') =>
+        {
+            Ok((username, domain))
+        }
+        _ => {
+            error!(address = lightning_address, "❌ Invalid Lightning address format");
+            Err(ZapClockError::InvalidAddress {
+                address: lightning_address.to_string(),
+            })
+        }
+    }
+}
+
+/// Read pay parameters, checking `tag` before the typed decode
+///
+/// Other LNURL kinds (e.g. `withdrawRequest`) lack the pay fields, so
+/// decoding first would hide the wrong tag behind a missing field.
+fn pay_params(value: serde_json::Value) -> Result<LnurlPayResponse> {
+    let tag = value.get("tag").and_then(|t| t.as_str()).unwrap_or_default();
+    if tag != "payRequest" {
+        error!(tag = %tag, "❌ Unexpected LNURL tag");
+        return Err(ZapClockError::InvalidLnurlResponse {
+            message: format!("Expected tag \"payRequest\", got \"{}\"", tag),
+        });
+    }
+    serde_json::from_value(value).map_err(|e| ZapClockError::InvalidLnurlResponse {
+        message: e.to_string(),
+    })
+}
+
+/// JSON body of an LNURL response, or the error it reports
+///
+/// A LUD-06 `{"status":"ERROR","reason":...}` envelope is the server's
+/// own error, whatever the HTTP status; other non-2xx responses are HTTP errors.
+fn lnurl_body(url: String, status: reqwest::StatusCode, body: &str) -> Result<serde_json::Value> {
+    let value = serde_json::from_str::<serde_json::Value>(body);
+    if let Ok(value) = &value {
+        if value.get("status").and_then(|s| s.as_str()) == Some("ERROR") {
+            let reason = value
+                .get("reason")
+                .and_then(|r| r.as_str())
+                .unwrap_or("no reason given")
+                .to_string();
+            error!(status = status.as_u16(), reason = %reason, "❌ LNURL server returned an error");
+            return Err(ZapClockError::LnurlServer { url, reason });
+        }
+    }
+    
+    if !status.is_success() {
+        error!(status = status.as_u16(), "❌ LNURL request failed");
+        return Err(ZapClockError::LnurlHttp {
+            url,
+            status: Some(status.as_u16()),
+            message: format!("HTTP {}: {}", status, body_excerpt(body)),
+        });
+    }
+    
+    value.map_err(|e| ZapClockError::InvalidLnurlResponse {
+        message: format!("{} ({})", e, body_excerpt(body)),
+    })
+}
+
+/// Shorten an HTTP body for inclusion in an error message
+fn body_excerpt(body: &str) -> String {
+    let trimmed = body.trim();
+    match trimmed.char_indices().nth(ERROR_BODY_EXCERPT_CHARS) {
+        Some((end, _)) => format!("{}…", &trimmed[..end]),
+        None => trimmed.to_string(),
     }
 }
 
@@ -128,3 +733,71 @@ impl Default for LightningPayment {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_strips_lightning_uri_prefix() {
+        let recipient = LnurlRecipient::parse("LIGHTNING:SATOSHI@EXAMPLE.COM").unwrap();
+        assert_eq!(recipient.endpoint, "https://example.com/.well-known/lnurlp/satoshi");
+        assert_eq!(recipient.lightning_address.as_deref(), Some("satoshi@example.com"));
+    }
+
+    #[test]
+    fn parse_handles_multibyte_input() {
+        let recipient = LnurlRecipient::parse("あいうえ@example.com").unwrap();
+        assert_eq!(recipient.lightning_address.as_deref(), Some("あいうえ@example.com"));
+        assert!(LnurlRecipient::parse("あいう").is_err());
+    }
+
+    #[test]
+    fn pay_params_reports_a_wrong_tag_before_missing_fields() {
+        let withdraw = serde_json::json!({
+            "tag": "withdrawRequest",
+            "callback": "https://example.com/withdraw",
+            "k1": "abc",
+        });
+        match pay_params(withdraw) {
+            Err(ZapClockError::InvalidLnurlResponse { message }) => {
+                assert!(message.contains("withdrawRequest"), "{}", message)
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pay_params_decodes_a_pay_request() {
+        let pay = serde_json::json!({
+            "tag": "payRequest",
+            "callback": "https://example.com/pay",
+            "minSendable": 1000,
+            "maxSendable": 100000,
+            "metadata": "[[\"text/plain\",\"hi\"]]",
+        });
+        assert_eq!(pay_params(pay).unwrap().max_sendable, 100_000);
+    }
+
+    #[test]
+    fn lnurl_body_reads_the_error_envelope_of_a_failed_request() {
+        let body = r#"{"status":"ERROR","reason":"Amount too small"}"#;
+        match lnurl_body("https://example.com".to_string(), reqwest::StatusCode::BAD_REQUEST, body) {
+            Err(ZapClockError::LnurlServer { reason, .. }) => assert_eq!(reason, "Amount too small"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lnurl_body_reports_other_failed_requests_as_http_errors() {
+        let result = lnurl_body("https://example.com".to_string(), reqwest::StatusCode::BAD_GATEWAY, "<html>");
+        assert!(matches!(result, Err(ZapClockError::LnurlHttp { status: Some(502), .. })));
+    }
+
+    #[test]
+    fn lnurl_body_reads_the_error_envelope_of_a_successful_request() {
+        let body = r#"{"status":"ERROR","reason":"Unknown user"}"#;
+        let result = lnurl_body("https://example.com".to_string(), reqwest::StatusCode::OK, body);
+        assert!(matches!(result, Err(ZapClockError::LnurlServer { .. })));
+    }
+}
@@ -0,0 +1,200 @@
+//! Timeouts and retries for NWC and LNURL calls
+//!
+//! The app-wide policy is set from Dart with [`set_policy`]; clients pick it
+//! up when they are created and can override it with `with_policy`.
+
+use crate::error::{Result, ZapClockError};
+use std::future::Future;
+use std::sync::RwLock;
+use std::time::Duration;
+use tracing::warn;
+
+/// Upper bound for a single backoff delay
+const MAX_BACKOFF_MS: u64 = 30_000;
+
+/// Policy used by newly created clients
+static POLICY: RwLock<NetworkPolicy> = RwLock::new(NetworkPolicy::DEFAULT);
+
+/// Timeouts per stage, retries and overall deadline of network calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkPolicy {
+    /// Connecting to the wallet relay
+    pub relay_connect_timeout_secs: u64,
+    /// Querying stored events on the wallet relay (info event, earlier responses)
+    pub relay_query_timeout_secs: u64,
+    /// Wallet answer to a non-payment request (balance, info, transactions)
+    pub wallet_timeout_secs: u64,
+    /// Wallet answer to a payment request
+    pub payment_timeout_secs: u64,
+    /// TCP/TLS connect to an LNURL server
+    pub http_connect_timeout_secs: u64,
+    /// Whole HTTP request to an LNURL server, including reading the body
+    pub http_timeout_secs: u64,
+    /// Extra attempts after a retryable failure (payment requests are never retried)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles with every further retry
+    pub retry_backoff_ms: u64,
+    /// Upper bound on one operation, including all retries and backoff
+    ///
+    /// A payment counts as one operation, from resolving the address to
+    /// the wallet's answer.
+    pub deadline_secs: u64,
+}
+
+impl NetworkPolicy {
+    pub const DEFAULT: Self = Self {
+        relay_connect_timeout_secs: 10,
+        relay_query_timeout_secs: 10,
+        wallet_timeout_secs: 30,
+        payment_timeout_secs: 60,
+        http_connect_timeout_secs: 10,
+        http_timeout_secs: 30,
+        max_retries: 2,
+        retry_backoff_ms: 500,
+        deadline_secs: 120,
+    };
+
+    /// Policy set with [`set_policy`] (the default until then)
+    pub fn current() -> Self {
+        *POLICY.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn relay_connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.relay_connect_timeout_secs)
+    }
+
+    pub fn relay_query_timeout(&self) -> Duration {
+        Duration::from_secs(self.relay_query_timeout_secs)
+    }
+
+    pub fn http_connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.http_connect_timeout_secs)
+    }
+
+    pub fn http_timeout(&self) -> Duration {
+        Duration::from_secs(self.http_timeout_secs)
+    }
+
+    pub fn deadline(&self) -> Duration {
+        Duration::from_secs(self.deadline_secs)
+    }
+
+    /// Check that no timeout or deadline is zero (which would fail every call)
+    pub fn validate(&self) -> Result<()> {
+        let durations = [
+            ("relay_connect_timeout_secs", self.relay_connect_timeout_secs),
+            ("relay_query_timeout_secs", self.relay_query_timeout_secs),
+            ("wallet_timeout_secs", self.wallet_timeout_secs),
+            ("payment_timeout_secs", self.payment_timeout_secs),
+            ("http_connect_timeout_secs", self.http_connect_timeout_secs),
+            ("http_timeout_secs", self.http_timeout_secs),
+            ("deadline_secs", self.deadline_secs),
+        ];
+        match durations.iter().find(|(_, secs)| *secs == 0) {
+            Some((name, _)) => Err(ZapClockError::InvalidNetworkPolicy {
+                message: format!("{} must be greater than 0", name),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Delay before retry number `retry` (starting at 0)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let delay = self
+            .retry_backoff_ms
+            .saturating_mul(1u64.checked_shl(retry).unwrap_or(u64::MAX));
+        Duration::from_millis(delay.min(MAX_BACKOFF_MS))
+    }
+
+    /// Run `attempt`, retrying retryable failures with backoff
+    ///
+    /// Gives up with `DeadlineExceeded` once `deadline_secs` have passed,
+    /// whatever attempt is in progress. Only use this for calls that are
+    /// safe to repeat.
+    pub async fn retry<T, F, Fut>(&self, operation: &str, mut attempt: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let attempts = async {
+            let mut retries = 0;
+            loop {
+                match attempt().await {
+                    Err(e) if e.is_retryable() && retries < self.max_retries => {
+                        let delay = self.backoff(retries);
+                        retries += 1;
+                        warn!(
+                            operation,
+                            error = %e,
+                            retry = retries,
+                            max_retries = self.max_retries,
+                            delay_ms = delay.as_millis() as u64,
+                            "🔁 Retrying after failure"
+                        );
+                        tokio::time::sleep(delay).await;
+                    }
+                    result => return result,
+                }
+            }
+        };
+
+        tokio::time::timeout(self.deadline(), attempts)
+            .await
+            .unwrap_or_else(|_| {
+                warn!(operation, deadline_secs = self.deadline_secs, "⏱️ Gave up at the deadline");
+                Err(ZapClockError::DeadlineExceeded {
+                    seconds: self.deadline_secs,
+                })
+            })
+    }
+}
+
+impl Default for NetworkPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Replace the policy used by clients created from now on
+///
+/// Fails with `InvalidNetworkPolicy` (keeping the current policy) if a
+/// timeout or the deadline is zero.
+pub fn set_policy(policy: NetworkPolicy) -> Result<()> {
+    policy.validate()?;
+    *POLICY.write().unwrap_or_else(|e| e.into_inner()) = policy;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_zero_timeouts_and_deadline() {
+        NetworkPolicy::DEFAULT.validate().unwrap();
+
+        let policy = NetworkPolicy {
+            payment_timeout_secs: 0,
+            ..NetworkPolicy::DEFAULT
+        };
+        assert!(matches!(
+            policy.validate(),
+            Err(ZapClockError::InvalidNetworkPolicy { message }) if message.contains("payment_timeout_secs")
+        ));
+
+        let policy = NetworkPolicy {
+            deadline_secs: 0,
+            ..NetworkPolicy::DEFAULT
+        };
+        assert!(set_policy(policy).is_err());
+        assert_eq!(NetworkPolicy::current(), NetworkPolicy::DEFAULT);
+
+        // No retries and no backoff are fine
+        let policy = NetworkPolicy {
+            max_retries: 0,
+            retry_backoff_ms: 0,
+            ..NetworkPolicy::DEFAULT
+        };
+        policy.validate().unwrap();
+    }
+}
@@ -1,124 +1,1198 @@
-/// Nostr Wallet Connect (NWC) client implementation
+//! Nostr Wallet Connect (NWC) client implementation
 
-use anyhow::{Context, Result};
+use crate::error::{Result, WalletErrorCode, ZapClockError};
+use crate::cancel::PaymentCancel;
+use crate::invoice::{self, DecodedInvoice};
+use crate::network::NetworkPolicy;
+use crate::progress::{PaymentProgress, ProgressReporter};
+use crate::redact;
+use nostr::hashes::{sha256, Hash};
+use nostr::nips::nip04;
+use nostr::nips::nip47::{
+    self, KeysendTLVRecord, ListTransactionsRequest, LookupInvoiceRequest, MultiPayInvoiceRequest,
+    PayInvoiceRequest, PayKeysendRequest,
+};
 use nostr_sdk::prelude::*;
-use nostr::nips::nip47::PayInvoiceRequest;
-use nwc::NWC;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::Receiver;
+use tokio::time::{timeout, Duration};
+use tracing::{debug, error, info, instrument, warn};
 
+/// Subscription ID used for wallet responses
+const RESPONSE_SUBSCRIPTION_ID: &str = "zap_clock_nwc";
+
+/// Base delay between reconnect attempts (grows while the relay keeps failing)
+const RELAY_RETRY_INTERVAL_SECS: u64 = 5;
+
+/// Sessions shared by all API calls, keyed by the SHA-256 of their
+/// connection string so the secret isn't kept as a map key
+static SESSIONS: OnceLock<Mutex<HashMap<sha256::Hash, NwcSession>>> = OnceLock::new();
+
+/// Long-lived NWC session
+///
+/// Keeps the relay connection and the wallet response subscription open,
+/// so balance checks and payments don't pay a full connect each time.
+/// The relay reconnects on its own, backing off while it keeps failing.
+/// Clones share the same connection; each clone has its own timeouts.
+#[derive(Clone)]
+pub struct NwcSession {
+    inner: Arc<SessionInner>,
+    policy: NetworkPolicy,
+}
+
+struct SessionInner {
+    uri: NostrWalletConnectURI,
+    relay: Relay,
+    subscribed: AtomicBool,
+}
+
+/// Decrypted NIP-47 response, before its result is decoded
+///
+/// Decoded leniently (instead of via `nip47::Response`) so optional fields
+/// and error codes newer than the `nostr` crate don't fail the whole response.
+#[derive(Debug, Deserialize)]
+pub struct WalletResponse {
+    error: Option<WalletResponseError>,
+    result: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WalletResponseError {
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+impl WalletResponse {
+    /// Decode the result, or return the wallet's error
+    pub fn into_result<T: DeserializeOwned>(self) -> Result<T> {
+        if let Some(e) = self.error {
+            return Err(ZapClockError::wallet(
+                WalletErrorCode::from(e.code.as_str()),
+                e.message,
+            ));
+        }
+
+        let result = self.result.ok_or_else(|| ZapClockError::InvalidResponse {
+            message: "Response has neither result nor error".to_string(),
+        })?;
+
+        serde_json::from_value(result).map_err(|e| ZapClockError::InvalidResponse {
+            message: e.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GetBalanceResult {
+    balance: u64,
+}
+
+/// Result of a successful `pay_invoice` request
+#[derive(Debug, Clone, Deserialize)]
+pub struct PayInvoiceResult {
+    pub preimage: String,
+    /// Routing fees in msats (only reported by some wallets)
+    pub fees_paid: Option<u64>,
+}
+
+/// Custom TLV record attached to a keysend payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlvRecord {
+    pub record_type: u64,
+    /// Hex-encoded value
+    pub value: String,
+}
+
+impl From<TlvRecord> for KeysendTLVRecord {
+    fn from(record: TlvRecord) -> Self {
+        Self {
+            tlv_type: record.record_type,
+            value: record.value,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct GetInfoResult {
+    alias: Option<String>,
+    color: Option<String>,
+    pubkey: Option<String>,
+    network: Option<String>,
+    block_height: Option<u32>,
+    block_hash: Option<String>,
+    methods: Vec<String>,
+    notifications: Vec<String>,
+}
+
+/// Capabilities of the connected wallet service
+///
+/// Combines the `get_info` response (what this connection may do) with the
+/// wallet service's info event, kind 13194 (what the service supports).
+#[derive(Debug, Clone)]
+pub struct WalletInfo {
+    pub alias: Option<String>,
+    pub color: Option<String>,
+    /// Lightning node public key
+    pub pubkey: Option<String>,
+    /// Active network (mainnet, testnet, signet or regtest)
+    pub network: Option<String>,
+    pub block_height: Option<u32>,
+    pub block_hash: Option<String>,
+    /// Methods this connection is allowed to call
+    pub methods: Vec<String>,
+    /// Notification types the wallet service can send
+    pub notifications: Vec<String>,
+    /// Encryption schemes the wallet service accepts
+    pub encryption: Vec<String>,
+    /// Whether `get_info` was answered (otherwise only the info event was read)
+    pub get_info_supported: bool,
+    /// Whether penalties can be paid with this connection
+    pub can_pay_invoice: bool,
+}
+
+/// Direction of a wallet transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionType {
+    Incoming,
+    Outgoing,
+}
+
+impl From<TransactionType> for nip47::TransactionType {
+    fn from(t: TransactionType) -> Self {
+        match t {
+            TransactionType::Incoming => Self::Incoming,
+            TransactionType::Outgoing => Self::Outgoing,
+        }
+    }
+}
+
+/// Filters for `list_transactions` (timestamps are unix seconds)
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    pub from: Option<u64>,
+    pub until: Option<u64>,
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Include unpaid invoices
+    pub unpaid: Option<bool>,
+    pub transaction_type: Option<TransactionType>,
+}
+
+/// Invoice to look up with `lookup_invoice`
+#[derive(Debug, Clone)]
+pub enum InvoiceLookup {
+    PaymentHash(String),
+    Bolt11(String),
+}
+
+/// Transaction record reported by the wallet (amounts in msats)
+#[derive(Debug, Clone, Deserialize)]
+pub struct WalletTransaction {
+    #[serde(rename = "type")]
+    pub transaction_type: Option<TransactionType>,
+    pub invoice: Option<String>,
+    pub description: Option<String>,
+    pub description_hash: Option<String>,
+    /// Present once the payment has settled
+    pub preimage: Option<String>,
+    pub payment_hash: String,
+    pub amount: u64,
+    #[serde(default)]
+    pub fees_paid: u64,
+    pub created_at: u64,
+    pub expires_at: Option<u64>,
+    pub settled_at: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTransactionsResult {
+    transactions: Vec<WalletTransaction>,
+}
+
+/// Parsed wallet service info event (kind 13194)
+struct InfoEvent {
+    methods: Vec<String>,
+    notifications: Vec<String>,
+    encryption: Vec<String>,
+}
+
+impl InfoEvent {
+    fn from_event(event: &Event) -> Self {
+        let split = |s: &str| s.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+        let tag_values = |name: &str| {
+            event
+                .tags
+                .iter()
+                .map(|t| t.as_slice())
+                .find(|t| t.first().map(String::as_str) == Some(name))
+                .and_then(|t| t.get(1))
+                .map(|v| split(v))
+        };
+
+        Self {
+            methods: split(&event.content),
+            notifications: tag_values("notifications").unwrap_or_default(),
+            // NIP-47: a missing encryption tag means the service only speaks NIP-04
+            encryption: tag_values("encryption").unwrap_or_else(|| vec!["nip04".to_string()]),
+        }
+    }
+}
+
+impl NwcSession {
+    /// Create new (not yet connected) session from NWC connection string
+    pub fn new(connection_string: &str) -> Result<Self> {
+        let uri = NostrWalletConnectURI::parse(connection_string).map_err(|e| {
+            ZapClockError::InvalidConnectionString {
+                message: e.to_string(),
+            }
+        })?;
+
+        let opts = RelayOptions::new()
+            .reconnect(true)
+            .retry_interval(Duration::from_secs(RELAY_RETRY_INTERVAL_SECS))
+            .adjust_retry_interval(true);
+        let relay = Relay::with_opts(uri.relay_url.clone(), opts);
+
+        Ok(Self {
+            inner: Arc::new(SessionInner {
+                uri,
+                relay,
+                subscribed: AtomicBool::new(false),
+            }),
+            policy: NetworkPolicy::current(),
+        })
+    }
+
+    /// Use `policy` for this session's timeouts
+    pub fn with_policy(mut self, policy: NetworkPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the shared session for a connection string, creating it on first use
+    pub fn shared(connection_string: &str) -> Result<Self> {
+        let mut sessions = SESSIONS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        let key = sha256::Hash::hash(connection_string.as_bytes());
+        if let Some(session) = sessions.get(&key) {
+            return Ok(session.clone());
+        }
+
+        debug!("🔧 Creating new session");
+        let session = Self::new(connection_string)?;
+        sessions.insert(key, session.clone());
+        Ok(session)
+    }
+
+    /// Relay used by the wallet service
+    pub fn relay_url(&self) -> &RelayUrl {
+        &self.inner.uri.relay_url
+    }
+
+    /// Timeouts and retries used by this session
+    pub fn policy(&self) -> &NetworkPolicy {
+        &self.policy
+    }
+
+    /// Whether the relay connection is currently up
+    pub fn is_connected(&self) -> bool {
+        self.inner.relay.is_connected()
+    }
+
+    /// Connect to the relay and subscribe to wallet responses
+    ///
+    /// Returns immediately when the session is already connected.
+    pub async fn connect(&self) -> Result<()> {
+        let relay = &self.inner.relay;
+
+        if !relay.is_connected() {
+            info!(relay = %self.relay_url(), "🔌 Connecting to relay");
+            let started = Instant::now();
+            let mut notifications = relay.notifications();
+
+            // No-op when the auto-reconnect loop is already running
+            relay.connect(None).await;
+
+            if !relay.is_connected() {
+                timeout(self.policy.relay_connect_timeout(), async {
+                    loop {
+                        match notifications.recv().await {
+                            Ok(RelayNotification::RelayStatus {
+                                status: RelayStatus::Connected,
+                            }) => return Ok(()),
+                            Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                            Err(RecvError::Closed) => {
+                                return Err(ZapClockError::Relay {
+                                    message: "Relay connection closed".to_string(),
+                                })
+                            }
+                        }
+                    }
+                })
+                .await
+                .map_err(|_| ZapClockError::Relay {
+                    message: format!(
+                        "Could not connect to {} within {} seconds",
+                        self.relay_url(),
+                        self.policy.relay_connect_timeout_secs
+                    ),
+                })??;
+            }
+            info!(elapsed_ms = started.elapsed().as_millis() as u64, "✅ Relay connected");
+        }
+
+        // Subscription is kept by the relay and restored after reconnects
+        if !self.inner.subscribed.swap(true, Ordering::SeqCst) {
+            let filter = Filter::new()
+                .author(self.inner.uri.public_key)
+                .kind(Kind::WalletConnectResponse)
+                .limit(0);
+
+            if let Err(e) = relay
+                .subscribe_with_id(
+                    SubscriptionId::new(RESPONSE_SUBSCRIPTION_ID),
+                    vec![filter],
+                    SubscribeOptions::default(),
+                )
+                .await
+            {
+                self.inner.subscribed.store(false, Ordering::SeqCst);
+                return Err(e.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send a NIP-47 request and wait for the wallet's response
+    pub async fn send_request(&self, request: nip47::Request) -> Result<WalletResponse> {
+        let event = self.sign_request(request)?;
+        self.send_event(event).await
+    }
+
+    /// Encrypt and sign a NIP-47 request without sending it
+    ///
+    /// The signed event can be stored and re-sent later; wallets ignore
+    /// events they have already handled.
+    pub fn sign_request(&self, request: nip47::Request) -> Result<Event> {
+        Ok(request.to_event(&self.inner.uri)?)
+    }
+
+    /// Publish a signed request event and wait for the wallet's response
+    pub async fn send_event(&self, event: Event) -> Result<WalletResponse> {
+        self.connect().await?;
+
+        let notifications = self.inner.relay.notifications();
+        let id = self.inner.relay.send_event(event).await?;
+        info!(request_id = %id, "📤 Request published");
+        self.wait_for_response(id, notifications, &ProgressReporter::default())
+            .await
+    }
+
+    /// Publish a signed payment request and wait up to `wait` for the response
+    ///
+    /// Reports relay connection, publish and response. `cancel` is committed
+    /// right before publishing; a cancelled request is never sent. Once the
+    /// request may have reached the wallet, a relay failure or missing
+    /// response is reported as `PaymentUnknown`.
+    pub async fn send_payment_event(
+        &self,
+        event: Event,
+        progress: &ProgressReporter,
+        cancel: &PaymentCancel,
+        wait: Duration,
+    ) -> Result<WalletResponse> {
+        self.connect().await?;
+        progress.emit(PaymentProgress::RelayConnected {
+            relay: self.relay_url().to_string(),
+        });
+
+        cancel.commit()?;
+        let notifications = self.inner.relay.notifications();
+        let id = self
+            .inner
+            .relay
+            .send_event(event)
+            .await
+            .map_err(ZapClockError::payment_unknown)?;
+        info!(request_id = %id, "📤 Request published");
+        progress.emit(PaymentProgress::RequestPublished {
+            request_id: id.to_hex(),
+        });
+
+        match timeout(wait, self.wait_for_response(id, notifications, progress)).await {
+            Ok(response) => response.map_err(ZapClockError::payment_unknown),
+            Err(_) => {
+                warn!(timeout_secs = wait.as_secs(), "⏱️ Timeout: no response from the wallet");
+                Err(ZapClockError::payment_unknown(format!(
+                    "no response from the wallet within {} seconds",
+                    wait.as_secs()
+                )))
+            }
+        }
+    }
+
+    /// Wait for the wallet's response to request `id`
+    async fn wait_for_response(
+        &self,
+        id: EventId,
+        mut notifications: Receiver<RelayNotification>,
+        progress: &ProgressReporter,
+    ) -> Result<WalletResponse> {
+        let published = Instant::now();
+        loop {
+            match notifications.recv().await {
+                Ok(RelayNotification::Event { event, .. }) => {
+                    if event.kind == Kind::WalletConnectResponse
+                        && event.tags.event_ids().next() == Some(&id)
+                    {
+                        info!(
+                            elapsed_ms = published.elapsed().as_millis() as u64,
+                            "📥 Wallet response received"
+                        );
+                        progress.emit(PaymentProgress::ResponseReceived);
+                        return self.decrypt_response(&event);
+                    }
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => {
+                    return Err(ZapClockError::Relay {
+                        message: "Relay connection closed before the wallet responded"
+                            .to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Send a NIP-47 request answered by several responses (`multi_*` methods)
+    ///
+    /// Responses are keyed by their `d` tag. Returns whatever arrived
+    /// within `wait`; a response without a `d` tag means the wallet
+    /// rejected the whole request. Like `send_payment_event`, `cancel` is
+    /// committed right before publishing.
+    pub async fn send_multi_request(
+        &self,
+        request: nip47::Request,
+        ids: &[String],
+        wait: Duration,
+        progress: &ProgressReporter,
+        cancel: &PaymentCancel,
+    ) -> Result<HashMap<String, WalletResponse>> {
+        self.connect().await?;
+        progress.emit(PaymentProgress::RelayConnected {
+            relay: self.relay_url().to_string(),
+        });
+
+        let event = request.to_event(&self.inner.uri)?;
+        cancel.commit()?;
+        let mut notifications = self.inner.relay.notifications();
+        let id = self
+            .inner
+            .relay
+            .send_event(event)
+            .await
+            .map_err(ZapClockError::payment_unknown)?;
+        info!(request_id = %id, parts = ids.len(), "📤 Request published");
+        progress.emit(PaymentProgress::RequestPublished {
+            request_id: id.to_hex(),
+        });
+
+        let mut responses = HashMap::new();
+        let collect = async {
+            loop {
+                match notifications.recv().await {
+                    Ok(RelayNotification::Event { event, .. }) => {
+                        if event.kind != Kind::WalletConnectResponse
+                            || event.tags.event_ids().next() != Some(&id)
+                        {
+                            continue;
+                        }
+                        let response = match self.decrypt_response(&event) {
+                            Ok(response) => response,
+                            Err(e) => {
+                                warn!(error = %e, "⚠️ Ignoring undecodable response");
+                                continue;
+                            }
+                        };
+                        match event.tags.identifier() {
+                            Some(d) if ids.iter().any(|i| i == d) => {
+                                responses.insert(d.to_string(), response);
+                                if responses.len() == ids.len() {
+                                    return Ok(());
+                                }
+                            }
+                            _ => {
+                                response.into_result::<serde_json::Value>()?;
+                            }
+                        }
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => {
+                        return Err(ZapClockError::payment_unknown(
+                            "relay connection closed before the wallet responded",
+                        ))
+                    }
+                }
+            }
+        };
+        let result = timeout(wait, collect).await;
+
+        match result {
+            Ok(Err(e)) if responses.is_empty() => Err(e),
+            _ => {
+                if !responses.is_empty() {
+                    progress.emit(PaymentProgress::ResponseReceived);
+                }
+                Ok(responses)
+            }
+        }
+    }
+
+    /// Look up the wallet's stored response to an earlier request
+    pub async fn fetch_response(&self, request_id: EventId) -> Result<Option<WalletResponse>> {
+        self.connect().await?;
+
+        let filter = Filter::new()
+            .author(self.inner.uri.public_key)
+            .kind(Kind::WalletConnectResponse)
+            .event(request_id)
+            .limit(1);
+
+        let events = self
+            .inner
+            .relay
+            .fetch_events(
+                vec![filter],
+                self.policy.relay_query_timeout(),
+                FilterOptions::ExitOnEOSE,
+            )
+            .await?;
+
+        events.first().map(|event| self.decrypt_response(event)).transpose()
+    }
+
+    /// Decrypt a response event from the wallet service
+    fn decrypt_response(&self, event: &Event) -> Result<WalletResponse> {
+        let json = nip04::decrypt(&self.inner.uri.secret, &event.pubkey, &event.content)
+            .map_err(|e| ZapClockError::InvalidResponse {
+                message: format!("Failed to decrypt response: {}", e),
+            })?;
+
+        serde_json::from_str(&json).map_err(|e| ZapClockError::InvalidResponse {
+            message: e.to_string(),
+        })
+    }
+
+    /// Fetch the wallet service's info event (kind 13194)
+    async fn fetch_info_event(&self) -> Result<Option<InfoEvent>> {
+        self.connect().await?;
+
+        let filter = Filter::new()
+            .author(self.inner.uri.public_key)
+            .kind(Kind::WalletConnectInfo)
+            .limit(1);
+
+        let events = self
+            .inner
+            .relay
+            .fetch_events(
+                vec![filter],
+                self.policy.relay_query_timeout(),
+                FilterOptions::ExitOnEOSE,
+            )
+            .await?;
+
+        Ok(events.first().map(InfoEvent::from_event))
+    }
+
+    /// Close the relay connection and drop the session from the shared cache
+    pub fn disconnect(&self) {
+        if let Some(sessions) = SESSIONS.get() {
+            sessions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .retain(|_, s| !Arc::ptr_eq(&s.inner, &self.inner));
+        }
+
+        self.inner.subscribed.store(false, Ordering::SeqCst);
+        if let Err(e) = self.inner.relay.disconnect() {
+            warn!(error = %e, "⚠️ Relay disconnect failed");
+        }
+        info!("🔌 Session closed");
+    }
+}
+
+#[derive(Clone)]
 pub struct NwcClient {
-    nwc_uri: NostrWalletConnectURI,
+    session: NwcSession,
+    progress: ProgressReporter,
+    cancel: PaymentCancel,
 }
 
 impl NwcClient {
     /// Create new client from NWC connection string
+    ///
+    /// Reuses the shared session for this connection string if one is open.
     pub fn new(connection_string: &str) -> Result<Self> {
-        println!("🔧 [NWC] Starting client creation");
-        let nwc_uri = NostrWalletConnectURI::parse(connection_string)
-            .context("Failed to parse NWC connection string")?;
+        debug!("🔧 Starting client creation");
+        let session = NwcSession::shared(connection_string)?.with_policy(NetworkPolicy::current());
         
-        println!("✅ [NWC] Client creation successful");
-        println!("   Relay: {}", nwc_uri.relay_url);
-        Ok(Self { nwc_uri })
+        debug!(relay = %session.relay_url(), "✅ Client creation successful");
+        Ok(Self::with_session(session))
+    }
+
+    /// Create client on top of an existing session
+    pub fn with_session(session: NwcSession) -> Self {
+        Self {
+            session,
+            progress: ProgressReporter::default(),
+            cancel: PaymentCancel::new(),
+        }
+    }
+    
+    /// Report payment progress to `progress`
+    pub fn with_progress(mut self, progress: ProgressReporter) -> Self {
+        self.progress = progress;
+        self
+    }
+    
+    /// Receiver of this client's payment progress
+    pub fn progress(&self) -> &ProgressReporter {
+        &self.progress
+    }
+    
+    /// Let `cancel` stop this client's payments before they are published
+    pub fn with_cancel(mut self, cancel: PaymentCancel) -> Self {
+        self.cancel = cancel;
+        self
+    }
+    
+    /// Cancellation handle of this client's payments
+    pub fn cancel(&self) -> &PaymentCancel {
+        &self.cancel
+    }
+    
+    /// Use `policy` instead of the app-wide network policy
+    pub fn with_policy(mut self, policy: NetworkPolicy) -> Self {
+        self.session = self.session.with_policy(policy);
+        self
+    }
+    
+    /// Timeouts and retries used by this client
+    pub fn policy(&self) -> &NetworkPolicy {
+        self.session.policy()
     }
     
     /// Test connection and get balance (with timeout)
+    #[instrument(skip_all)]
     pub async fn test_connection(&self) -> Result<u64> {
-        use tokio::time::{timeout, Duration};
-        
-        println!("🔍 [NWC] Starting connection test");
+        info!("🔍 Starting connection test");
         // OK if NWC URI is correctly parsed
         // Check relay URL string representation
-        if self.nwc_uri.relay_url.to_string().is_empty() {
-            println!("❌ [NWC] Relay URL not set");
-            anyhow::bail!("Relay URL not set");
+        if self.session.relay_url().to_string().is_empty() {
+            error!("❌ Relay URL not set");
+            return Err(ZapClockError::InvalidConnectionString {
+                message: "Relay URL not set".to_string(),
+            });
         }
         
-        println!("   Relay: {}", self.nwc_uri.relay_url);
+        debug!(relay = %self.session.relay_url());
         
-        // Get balance (30 second timeout)
-        println!("💰 [NWC] Fetching balance...");
+        // Get balance (retried while the relay or wallet doesn't answer)
+        info!("💰 Fetching balance...");
+        let result: GetBalanceResult = self
+            .request_with_retry(nip47::Request::get_balance())
+            .await
+            .inspect_err(|e| error!(error = %e, "❌ Balance retrieval error"))?;
+        
+        // Convert msats to sats (1 sats = 1000 msats)
+        let balance_msats = result.balance;
+        let balance_sats = balance_msats / 1000;
+        info!(balance_sats, balance_msats, "✅ Connection test successful");
+        Ok(balance_sats)
+    }
+    
+    /// Discover what the wallet service supports and what this connection may do
+    ///
+    /// Wallets that don't implement `get_info` (or restrict it, or never
+    /// answer it) are reported from the info event alone.
+    #[instrument(skip_all)]
+    pub async fn get_wallet_info(&self) -> Result<WalletInfo> {
+        info!("🔍 Fetching wallet info");
+        
+        let info_event = self
+            .policy()
+            .retry("info event", || self.session.fetch_info_event())
+            .await?;
+        if info_event.is_none() {
+            warn!("⚠️ Wallet service info event not found");
+        }
+        
+        let get_info = self.request_with_retry::<GetInfoResult>(nip47::Request::get_info()).await;
+        let get_info = get_info_or_fallback(get_info, info_event.is_some())?;
+        
+        let get_info_supported = get_info.is_some();
+        let info = get_info.unwrap_or_default();
+        let (event_methods, notifications, encryption) = match info_event {
+            Some(e) => (e.methods, e.notifications, e.encryption),
+            None => (Vec::new(), Vec::new(), vec!["nip04".to_string()]),
+        };
+        
+        // Prefer the per-connection list; fall back to what the service advertises
+        let methods = if get_info_supported { info.methods } else { event_methods };
+        let notifications = if info.notifications.is_empty() { notifications } else { info.notifications };
+        let can_pay_invoice = methods.iter().any(|m| m == "pay_invoice");
+        
+        info!(methods = ?methods, "✅ Wallet info retrieved");
+        Ok(WalletInfo {
+            alias: info.alias,
+            color: info.color,
+            pubkey: info.pubkey,
+            network: info.network,
+            block_height: info.block_height,
+            block_hash: info.block_hash,
+            methods,
+            notifications,
+            encryption,
+            get_info_supported,
+            can_pay_invoice,
+        })
+    }
+    
+    /// List wallet transactions, newest first (with timeout)
+    pub async fn list_transactions(&self, filter: TransactionFilter) -> Result<Vec<WalletTransaction>> {
+        info!(filter = ?filter, "📜 Listing transactions");
+        
+        let request = nip47::Request::list_transactions(ListTransactionsRequest {
+            from: filter.from.map(Timestamp::from),
+            until: filter.until.map(Timestamp::from),
+            limit: filter.limit,
+            offset: filter.offset,
+            unpaid: filter.unpaid,
+            transaction_type: filter.transaction_type.map(Into::into),
+        });
+        
+        let result: ListTransactionsResult = self.request_with_retry(request).await?;
+        info!(count = result.transactions.len(), "✅ Transactions retrieved");
+        Ok(result.transactions)
+    }
+    
+    /// Look up a single invoice by payment hash or bolt11 (with timeout)
+    pub async fn lookup_invoice(&self, lookup: InvoiceLookup) -> Result<WalletTransaction> {
+        match &lookup {
+            InvoiceLookup::PaymentHash(payment_hash) => info!(payment_hash = %payment_hash, "🔍 Looking up invoice"),
+            InvoiceLookup::Bolt11(bolt11) => info!(invoice_prefix = %redact::invoice(bolt11), "🔍 Looking up invoice"),
+        }
+        
+        let request = nip47::Request::lookup_invoice(match lookup {
+            InvoiceLookup::PaymentHash(payment_hash) => LookupInvoiceRequest {
+                payment_hash: Some(payment_hash),
+                invoice: None,
+            },
+            InvoiceLookup::Bolt11(invoice) => LookupInvoiceRequest {
+                payment_hash: None,
+                invoice: Some(invoice),
+            },
+        });
+        
+        let transaction: WalletTransaction = self.request_with_retry(request).await?;
+        info!(settled = transaction.settled_at.is_some(), "✅ Invoice found");
+        Ok(transaction)
+    }
+    
+    /// Send a read-only request, retrying retryable failures per the policy
+    async fn request_with_retry<T: DeserializeOwned>(&self, request: nip47::Request) -> Result<T> {
+        let method = request.method.to_string();
+        self.policy()
+            .retry(&method, || self.request_with_timeout(request.clone()))
+            .await
+    }
+    
+    /// Send a request and decode its result, failing after the wallet timeout
+    async fn request_with_timeout<T: DeserializeOwned>(&self, request: nip47::Request) -> Result<T> {
+        let method = request.method;
+        let timeout_secs = self.policy().wallet_timeout_secs;
         let result = timeout(
-            Duration::from_secs(30),
-            async {
-                let nwc_client = NWC::new(self.nwc_uri.clone());
-                nwc_client.get_balance().await
-            }
+            Duration::from_secs(timeout_secs),
+            async { self.session.send_request(request).await?.into_result::<T>() }
         ).await;
         
         match result {
-            Ok(Ok(balance_msats)) => {
-                // Convert msats to sats (1 sats = 1000 msats)
-                let balance_sats = balance_msats / 1000;
-                println!("✅ [NWC] Connection test successful - balance: {} sats ({} msats)", balance_sats, balance_msats);
-                Ok(balance_sats)
-            }
-            Ok(Err(e)) => {
-                println!("❌ [NWC] Balance retrieval error: {}", e);
-                Err(e.into())
-            }
+            Ok(result) => result.inspect_err(|e| error!(method = %method, error = %e, "❌ Wallet request failed")),
             Err(_) => {
-                println!("⏱️ [NWC] Timeout: no response within 30 seconds");
-                anyhow::bail!("NWC connection test timed out (30 seconds)")
+                warn!(method = %method, timeout_secs, "⏱️ Timeout: no response from the wallet");
+                Err(ZapClockError::NwcTimeout { seconds: timeout_secs })
             }
         }
     }
     
     /// Pay Invoice (with timeout)
-    pub async fn pay_invoice(&self, invoice: &str) -> Result<String> {
-        use tokio::time::{timeout, Duration};
+    ///
+    /// The returned preimage is checked against the invoice's payment hash.
+    /// `amount_msats` is only needed for invoices without an amount.
+    #[instrument(skip_all, fields(payment_hash = %invoice.payment_hash))]
+    pub async fn pay_invoice(
+        &self,
+        invoice: &DecodedInvoice,
+        amount_msats: Option<u64>,
+    ) -> Result<PayInvoiceResult> {
+        info!(invoice_prefix = %redact::invoice(&invoice.bolt11), "💳 Starting Invoice payment");
         
-        println!("💳 [NWC] Starting Invoice payment");
-        println!("   Invoice: {}...", &invoice[..std::cmp::min(30, invoice.len())]);
+        let request = self.prepare_pay_invoice(invoice, amount_msats)?;
+        self.send_payment(request, &invoice.payment_hash).await
+    }
+    
+    /// Sign a `pay_invoice` request without sending it
+    ///
+    /// The request id is derived from the payment hash, so preparing the
+    /// same invoice twice yields the same request parameters.
+    pub fn prepare_pay_invoice(
+        &self,
+        invoice: &DecodedInvoice,
+        amount_msats: Option<u64>,
+    ) -> Result<Event> {
+        let request_id = format!("pay_{}", invoice.payment_hash);
+        debug!(request_id = %request_id, "📝 Request signed");
         
-        // Set timeout to 60 seconds
-        let result = timeout(
-            Duration::from_secs(60),
-            self.pay_invoice_internal(invoice)
-        ).await;
+        let pay_request = PayInvoiceRequest {
+            id: Some(request_id),
+            invoice: invoice.bolt11.clone(),
+            amount: amount_msats,
+        };
+        
+        self.session.sign_request(nip47::Request::pay_invoice(pay_request))
+    }
+    
+    /// Send a signed payment request (with timeout)
+    ///
+    /// The returned preimage is checked against `payment_hash`.
+    #[instrument(skip_all, fields(request_id = %request.id))]
+    pub async fn send_payment(&self, request: Event, payment_hash: &str) -> Result<PayInvoiceResult> {
+        info!(relay = %self.session.relay_url(), "📤 Sending payment request");
+        let started = Instant::now();
+        
+        // Not retried: a second attempt would be a second payment request
+        let response = self
+            .session
+            .send_payment_event(
+                request,
+                &self.progress,
+                &self.cancel,
+                Duration::from_secs(self.policy().payment_timeout_secs),
+            )
+            .await;
+        let result = response.and_then(|response| {
+            response.into_result::<PayInvoiceResult>().map_err(|e| match e {
+                ZapClockError::Wallet { .. } => e,
+                // Answered, but with nothing that tells whether it paid
+                e => ZapClockError::payment_unknown(e),
+            })
+        });
         
         match result {
-            Ok(Ok(preimage)) => {
-                println!("✅ [NWC] Payment successful!");
-                println!("   Preimage: {}", &preimage[..std::cmp::min(20, preimage.len())]);
-                Ok(preimage)
+            Ok(result) => {
+                invoice::verify_preimage(payment_hash, &result.preimage)
+                    .inspect_err(|e| error!(error = %e, "❌ Preimage verification failed"))?;
+                info!(
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    "✅ Payment successful! (preimage verified)"
+                );
+                self.progress.emit(PaymentProgress::Verified {
+                    payment_hash: payment_hash.to_string(),
+                });
+                Ok(result)
             }
-            Ok(Err(e)) => {
-                println!("❌ [NWC] Payment error: {}", e);
+            Err(e) => {
+                error!(error = %e, "❌ Payment error");
                 Err(e)
             }
-            Err(_) => {
-                println!("⏱️ [NWC] Timeout: no response within 60 seconds");
-                anyhow::bail!("NWC payment timed out (60 seconds)")
-            }
         }
     }
     
-    /// Internal implementation of Invoice payment
-    async fn pay_invoice_internal(&self, invoice: &str) -> Result<String> {
-        println!("🔧 [NWC] Initializing NWC client...");
-        let nwc_client = NWC::new(self.nwc_uri.clone());
-        println!("✅ [NWC] NWC client initialization complete");
+    /// Pay several invoices with one `multi_pay_invoice` request (with timeout)
+    ///
+    /// Returns one result per invoice, in order. Invoices the wallet didn't
+    /// answer for in time are reported as `PaymentUnknown`.
+    /// Fails as a whole only if the wallet rejected the entire request.
+    #[instrument(skip_all, fields(invoices = invoices.len()))]
+    pub async fn multi_pay_invoice(
+        &self,
+        invoices: &[DecodedInvoice],
+    ) -> Result<Vec<Result<PayInvoiceResult>>> {
+        info!("💳 Starting multi_pay_invoice");
         
-        // Create PayInvoiceRequest
-        let request_id = format!("pay_{}", rand::random::<u64>());
-        println!("   Request ID: {}", request_id);
+        // Invoice ids come back as the `d` tag of each response
+        let ids: Vec<String> = invoices.iter().map(|i| i.payment_hash.clone()).collect();
+        let request = MultiPayInvoiceRequest {
+            invoices: invoices
+                .iter()
+                .map(|invoice| PayInvoiceRequest {
+                    id: Some(invoice.payment_hash.clone()),
+                    invoice: invoice.bolt11.clone(),
+                    amount: None,
+                })
+                .collect(),
+        };
         
-        let pay_request = PayInvoiceRequest {
+        let timeout_secs = self.policy().payment_timeout_secs;
+        let mut responses = self
+            .session
+            .send_multi_request(
+                nip47::Request::multi_pay_invoice(request),
+                &ids,
+                Duration::from_secs(timeout_secs),
+                &self.progress,
+                &self.cancel,
+            )
+            .await
+            .inspect_err(|e| error!(error = %e, "❌ multi_pay_invoice rejected"))?;
+        
+        let results = invoices
+            .iter()
+            .map(|invoice| match responses.remove(&invoice.payment_hash) {
+                Some(response) => response.into_result::<PayInvoiceResult>().and_then(|result| {
+                    invoice.verify_preimage(&result.preimage)?;
+                    self.progress.emit(PaymentProgress::Verified {
+                        payment_hash: invoice.payment_hash.clone(),
+                    });
+                    Ok(result)
+                }),
+                None => Err(ZapClockError::payment_unknown(format!(
+                    "no response from the wallet within {} seconds",
+                    timeout_secs
+                ))),
+            })
+            .collect::<Vec<_>>();
+        
+        info!(
+            paid = results.iter().filter(|r| r.is_ok()).count(),
+            total = results.len(),
+            "✅ multi_pay_invoice finished"
+        );
+        Ok(results)
+    }
+    
+    /// Pay a node directly with keysend (with timeout)
+    ///
+    /// Uses the caller's hex `preimage`, or a fresh random one, so the
+    /// wallet's answer can be checked against its hash.
+    #[instrument(skip(self, preimage, tlv_records))]
+    pub async fn pay_keysend(
+        &self,
+        pubkey: &str,
+        amount_msats: u64,
+        preimage: Option<&str>,
+        tlv_records: Vec<TlvRecord>,
+    ) -> Result<PayInvoiceResult> {
+        info!("💳 Starting keysend payment");
+        
+        let preimage = preimage.map_or_else(keysend_preimage, str::to_string);
+        let payment_hash = keysend_payment_hash(&preimage)?;
+        let request = self.prepare_pay_keysend(pubkey, amount_msats, &preimage, tlv_records)?;
+        self.send_payment(request, &payment_hash).await
+    }
+    
+    /// Sign a `pay_keysend` request without sending it
+    ///
+    /// The node key, preimage and TLV values are validated first.
+    pub fn prepare_pay_keysend(
+        &self,
+        pubkey: &str,
+        amount_msats: u64,
+        preimage: &str,
+        tlv_records: Vec<TlvRecord>,
+    ) -> Result<Event> {
+        validate_keysend(pubkey, &tlv_records)?;
+        let request_id = format!("keysend_{}", keysend_payment_hash(preimage)?);
+        debug!(request_id = %request_id, "📝 Request signed");
+        
+        let keysend_request = PayKeysendRequest {
             id: Some(request_id),
-            invoice: invoice.to_string(),
-            amount: None,
+            amount: amount_msats,
+            pubkey: pubkey.to_string(),
+            preimage: Some(preimage.to_string()),
+            tlv_records: tlv_records.into_iter().map(Into::into).collect(),
         };
         
-        // pay_invoice request
-        println!("📤 [NWC] Sending pay_invoice request...");
-        println!("   Relay: {}", self.nwc_uri.relay_url);
-        
-        let response = nwc_client
-            .pay_invoice(pay_request)
-            .await
-            .context("Invoice payment via NWC failed")?;
+        self.session.sign_request(nip47::Request::pay_keysend(keysend_request))
+    }
+    
+    /// Look for the wallet's answer to a payment request sent earlier
+    ///
+    /// Returns None if the wallet hasn't answered (yet). A stored success
+    /// is only accepted if its preimage matches `payment_hash`.
+    pub async fn find_payment_response(
+        &self,
+        request_id: EventId,
+        payment_hash: &str,
+    ) -> Result<Option<PayInvoiceResult>> {
+        let Some(response) = self.session.fetch_response(request_id).await? else {
+            return Ok(None);
+        };
         
-        // Return preimage
-        Ok(response.preimage)
+        let result: PayInvoiceResult = response.into_result()?;
+        invoice::verify_preimage(payment_hash, &result.preimage)?;
+        info!("✅ Found earlier payment response (preimage verified)");
+        Ok(Some(result))
+    }
+}
+
+/// Fresh random hex preimage for a keysend payment
+pub fn keysend_preimage() -> String {
+    hex::encode(rand::random::<[u8; 32]>())
+}
+
+/// Payment hash of a hex keysend preimage (which must be 32 bytes)
+pub fn keysend_payment_hash(preimage: &str) -> Result<String> {
+    match hex::decode(preimage) {
+        Ok(bytes) if bytes.len() == 32 => Ok(invoice::payment_hash_of(&bytes)),
+        _ => Err(ZapClockError::InvalidKeysend {
+            message: "Preimage must be 32 bytes of hex".to_string(),
+        }),
+    }
+}
+
+/// Check a keysend node key (33-byte compressed, hex) and TLV values (hex)
+fn validate_keysend(pubkey: &str, tlv_records: &[TlvRecord]) -> Result<()> {
+    let is_node_key = hex::decode(pubkey)
+        .is_ok_and(|key| key.len() == 33 && matches!(key[0], 0x02 | 0x03));
+    if !is_node_key {
+        return Err(ZapClockError::InvalidKeysend {
+            message: format!("Not a 33-byte hex node public key: {}", pubkey),
+        });
+    }
+
+    if let Some(record) = tlv_records.iter().find(|r| hex::decode(&r.value).is_err()) {
+        return Err(ZapClockError::InvalidKeysend {
+            message: format!("TLV record {} value is not hex", record.record_type),
+        });
+    }
+    Ok(())
+}
+
+/// `get_info` result, or None if the info event has to do instead
+///
+/// Besides wallets refusing `get_info`, one that never answers it is
+/// described by its info event, if it published one.
+fn get_info_or_fallback(
+    get_info: Result<GetInfoResult>,
+    has_info_event: bool,
+) -> Result<Option<GetInfoResult>> {
+    match get_info {
+        Ok(info) => Ok(Some(info)),
+        Err(ZapClockError::Wallet { code, message, .. })
+            if matches!(code, WalletErrorCode::NotImplemented | WalletErrorCode::Restricted) =>
+        {
+            warn!(code = %code, message = %message, "⚠️ get_info unavailable");
+            Ok(None)
+        }
+        Err(e @ (ZapClockError::NwcTimeout { .. } | ZapClockError::DeadlineExceeded { .. }))
+            if has_info_event =>
+        {
+            warn!(error = %e, "⚠️ get_info unanswered, using the info event");
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE_KEY: &str = "02eec7245d6b7d2ccb30380bfbe2a3648cd7a942653f5aa340edcea1f283686619";
+
+    fn tlv(value: &str) -> TlvRecord {
+        TlvRecord {
+            record_type: 34349334,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn validate_keysend_accepts_compressed_node_key() {
+        assert!(validate_keysend(NODE_KEY, &[tlv("68656c6c6f")]).is_ok());
+    }
+
+    #[test]
+    fn validate_keysend_rejects_malformed_input() {
+        for pubkey in ["", "02abcd", &NODE_KEY[2..], &format!("04{}", &NODE_KEY[2..])] {
+            assert!(matches!(
+                validate_keysend(pubkey, &[]),
+                Err(ZapClockError::InvalidKeysend { .. })
+            ));
+        }
+        assert!(matches!(
+            validate_keysend(NODE_KEY, &[tlv("hello")]),
+            Err(ZapClockError::InvalidKeysend { .. })
+        ));
+    }
+
+    #[test]
+    fn keysend_payment_hash_needs_32_byte_preimage() {
+        let preimage = keysend_preimage();
+        assert_eq!(
+            keysend_payment_hash(&preimage).unwrap(),
+            invoice::payment_hash_of(&hex::decode(&preimage).unwrap())
+        );
+        assert!(keysend_payment_hash("abcd").is_err());
+        assert!(keysend_payment_hash("not hex").is_err());
+    }
+
+    #[test]
+    fn get_info_falls_back_to_the_info_event() {
+        let refused = || {
+            Err(ZapClockError::Wallet {
+                code: WalletErrorCode::NotImplemented,
+                message: "get_info".to_string(),
+                retryable: false,
+            })
+        };
+        let unanswered = || Err(ZapClockError::NwcTimeout { seconds: 30 });
+
+        assert!(get_info_or_fallback(Ok(GetInfoResult::default()), true).unwrap().is_some());
+        assert!(get_info_or_fallback(refused(), false).unwrap().is_none());
+        assert!(get_info_or_fallback(unanswered(), true).unwrap().is_none());
+        let deadline = Err(ZapClockError::DeadlineExceeded { seconds: 120 });
+        assert!(get_info_or_fallback(deadline, true).unwrap().is_none());
+
+        // Nothing else describes the wallet
+        assert!(matches!(
+            get_info_or_fallback(unanswered(), false),
+            Err(ZapClockError::NwcTimeout { .. })
+        ));
+        let relay = Err(ZapClockError::Relay {
+            message: "closed".to_string(),
+        });
+        assert!(get_info_or_fallback(relay, true).is_err());
+    }
+
+    #[test]
+    fn shared_sessions_are_keyed_by_connection_string() {
+        let uri = |secret: &str| {
+            format!(
+                "nostr+walletconnect://b889ff5b1513b641e2a139f661a661364979c5beee91842f8f0ef42ab558e9d4?relay=wss%3A%2F%2Frelay.example.com&secret={}",
+                secret
+            )
+        };
+        let first = uri("1".repeat(64).as_str());
+        let second = uri("2".repeat(64).as_str());
+
+        let session = NwcSession::shared(&first).unwrap();
+        assert!(Arc::ptr_eq(&session.inner, &NwcSession::shared(&first).unwrap().inner));
+        assert!(!Arc::ptr_eq(&session.inner, &NwcSession::shared(&second).unwrap().inner));
     }
 }
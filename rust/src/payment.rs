@@ -0,0 +1,1033 @@
+//! Penalty payment flow (LNURL-pay invoice → NWC payment)
+
+use crate::error::{Result, WalletErrorCode, ZapClockError};
+use crate::invoice::{self, DecodedInvoice};
+use crate::lightning::{FetchedInvoice, LightningPayment, SuccessAction, EXPECTED_NETWORK};
+use crate::nwc::{self, InvoiceLookup, NwcClient, PayInvoiceResult, TlvRecord};
+use crate::journal::{self, JournalEntry, JournalStage};
+use crate::progress::{PaymentProgress, ProgressReporter};
+use crate::store::{self, PaymentRecord};
+use crate::zap::ZapInfo;
+use nostr_sdk::{Event, JsonUtil, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::field::Empty;
+use tracing::{info, instrument, warn};
+
+/// TLV type commonly used for keysend messages
+const KEYSEND_MESSAGE_TLV_TYPE: u64 = 34349334;
+
+/// Where a penalty is sent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PaymentRecipient {
+    /// Lightning address, LNURL or `lightning:` URI
+    Lnurl { address: String },
+    /// Fixed BOLT11 invoice
+    Bolt11 { invoice: String },
+    /// Node public key (hex), paid with keysend
+    Keysend {
+        pubkey: String,
+        tlv_records: Vec<TlvRecord>,
+        /// Hex preimage to use (a random one if None)
+        #[serde(default)]
+        preimage: Option<String>,
+    },
+}
+
+/// Recipient of a share of a split penalty
+#[derive(Debug, Clone)]
+pub struct WeightedRecipient {
+    /// Lightning address, LNURL or `lightning:` URI
+    pub address: String,
+    /// Relative share (e.g. 50 / 30 / 20)
+    pub weight: u32,
+}
+
+/// Result of paying one share of a split penalty
+#[derive(Debug, Clone)]
+pub struct SplitShareOutcome {
+    pub address: String,
+    /// Share of the total amount assigned to this recipient
+    pub amount_sats: u64,
+    /// Receipt, if this share was paid
+    pub outcome: Option<PaymentOutcome>,
+    /// Why this share wasn't paid (None on success)
+    ///
+    /// `PaymentUnknown` means the share may still have been paid.
+    pub error: Option<ZapClockError>,
+}
+
+/// Receipt for a completed Lightning payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentOutcome {
+    /// Paid invoice (None for keysend payments)
+    pub bolt11: Option<String>,
+    /// Hex-encoded payment hash, decoded from the invoice
+    pub payment_hash: String,
+    /// Hex-encoded preimage returned by the wallet
+    pub preimage: String,
+    /// Amount paid to the recipient, excluding fees
+    pub amount_msats: u64,
+    /// Routing fees, if the wallet reported them
+    pub fees_paid_msats: Option<u64>,
+    /// Recipient the invoice was requested from (node id for raw invoices and keysend)
+    pub recipient: String,
+    /// Comment attached to the LNURL request (None if it was omitted)
+    pub comment: Option<String>,
+    /// Message, link or decrypted secret returned by the recipient (LUD-09)
+    pub success_action: Option<SuccessAction>,
+    /// NIP-57 zap request and receipt status (None if the recipient doesn't support zaps)
+    pub zap: Option<ZapInfo>,
+    /// Unix timestamp when the payment flow started
+    pub started_at: u64,
+    /// Unix timestamp when the wallet confirmed the payment
+    pub paid_at: u64,
+}
+
+/// Fetch an invoice from a Lightning address and pay it via NWC
+#[instrument(skip(client, comment))]
+pub async fn pay_to_address(
+    client: &NwcClient,
+    lightning_address: &str,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<PaymentOutcome> {
+    let key = attempt_key();
+    let deadline = payment_deadline(client);
+    let attempt = async {
+        let (record, request) =
+            prepare_lnurl(client, &key, lightning_address, amount_sats, comment).await?;
+        send_prepared(client, record, request).await
+    };
+    journaled(&key, lightning_address, within_deadline(client, deadline, attempt)).await
+}
+
+impl PaymentOutcome {
+    /// Receipt for a paid LNURL invoice
+    fn from_fetched(
+        fetched: FetchedInvoice,
+        paid: PayInvoiceResult,
+        recipient: &str,
+        started_at: u64,
+    ) -> Self {
+        let invoice = fetched.invoice;
+        let success_action = fetched
+            .success_action
+            .map(|action| action.resolve(&paid.preimage));
+
+        Self {
+            amount_msats: fetched.amount_msats,
+            bolt11: Some(invoice.bolt11),
+            payment_hash: invoice.payment_hash,
+            preimage: paid.preimage,
+            fees_paid_msats: paid.fees_paid,
+            recipient: recipient.to_string(),
+            comment: fetched.comment,
+            success_action,
+            zap: fetched.zap_request.map(ZapInfo::from),
+            started_at,
+            paid_at: Timestamp::now().as_u64(),
+        }
+    }
+}
+
+/// Pay a BOLT11 invoice generated elsewhere via NWC
+///
+/// `amount_sats` is required for "any amount" invoices and must match the
+/// invoice amount otherwise.
+#[instrument(skip(client, bolt11))]
+pub async fn pay_bolt11(
+    client: &NwcClient,
+    bolt11: &str,
+    amount_sats: Option<u64>,
+) -> Result<PaymentOutcome> {
+    let key = attempt_key();
+    let deadline = payment_deadline(client);
+    let attempt = async {
+        let (record, request) = prepare_bolt11(client, &key, bolt11, amount_sats).await?;
+        send_prepared(client, record, request).await
+    };
+    journaled(&key, "BOLT11 invoice", within_deadline(client, deadline, attempt)).await
+}
+
+/// Decode a BOLT11 invoice and check it can be paid with `amount_sats`
+fn validate_bolt11(bolt11: &str, amount_sats: Option<u64>) -> Result<(DecodedInvoice, u64)> {
+    let invoice = DecodedInvoice::parse(bolt11)?;
+    let amount_msats = invoice.payable_amount(amount_sats.map(|sats| sats * 1000))?;
+    invoice.check_network(EXPECTED_NETWORK)?;
+    invoice.check_not_expired()?;
+    Ok((invoice, amount_msats))
+}
+
+/// Report a validated invoice to the client's progress receiver
+fn report_invoice(client: &NwcClient, invoice: &DecodedInvoice, amount_msats: u64) {
+    client.progress().emit(PaymentProgress::InvoiceReceived {
+        amount_msats,
+        payment_hash: invoice.payment_hash.clone(),
+    });
+}
+
+/// NIP-47 only takes an amount for invoices that don't carry one
+fn amount_param(invoice: &DecodedInvoice, amount_msats: u64) -> Option<u64> {
+    invoice.amount_msats.is_none().then_some(amount_msats)
+}
+
+/// Add the comment as a keysend message record (unless one is already set)
+fn keysend_records(comment: Option<&str>, mut tlv_records: Vec<TlvRecord>) -> Vec<TlvRecord> {
+    if let Some(comment) = comment {
+        if !tlv_records.iter().any(|r| r.record_type == KEYSEND_MESSAGE_TLV_TYPE) {
+            tlv_records.push(TlvRecord {
+                record_type: KEYSEND_MESSAGE_TLV_TYPE,
+                value: hex::encode(comment.as_bytes()),
+            });
+        }
+    }
+    tlv_records
+}
+
+/// Pay a node directly with keysend via NWC
+///
+/// Uses the caller's hex `preimage`, or generates one, so the payment can
+/// be verified. A `comment` is sent as a keysend message TLV record.
+#[instrument(skip(client, comment, tlv_records, preimage))]
+pub async fn pay_keysend(
+    client: &NwcClient,
+    pubkey: &str,
+    amount_sats: u64,
+    comment: Option<String>,
+    tlv_records: Vec<TlvRecord>,
+    preimage: Option<String>,
+) -> Result<PaymentOutcome> {
+    let key = attempt_key();
+    let deadline = payment_deadline(client);
+    let attempt = async {
+        let (record, request) =
+            prepare_keysend(client, &key, pubkey, amount_sats, comment, tlv_records, preimage)?;
+        send_prepared(client, record, request).await
+    };
+    journaled(&key, &format!("keysend:{}", pubkey), within_deadline(client, deadline, attempt)).await
+}
+
+/// Pay `amount_sats` to any kind of recipient
+///
+/// For BOLT11 recipients the amount must match the invoice (or fill in
+/// an "any amount" invoice).
+pub async fn pay_recipient(
+    client: &NwcClient,
+    recipient: &PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<PaymentOutcome> {
+    match recipient {
+        PaymentRecipient::Lnurl { address } => {
+            pay_to_address(client, address, amount_sats, comment).await
+        }
+        PaymentRecipient::Bolt11 { invoice } => pay_bolt11(client, invoice, Some(amount_sats)).await,
+        PaymentRecipient::Keysend {
+            pubkey,
+            tlv_records,
+            preimage,
+        } => {
+            pay_keysend(
+                client,
+                pubkey,
+                amount_sats,
+                comment,
+                tlv_records.clone(),
+                preimage.clone(),
+            )
+            .await
+        }
+    }
+}
+
+/// Split `amount_sats` across recipients by weight
+///
+/// Rounds down and hands leftover sats to the largest remainders, so the
+/// shares always add up to the total.
+fn split_amount(amount_sats: u64, recipients: &[WeightedRecipient]) -> Result<Vec<u64>> {
+    let total_weight: u64 = recipients.iter().map(|r| u64::from(r.weight)).sum();
+    if recipients.is_empty() || total_weight == 0 {
+        return Err(ZapClockError::InvalidSplit {
+            message: "No recipient with a positive weight".to_string(),
+        });
+    }
+
+    let mut shares: Vec<(usize, u64, u64)> = recipients
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let scaled = u128::from(amount_sats) * u128::from(r.weight);
+            let share = (scaled / u128::from(total_weight)) as u64;
+            let remainder = (scaled % u128::from(total_weight)) as u64;
+            (i, share, remainder)
+        })
+        .collect();
+
+    let assigned: u64 = shares.iter().map(|(_, share, _)| share).sum();
+    shares.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+    for (_, share, _) in shares.iter_mut().take((amount_sats - assigned) as usize) {
+        *share += 1;
+    }
+    shares.sort_by_key(|(i, _, _)| *i);
+
+    Ok(shares.into_iter().map(|(_, share, _)| share).collect())
+}
+
+/// Split a penalty across weighted recipients and pay every share
+///
+/// Invoices are fetched from each recipient, then paid with a single
+/// `multi_pay_invoice` request if the wallet supports it, or one by one
+/// otherwise. Shares fail independently; the call itself only fails if
+/// the split is invalid. Fetching the invoices shares one deadline; each
+/// payment request has its own timeout.
+#[instrument(skip(client, recipients, comment), fields(recipients = recipients.len()))]
+pub async fn pay_split(
+    client: &NwcClient,
+    recipients: &[WeightedRecipient],
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<Vec<SplitShareOutcome>> {
+    let started_at = Timestamp::now().as_u64();
+    let amounts = split_amount(amount_sats, recipients)?;
+
+    let mut shares: Vec<SplitShareOutcome> = recipients
+        .iter()
+        .zip(&amounts)
+        .map(|(recipient, &amount_sats)| SplitShareOutcome {
+            address: recipient.address.clone(),
+            amount_sats,
+            outcome: None,
+            error: None,
+        })
+        .collect();
+
+    // Step 1: ask the wallet how it can pay, before any invoice is fetched
+    let deadline = payment_deadline(client);
+    let payable_shares = shares.iter().filter(|share| share.amount_sats > 0).count();
+    let supports_multi = payable_shares > 1
+        && within_deadline(client, deadline, client.get_wallet_info())
+            .await
+            .map(|info| info.methods.iter().any(|m| m == "multi_pay_invoice"))
+            .unwrap_or(false);
+
+    // Step 2: fetch an invoice for every non-empty share, each journaled on its own
+    let keys: Vec<String> = shares.iter().map(|_| attempt_key()).collect();
+    let mut fetched: Vec<(usize, FetchedInvoice)> = Vec::new();
+    for (index, share) in shares.iter_mut().enumerate() {
+        if share.amount_sats == 0 {
+            share.error = Some(ZapClockError::InvalidSplit {
+                message: "Share rounds down to 0 sats".to_string(),
+            });
+            continue;
+        }
+        let key = &keys[index];
+        journal::record(JournalEntry::new(key, JournalStage::Started).detail(&share.address)).await;
+        let fetch = fetch_lnurl_invoice(client, key, &share.address, share.amount_sats, comment.clone());
+        match within_deadline(client, deadline, fetch).await {
+            Ok(invoice) => fetched.push((index, invoice)),
+            Err(e) => share.error = Some(e),
+        }
+    }
+
+    // Step 3: pay all invoices at once, or one by one
+    let invoices: Vec<_> = fetched.iter().map(|(_, f)| f.invoice.clone()).collect();
+    let mut results: Option<Vec<Result<PayInvoiceResult>>> = None;
+    if supports_multi && fetched.len() > 1 {
+        let sent: Vec<_> = fetched
+            .iter()
+            .map(|(index, f)| (keys[*index].clone(), f.invoice.payment_hash.clone(), f.amount_msats))
+            .collect();
+        let published = Published::default();
+        let multi_client = client
+            .clone()
+            .with_progress(journal_publish(client.progress(), sent, published.clone()));
+        let paid = match multi_client.multi_pay_invoice(&invoices).await {
+            Ok(paid) => Some(paid),
+            // Rejected before anything was paid: safe to retry one by one
+            Err(ZapClockError::Wallet {
+                code: WalletErrorCode::NotImplemented,
+                ..
+            }) => {
+                warn!("⚠️ multi_pay_invoice not implemented, paying one by one");
+                None
+            }
+            Err(e) => Some(invoices.iter().map(|_| Err(e.clone())).collect()),
+        };
+        if let Some(paid) = &paid {
+            let published = published.settle().await;
+            for ((index, f), result) in fetched.iter().zip(paid) {
+                record_answer(&keys[*index], &f.invoice.payment_hash, result, published).await;
+            }
+        }
+        results = paid;
+    }
+    let results = match results {
+        Some(results) => results,
+        None => {
+            let mut paid = Vec::with_capacity(fetched.len());
+            for (index, f) in &fetched {
+                let request = client.prepare_pay_invoice(&f.invoice, None);
+                paid.push(match request {
+                    Ok(request) => {
+                        send_journaled(client, &keys[*index], request, &f.invoice.payment_hash, f.amount_msats)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                });
+            }
+            paid
+        }
+    };
+
+    for ((index, fetched), result) in fetched.into_iter().zip(results) {
+        let share = &mut shares[index];
+        match result {
+            Ok(paid) => {
+                share.outcome = Some(PaymentOutcome::from_fetched(
+                    fetched,
+                    paid,
+                    &share.address,
+                    started_at,
+                ))
+            }
+            Err(e) => share.error = Some(e),
+        }
+    }
+    for (key, share) in keys.iter().zip(&shares) {
+        if let (Some(e), false) = (&share.error, share.amount_sats == 0) {
+            record_failure(key, e).await;
+        }
+    }
+
+    Ok(shares)
+}
+
+/// Idempotency key of an alarm occurrence
+pub fn idempotency_key(alarm_id: i32, scheduled_at: u64) -> String {
+    format!("alarm_{}_{}", alarm_id, scheduled_at)
+}
+
+/// Whether a failed payment may still have gone out
+///
+/// Errors after publishing are `PaymentUnknown`, unless the wallet refused
+/// the request. A paid invoice whose preimage doesn't match counts as
+/// unknown too; any other error means nothing was sent.
+fn payment_state_unknown(error: &ZapClockError) -> bool {
+    matches!(
+        error,
+        ZapClockError::PaymentUnknown { .. } | ZapClockError::PreimageMismatch { .. }
+    )
+}
+
+/// Journal key of a payment made without an idempotency key
+fn attempt_key() -> String {
+    format!(
+        "payment_{}_{}",
+        Timestamp::now().as_u64(),
+        hex::encode(rand::random::<[u8; 4]>())
+    )
+}
+
+/// Run one payment attempt, journaling its start and a definite failure
+///
+/// The stages in between are journaled by the attempt itself.
+async fn journaled<T>(
+    key: &str,
+    recipient: &str,
+    attempt: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    journal::record(JournalEntry::new(key, JournalStage::Started).detail(recipient)).await;
+    let result = attempt.await;
+    if let Err(e) = &result {
+        record_failure(key, e).await;
+    }
+    result
+}
+
+/// When a payment started now has to be settled, per the client's policy
+fn payment_deadline(client: &NwcClient) -> Instant {
+    Instant::now() + client.policy().deadline()
+}
+
+/// Run (part of) a payment flow, giving up at `deadline`
+///
+/// One deadline covers resolving the address, fetching the invoice and
+/// the wallet's answer. Giving up after the request was committed leaves
+/// the payment's fate unknown.
+async fn within_deadline<T>(
+    client: &NwcClient,
+    deadline: Instant,
+    flow: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match tokio::time::timeout_at(deadline, flow).await {
+        Ok(result) => result,
+        Err(_) => {
+            let seconds = client.policy().deadline_secs;
+            warn!(deadline_secs = seconds, "⏱️ Payment gave up at the deadline");
+            let e = ZapClockError::DeadlineExceeded { seconds };
+            if client.cancel().is_committed() {
+                Err(ZapClockError::payment_unknown(e))
+            } else {
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Journal a failed attempt, unless the payment may still have gone out
+async fn record_failure(key: &str, error: &ZapClockError) {
+    if !payment_state_unknown(error) {
+        journal::record(JournalEntry::new(key, JournalStage::Failed).detail(error.to_string())).await;
+    }
+}
+
+/// Pay at most once per idempotency key
+///
+/// The invoice and signed NIP-47 request are stored before anything is
+/// published. Calling again with the same key returns the recorded
+/// outcome, or resumes the interrupted payment: the wallet's stored
+/// response and its invoice lookup are checked first, then the very same
+/// request event is re-sent (wallets ignore events they already handled).
+/// Concurrent calls with the same key run one after the other.
+#[instrument(skip_all, fields(payment_key = key, stage = Empty))]
+pub async fn pay_idempotent(
+    client: &NwcClient,
+    key: &str,
+    recipient: &PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<PaymentOutcome> {
+    let _in_flight = client
+        .cancel()
+        .run(async { Ok(lock_key(key).await) })
+        .await?;
+    // Resuming and starting over share one deadline
+    let deadline = payment_deadline(client);
+
+    if let Some(record) = store::load(key)? {
+        if let Some(outcome) = record.outcome {
+            info!("♻️ Already paid, returning recorded outcome");
+            return Ok(outcome);
+        }
+        if let Some(outcome) = within_deadline(client, deadline, resume(client, record)).await? {
+            return Ok(outcome);
+        }
+    }
+
+    let attempt = async {
+        let (mut record, request) = prepare(client, key, recipient, amount_sats, comment).await?;
+
+        // Persist before publishing: from here on the payment may go out
+        store::save(&mut record)?;
+        send_recorded(client, record, request, false).await
+    };
+    let attempt = within_deadline(client, deadline, client.cancel().run(attempt));
+    let result = journaled(key, &recipient_label(recipient), attempt).await;
+
+    // A cancel or the deadline only wins before publishing, so a record left behind was never sent
+    if matches!(result, Err(ZapClockError::Cancelled | ZapClockError::DeadlineExceeded { .. }))
+        && store::load(key)?.is_some_and(|record| record.outcome.is_none())
+    {
+        store::remove(key)?;
+    }
+    result
+}
+
+/// Wait until no other payment of `key` is in progress, and hold it off
+async fn lock_key(key: &str) -> OwnedMutexGuard<()> {
+    static KEYS: OnceLock<Mutex<HashMap<String, Arc<AsyncMutex<()>>>>> = OnceLock::new();
+    let lock = {
+        let mut keys = KEYS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        // Only the map holds idle locks
+        keys.retain(|_, lock| Arc::strong_count(lock) > 1);
+        keys.entry(key.to_string()).or_default().clone()
+    };
+    if lock.try_lock().is_err() {
+        info!(payment_key = key, "⏳ Waiting for the payment already in progress");
+    }
+    lock.lock_owned().await
+}
+
+/// Short recipient description for the journal and logs (never a full invoice)
+pub(crate) fn recipient_label(recipient: &PaymentRecipient) -> String {
+    match recipient {
+        PaymentRecipient::Lnurl { address } => address.clone(),
+        PaymentRecipient::Bolt11 { .. } => "BOLT11 invoice".to_string(),
+        PaymentRecipient::Keysend { pubkey, .. } => format!("keysend:{}", pubkey),
+    }
+}
+
+/// Get an invoice (if any) and sign the NIP-47 request for it
+async fn prepare(
+    client: &NwcClient,
+    key: &str,
+    recipient: &PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<(PaymentRecord, Event)> {
+    match recipient {
+        PaymentRecipient::Lnurl { address } => {
+            prepare_lnurl(client, key, address, amount_sats, comment).await
+        }
+        PaymentRecipient::Bolt11 { invoice } => prepare_bolt11(client, key, invoice, Some(amount_sats)).await,
+        PaymentRecipient::Keysend {
+            pubkey,
+            tlv_records,
+            preimage,
+        } => prepare_keysend(
+            client,
+            key,
+            pubkey,
+            amount_sats,
+            comment,
+            tlv_records.clone(),
+            preimage.clone(),
+        ),
+    }
+}
+
+/// Resolve a Lightning address and fetch an invoice for `amount_sats`
+async fn fetch_lnurl_invoice(
+    client: &NwcClient,
+    key: &str,
+    address: &str,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<FetchedInvoice> {
+    client.progress().emit(PaymentProgress::ResolvingAddress {
+        address: address.to_string(),
+    });
+    let lightning = LightningPayment::new();
+    let endpoint = lightning.resolve_pay_endpoint(address).await?;
+    journal::record(JournalEntry::new(key, JournalStage::AddressResolved)).await;
+    let fetched = lightning.fetch_invoice(&endpoint, amount_sats, comment).await?;
+    journal::record(
+        JournalEntry::new(key, JournalStage::InvoiceFetched)
+            .payment_hash(&fetched.invoice.payment_hash)
+            .amount_msats(fetched.amount_msats),
+    )
+    .await;
+    report_invoice(client, &fetched.invoice, fetched.amount_msats);
+    Ok(fetched)
+}
+
+/// Fetch an invoice from a Lightning address and sign the request for it
+async fn prepare_lnurl(
+    client: &NwcClient,
+    key: &str,
+    address: &str,
+    amount_sats: u64,
+    comment: Option<String>,
+) -> Result<(PaymentRecord, Event)> {
+    let started_at = Timestamp::now().as_u64();
+    let fetched = fetch_lnurl_invoice(client, key, address, amount_sats, comment).await?;
+    let request = client.prepare_pay_invoice(&fetched.invoice, None)?;
+    let record = PaymentRecord {
+        key: key.to_string(),
+        recipient: address.to_string(),
+        amount_msats: fetched.amount_msats,
+        comment: fetched.comment,
+        bolt11: Some(fetched.invoice.bolt11),
+        payment_hash: fetched.invoice.payment_hash,
+        expires_at: Some(fetched.invoice.expires_at),
+        success_action: fetched.success_action,
+        zap: fetched.zap_request.map(ZapInfo::from),
+        request_event: request.as_json(),
+        outcome: None,
+        started_at,
+        updated_at: started_at,
+    };
+    Ok((record, request))
+}
+
+/// Validate a BOLT11 invoice and sign the request for it
+async fn prepare_bolt11(
+    client: &NwcClient,
+    key: &str,
+    bolt11: &str,
+    amount_sats: Option<u64>,
+) -> Result<(PaymentRecord, Event)> {
+    let started_at = Timestamp::now().as_u64();
+    let (invoice, amount_msats) = validate_bolt11(bolt11, amount_sats)?;
+    journal::record(
+        JournalEntry::new(key, JournalStage::InvoiceFetched)
+            .payment_hash(&invoice.payment_hash)
+            .amount_msats(amount_msats),
+    )
+    .await;
+    report_invoice(client, &invoice, amount_msats);
+    let request = client.prepare_pay_invoice(&invoice, amount_param(&invoice, amount_msats))?;
+    let record = PaymentRecord {
+        key: key.to_string(),
+        recipient: invoice.payee,
+        amount_msats,
+        comment: None,
+        bolt11: Some(invoice.bolt11),
+        payment_hash: invoice.payment_hash,
+        expires_at: Some(invoice.expires_at),
+        success_action: None,
+        zap: None,
+        request_event: request.as_json(),
+        outcome: None,
+        started_at,
+        updated_at: started_at,
+    };
+    Ok((record, request))
+}
+
+/// Sign a keysend request, using the caller's preimage or a random one
+fn prepare_keysend(
+    client: &NwcClient,
+    key: &str,
+    pubkey: &str,
+    amount_sats: u64,
+    comment: Option<String>,
+    tlv_records: Vec<TlvRecord>,
+    preimage: Option<String>,
+) -> Result<(PaymentRecord, Event)> {
+    let started_at = Timestamp::now().as_u64();
+    let preimage = preimage.unwrap_or_else(nwc::keysend_preimage);
+    let payment_hash = nwc::keysend_payment_hash(&preimage)?;
+    let tlv_records = keysend_records(comment.as_deref(), tlv_records);
+    let amount_msats = amount_sats * 1000;
+    let request = client.prepare_pay_keysend(pubkey, amount_msats, &preimage, tlv_records)?;
+    let record = PaymentRecord {
+        key: key.to_string(),
+        recipient: pubkey.to_string(),
+        amount_msats,
+        comment,
+        bolt11: None,
+        payment_hash,
+        expires_at: None,
+        success_action: None,
+        zap: None,
+        request_event: request.as_json(),
+        outcome: None,
+        started_at,
+        updated_at: started_at,
+    };
+    Ok((record, request))
+}
+
+/// Resume a payment whose outcome wasn't recorded
+///
+/// Returns None if it is safe to start over with a new invoice, which
+/// needs the wallet to confirm an expired invoice was never paid. Until
+/// then the record is kept and this fails with `PaymentUnknown`. The
+/// client's cancel handle is only committed right before re-sending.
+async fn resume(client: &NwcClient, record: PaymentRecord) -> Result<Option<PaymentOutcome>> {
+    info!(payment_key = %record.key, "♻️ Resuming in-flight payment");
+    let request = Event::from_json(&record.request_event).map_err(|e| ZapClockError::Storage {
+        message: format!("Invalid stored request: {}", e),
+    })?;
+
+    // 1. The wallet may have answered while the app was gone
+    if let Some(paid) = client
+        .find_payment_response(request.id, &record.payment_hash)
+        .await?
+    {
+        journal::record(JournalEntry::new(&record.key, JournalStage::ResponseReceived).detail("found after restart")).await;
+        journal::record(JournalEntry::new(&record.key, JournalStage::PreimageVerified).payment_hash(&record.payment_hash)).await;
+        return finish(record, paid).map(Some);
+    }
+
+    // 2. The wallet's own transaction history
+    let lookup = client
+        .lookup_invoice(InvoiceLookup::PaymentHash(record.payment_hash.clone()))
+        .await;
+    let confirmed_unpaid = match lookup {
+        Ok(transaction) if transaction.settled_at.is_some() => {
+            if let Some(preimage) = transaction
+                .preimage
+                .filter(|preimage| invoice::verify_preimage(&record.payment_hash, preimage).is_ok())
+            {
+                let paid = PayInvoiceResult {
+                    preimage,
+                    fees_paid: Some(transaction.fees_paid),
+                };
+                journal::record(
+                    JournalEntry::new(&record.key, JournalStage::PreimageVerified)
+                        .payment_hash(&record.payment_hash)
+                        .detail("found in wallet history"),
+                )
+                .await;
+                return finish(record, paid).map(Some);
+            }
+            false
+        }
+        Ok(_) => true,
+        // Many wallets can't look up outgoing payments; that proves nothing
+        Err(e) => {
+            warn!(error = %e, "⚠️ Invoice lookup inconclusive");
+            false
+        }
+    };
+
+    // 3. An expired invoice can't be paid anymore: start over only if it provably wasn't
+    if record
+        .expires_at
+        .is_some_and(|expires_at| Timestamp::now().as_u64() >= expires_at)
+    {
+        if !confirmed_unpaid {
+            warn!("⚠️ Stored invoice expired, but the wallet can't tell whether it was paid");
+            return Err(ZapClockError::payment_unknown(
+                "invoice expired and the wallet could not confirm it was unpaid",
+            ));
+        }
+        warn!("⚠️ Stored invoice expired unpaid, starting over");
+        journal::record(JournalEntry::new(&record.key, JournalStage::Failed).detail("invoice expired unpaid")).await;
+        store::remove(&record.key)?;
+        return Ok(None);
+    }
+
+    // 4. Re-send the same signed request: past the point of no return
+    client.cancel().commit()?;
+    send_recorded(client, record, request, true).await.map(Some)
+}
+
+/// Publish a prepared request that isn't stored, and return the receipt
+async fn send_prepared(
+    client: &NwcClient,
+    mut record: PaymentRecord,
+    request: Event,
+) -> Result<PaymentOutcome> {
+    let paid = send_journaled(client, &record.key, request, &record.payment_hash, record.amount_msats).await?;
+    Ok(record.complete(paid))
+}
+
+/// Publish a stored request and record the result
+///
+/// A `resumed` request may have gone out on an earlier attempt, so only
+/// the wallet refusing it proves nothing was paid.
+async fn send_recorded(
+    client: &NwcClient,
+    record: PaymentRecord,
+    request: Event,
+    resumed: bool,
+) -> Result<PaymentOutcome> {
+    let sent = send_journaled(client, &record.key, request, &record.payment_hash, record.amount_msats).await;
+    match sent {
+        Ok(paid) => finish(record, paid),
+        Err(e) => {
+            let unpaid = match e {
+                ZapClockError::Wallet { .. } => true,
+                _ => !resumed && !payment_state_unknown(&e),
+            };
+            if !unpaid {
+                warn!(payment_key = %record.key, error = %e, "⚠️ Outcome unknown, keeping record for resume");
+                return Err(e);
+            }
+            // Fresh attempts are journaled by `journaled`
+            if resumed {
+                record_failure(&record.key, &e).await;
+            }
+            store::remove(&record.key)?;
+            Err(e)
+        }
+    }
+}
+
+/// Publish a signed payment request, journaling when it went out and the answer
+async fn send_journaled(
+    client: &NwcClient,
+    key: &str,
+    request: Event,
+    payment_hash: &str,
+    amount_msats: u64,
+) -> Result<PayInvoiceResult> {
+    let published = Published::default();
+    let sent = vec![(key.to_string(), payment_hash.to_string(), amount_msats)];
+    let client = client
+        .clone()
+        .with_progress(journal_publish(client.progress(), sent, published.clone()));
+    let result = client.send_payment(request, payment_hash).await;
+    record_answer(key, payment_hash, &result, published.settle().await).await;
+    result
+}
+
+/// `RequestSent` entries being journaled for a published request
+#[derive(Clone, Default)]
+struct Published(Arc<Mutex<Vec<JoinHandle<()>>>>);
+
+impl Published {
+    /// Wait for the entries to be written; returns whether the request was published
+    async fn settle(&self) -> bool {
+        let pending = std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()));
+        let published = !pending.is_empty();
+        for journaling in pending {
+            let _ = journaling.await;
+        }
+        published
+    }
+}
+
+/// Forward progress to `progress`, journaling `RequestSent` for every
+/// `(key, payment_hash, amount_msats)` once the relay took the request
+///
+/// Progress is reported synchronously, so the entries are written by a
+/// spawned task that `published` waits for.
+fn journal_publish(
+    progress: &ProgressReporter,
+    sent: Vec<(String, String, u64)>,
+    published: Published,
+) -> ProgressReporter {
+    let progress = progress.clone();
+    ProgressReporter::new(move |update| {
+        if let PaymentProgress::RequestPublished { request_id } = &update {
+            let sent = sent.clone();
+            let request_id = request_id.clone();
+            let journaling = tokio::spawn(async move {
+                for (key, payment_hash, amount_msats) in sent {
+                    journal::record(
+                        JournalEntry::new(&key, JournalStage::RequestSent)
+                            .payment_hash(&payment_hash)
+                            .amount_msats(amount_msats)
+                            .detail(request_id.clone()),
+                    )
+                    .await;
+                }
+            });
+            published.0.lock().unwrap_or_else(|e| e.into_inner()).push(journaling);
+        }
+        progress.emit(update);
+    })
+}
+
+/// Journal the wallet's answer to a payment request
+async fn record_answer(key: &str, payment_hash: &str, result: &Result<PayInvoiceResult>, published: bool) {
+    match result {
+        Ok(_) => {
+            journal::record(JournalEntry::new(key, JournalStage::ResponseReceived)).await;
+            journal::record(JournalEntry::new(key, JournalStage::PreimageVerified).payment_hash(payment_hash)).await;
+        }
+        Err(e @ (ZapClockError::Wallet { .. } | ZapClockError::PreimageMismatch { .. })) => {
+            journal::record(JournalEntry::new(key, JournalStage::ResponseReceived).detail(e.to_string())).await;
+        }
+        // The relay may have taken the request without confirming it
+        Err(e @ ZapClockError::PaymentUnknown { .. }) if !published => {
+            journal::record(
+                JournalEntry::new(key, JournalStage::RequestSent)
+                    .payment_hash(payment_hash)
+                    .detail(e.to_string()),
+            )
+            .await;
+        }
+        Err(_) => {}
+    }
+}
+
+/// Record a confirmed payment and return its receipt
+fn finish(mut record: PaymentRecord, paid: PayInvoiceResult) -> Result<PaymentOutcome> {
+    let outcome = record.complete(paid);
+    store::save(&mut record)?;
+    Ok(outcome)
+}
+
+/// Look for the zap receipt of a completed payment
+///
+/// Kept separate from `pay_to_address` so waiting for relays never delays
+/// stopping the alarm. Payments that weren't zaps are returned unchanged.
+pub async fn confirm_zap_receipt(mut outcome: PaymentOutcome, timeout_secs: u64) -> Result<PaymentOutcome> {
+    if let (Some(zap), Some(bolt11)) = (&mut outcome.zap, &outcome.bolt11) {
+        zap.receipt = zap.wait_for_receipt(bolt11, timeout_secs).await?;
+    }
+    Ok(outcome)
+}
+
+/// Store the receipt status of `outcome` in the completed record of `key`
+pub fn record_zap_receipt(key: &str, outcome: &PaymentOutcome) -> Result<()> {
+    let Some(mut record) = store::load(key)? else {
+        return Ok(());
+    };
+    if let Some(recorded) = &mut record.outcome {
+        recorded.zap.clone_from(&outcome.zap);
+        record.zap.clone_from(&outcome.zap);
+        store::save(&mut record)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn lock_key_serializes_payments_of_the_same_key() {
+        let first = lock_key("alarm_1_100").await;
+        let waiting = tokio::spawn(async { lock_key("alarm_1_100").await });
+
+        // Another key is not held up
+        let _other = tokio::time::timeout(Duration::from_secs(1), lock_key("alarm_2_100"))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiting.is_finished());
+
+        drop(first);
+        tokio::time::timeout(Duration::from_secs(1), waiting)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    fn recipients(weights: &[u32]) -> Vec<WeightedRecipient> {
+        weights
+            .iter()
+            .enumerate()
+            .map(|(i, &weight)| WeightedRecipient {
+                address: format!("user{}@example.com", i),
+                weight,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_amount_follows_the_weights() {
+        assert_eq!(split_amount(1000, &recipients(&[50, 30, 20])).unwrap(), [500, 300, 200]);
+        assert_eq!(split_amount(21, &recipients(&[1])).unwrap(), [21]);
+        // Weights are relative
+        assert_eq!(split_amount(100, &recipients(&[5, 3, 2])).unwrap(), [50, 30, 20]);
+    }
+
+    #[test]
+    fn split_amount_hands_leftovers_to_the_largest_remainders() {
+        // 33.3 / 33.3 / 33.3: the first share wins the tie
+        assert_eq!(split_amount(100, &recipients(&[1, 1, 1])).unwrap(), [34, 33, 33]);
+        // 50.5 / 30.3 / 20.2
+        assert_eq!(split_amount(101, &recipients(&[50, 30, 20])).unwrap(), [51, 30, 20]);
+        // 5.7 / 2.8 / 1.5: the two leftover sats go to .8 and .7
+        assert_eq!(split_amount(10, &recipients(&[57, 28, 15])).unwrap(), [6, 3, 1]);
+
+        for total in [0, 1, 7, 999, 1_000_003] {
+            let shares = split_amount(total, &recipients(&[7, 13, 0, 29])).unwrap();
+            assert_eq!(shares.iter().sum::<u64>(), total);
+            assert_eq!(shares[2], 0);
+        }
+    }
+
+    #[test]
+    fn split_amount_below_the_number_of_recipients() {
+        assert_eq!(split_amount(2, &recipients(&[1, 1, 1])).unwrap(), [1, 1, 0]);
+        assert_eq!(split_amount(0, &recipients(&[1, 1])).unwrap(), [0, 0]);
+    }
+
+    #[test]
+    fn split_amount_needs_a_positive_weight() {
+        for weights in [&[][..], &[0], &[0, 0]] {
+            assert!(matches!(
+                split_amount(100, &recipients(weights)),
+                Err(ZapClockError::InvalidSplit { .. })
+            ));
+        }
+    }
+}
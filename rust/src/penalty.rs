@@ -0,0 +1,701 @@
+//! Penalty session state machine
+//!
+//! Ringing → CountingDown → Dismissed, or → Paying → Paid / Failed /
+//! Unknown once the countdown runs out. A payment cancelled before its
+//! request is published ends Dismissed as well. Sessions are persisted in the
+//! payment store so a countdown or payment survives a process restart.
+
+use crate::cancel::{CancelOutcome, PaymentCancel};
+use crate::error::{Result, ZapClockError};
+use crate::nwc::NwcClient;
+use crate::payment::{self, PaymentOutcome, PaymentRecipient};
+use crate::store;
+use nostr_sdk::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::Instant;
+use tracing::{info, instrument, warn};
+
+/// File holding the penalty sessions, inside the store directory
+const PENALTY_FILE_NAME: &str = "penalties.json";
+
+/// Where a penalty session stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PenaltyState {
+    /// Alarm is ringing, countdown not started yet
+    Ringing,
+    /// Countdown running; dismissing now avoids the penalty
+    CountingDown,
+    /// Alarm stopped in time, nothing is paid
+    Dismissed,
+    /// Countdown ran out, payment in progress
+    Paying,
+    Paid,
+    /// Payment definitely did not go out
+    Failed,
+    /// Payment may or may not have gone out; paying again resumes it
+    Unknown,
+}
+
+impl fmt::Display for PenaltyState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self {
+            Self::Ringing => "ringing",
+            Self::CountingDown => "counting down",
+            Self::Dismissed => "dismissed",
+            Self::Paying => "paying",
+            Self::Paid => "paid",
+            Self::Failed => "failed",
+            Self::Unknown => "in an unknown state",
+        };
+        write!(f, "{}", state)
+    }
+}
+
+/// Penalty of one alarm occurrence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PenaltySession {
+    pub alarm_id: i32,
+    /// Unix time the alarm was scheduled to fire
+    pub scheduled_at: u64,
+    pub state: PenaltyState,
+    pub recipient: PaymentRecipient,
+    pub amount_sats: u64,
+    pub comment: Option<String>,
+    /// Seconds the user has to stop the alarm before paying
+    pub timeout_secs: u64,
+    pub ringing_since: u64,
+    pub countdown_started_at: Option<u64>,
+    /// Receipt once paid
+    pub outcome: Option<PaymentOutcome>,
+    /// Error of the last payment attempt
+    pub error: Option<String>,
+    pub updated_at: u64,
+}
+
+impl PenaltySession {
+    /// New session for an alarm that just started ringing
+    pub fn new(
+        alarm_id: i32,
+        scheduled_at: u64,
+        recipient: PaymentRecipient,
+        amount_sats: u64,
+        comment: Option<String>,
+        timeout_secs: u64,
+        now: u64,
+    ) -> Self {
+        Self {
+            alarm_id,
+            scheduled_at,
+            state: PenaltyState::Ringing,
+            recipient,
+            amount_sats,
+            comment,
+            timeout_secs,
+            ringing_since: now,
+            countdown_started_at: None,
+            outcome: None,
+            error: None,
+            updated_at: now,
+        }
+    }
+
+    /// Idempotency key of the penalty payment
+    pub fn key(&self) -> String {
+        payment::idempotency_key(self.alarm_id, self.scheduled_at)
+    }
+
+    /// Ringing → CountingDown
+    pub fn start_countdown(&mut self, now: u64) -> Result<()> {
+        self.expect(&[PenaltyState::Ringing], "start the countdown of")?;
+        self.countdown_started_at = Some(now);
+        self.transition(PenaltyState::CountingDown, now);
+        Ok(())
+    }
+
+    /// Seconds left before the penalty is due (0 once due or finished)
+    pub fn remaining_secs(&self, now: u64) -> u64 {
+        match (self.state, self.countdown_started_at) {
+            (PenaltyState::Ringing, _) => self.timeout_secs,
+            (PenaltyState::CountingDown, Some(started_at)) => {
+                (started_at + self.timeout_secs).saturating_sub(now)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Whether the countdown has run out
+    pub fn is_due(&self, now: u64) -> bool {
+        self.state == PenaltyState::CountingDown && self.remaining_secs(now) == 0
+    }
+
+    /// Ringing / CountingDown → Dismissed, while time is left
+    ///
+    /// Dismissing an already dismissed session is a no-op.
+    pub fn dismiss(&mut self, now: u64) -> Result<()> {
+        if self.state == PenaltyState::Dismissed {
+            return Ok(());
+        }
+        self.expect(&[PenaltyState::Ringing, PenaltyState::CountingDown], "dismiss")?;
+        if self.is_due(now) {
+            return Err(self.invalid("dismiss"));
+        }
+        self.transition(PenaltyState::Dismissed, now);
+        Ok(())
+    }
+
+    /// CountingDown (due) → Paying, or retry Paying / Unknown after a restart
+    pub fn begin_payment(&mut self, now: u64) -> Result<()> {
+        self.expect(
+            &[PenaltyState::CountingDown, PenaltyState::Paying, PenaltyState::Unknown],
+            "pay",
+        )?;
+        if self.state == PenaltyState::CountingDown && !self.is_due(now) {
+            return Err(self.invalid("pay"));
+        }
+        self.error = None;
+        self.transition(PenaltyState::Paying, now);
+        Ok(())
+    }
+
+    /// Paying → Dismissed, once the payment was cancelled before publishing
+    pub fn cancel_payment(&mut self, now: u64) -> Result<()> {
+        self.expect(&[PenaltyState::Paying], "cancel the payment of")?;
+        self.transition(PenaltyState::Dismissed, now);
+        Ok(())
+    }
+
+    /// Paying → Paid / Failed / Unknown (or Dismissed if cancelled)
+    ///
+    /// `may_have_gone_out` tells a failure whose request may have been
+    /// published (Unknown) from one where nothing was paid (Failed).
+    pub fn finish_payment(
+        &mut self,
+        result: &Result<PaymentOutcome>,
+        may_have_gone_out: bool,
+        now: u64,
+    ) -> Result<()> {
+        self.expect(&[PenaltyState::Paying], "finish the payment of")?;
+        let state = match result {
+            Ok(outcome) => {
+                self.outcome = Some(outcome.clone());
+                PenaltyState::Paid
+            }
+            Err(ZapClockError::Cancelled) => PenaltyState::Dismissed,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                if may_have_gone_out {
+                    PenaltyState::Unknown
+                } else {
+                    PenaltyState::Failed
+                }
+            }
+        };
+        self.transition(state, now);
+        Ok(())
+    }
+
+    /// Whether no further transition is possible
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.state,
+            PenaltyState::Dismissed | PenaltyState::Paid | PenaltyState::Failed
+        )
+    }
+
+    fn expect(&self, allowed: &[PenaltyState], action: &str) -> Result<()> {
+        if allowed.contains(&self.state) {
+            Ok(())
+        } else {
+            Err(self.invalid(action))
+        }
+    }
+
+    fn invalid(&self, action: &str) -> ZapClockError {
+        ZapClockError::InvalidPenaltyTransition {
+            state: self.state.to_string(),
+            action: action.to_string(),
+        }
+    }
+
+    fn transition(&mut self, state: PenaltyState, now: u64) {
+        info!(alarm_id = self.alarm_id, from = %self.state, to = %state, "⏱️ Penalty state changed");
+        self.state = state;
+        self.updated_at = now;
+    }
+}
+
+/// Start the penalty of a ringing alarm
+///
+/// Starting the same alarm occurrence again (e.g. after a restart) returns
+/// the existing session instead of restarting its countdown.
+pub fn start(
+    alarm_id: i32,
+    scheduled_at: u64,
+    recipient: PaymentRecipient,
+    amount_sats: u64,
+    comment: Option<String>,
+    timeout_secs: u64,
+) -> Result<PenaltySession> {
+    if let Some(session) = load(alarm_id)? {
+        if session.scheduled_at == scheduled_at {
+            return Ok(session);
+        }
+        if !session.is_finished() {
+            warn!(alarm_id, state = %session.state, "⚠️ Replacing unfinished penalty session");
+        }
+    }
+
+    let now = Timestamp::now().as_u64();
+    let mut session = PenaltySession::new(
+        alarm_id,
+        scheduled_at,
+        recipient,
+        amount_sats,
+        comment,
+        timeout_secs,
+        now,
+    );
+    session.start_countdown(now)?;
+    save(&session)?;
+    Ok(session)
+}
+
+/// Stop the countdown of an alarm (None if it has no penalty)
+///
+/// A penalty already being paid is cancelled if its request hasn't been
+/// published yet; otherwise this fails with `PaymentCommitted`.
+pub fn dismiss(alarm_id: i32) -> Result<Option<PenaltySession>> {
+    let Some(mut session) = load(alarm_id)? else {
+        return Ok(None);
+    };
+    let now = Timestamp::now().as_u64();
+    let in_flight = in_flight().get(&alarm_id).cloned();
+    match (session.state, in_flight) {
+        (PenaltyState::Paying, Some(cancel)) => match cancel.cancel() {
+            CancelOutcome::Cancelled | CancelOutcome::AlreadyCancelled => {
+                session.cancel_payment(now)?
+            }
+            CancelOutcome::TooLate => return Err(ZapClockError::PaymentCommitted),
+        },
+        _ => session.dismiss(now)?,
+    }
+    save(&session)?;
+    Ok(Some(session))
+}
+
+/// Seconds left before the penalty of an alarm is due (None if no penalty)
+pub fn remaining_secs(alarm_id: i32) -> Result<Option<u64>> {
+    Ok(load(alarm_id)?.map(|session| session.remaining_secs(Timestamp::now().as_u64())))
+}
+
+/// Pay a penalty whose countdown ran out (or resume its payment)
+///
+/// A failed payment is reported through the session state, not as an error.
+pub async fn pay_due(client: &NwcClient, alarm_id: i32) -> Result<PenaltySession> {
+    pay_due_with_result(client, alarm_id)
+        .await
+        .map(|(session, _)| session)
+}
+
+/// Like [`pay_due`], also returning the result of the payment attempt
+///
+/// Fails with `InvalidPenaltyTransition` while the alarm's penalty is
+/// already being paid in this process.
+#[instrument(skip(client))]
+pub async fn pay_due_with_result(
+    client: &NwcClient,
+    alarm_id: i32,
+) -> Result<(PenaltySession, Result<PaymentOutcome>)> {
+    let mut session = load(alarm_id)?.ok_or(ZapClockError::PenaltyNotFound { alarm_id })?;
+    {
+        let mut in_flight = in_flight();
+        // Paying again would take over the running payment's cancel handle
+        if in_flight.contains_key(&alarm_id) {
+            return Err(ZapClockError::InvalidPenaltyTransition {
+                state: "already being paid".to_string(),
+                action: "pay".to_string(),
+            });
+        }
+        session.begin_payment(Timestamp::now().as_u64())?;
+        // Registered before the Paying state is visible, so a dismiss can always cancel it
+        in_flight.insert(alarm_id, client.cancel().clone());
+    }
+    save(&session).inspect_err(|_| {
+        in_flight().remove(&alarm_id);
+    })?;
+
+    let key = session.key();
+    let started = Instant::now();
+    let result = payment::pay_idempotent(
+        client,
+        &key,
+        &session.recipient,
+        session.amount_sats,
+        session.comment.clone(),
+    )
+    .await;
+    in_flight().remove(&alarm_id);
+    // A record is only kept while the payment's fate is unknown
+    let may_have_gone_out = result.is_err() && store::load(&key)?.is_some();
+    session.finish_payment(&result, may_have_gone_out, Timestamp::now().as_u64())?;
+    save(&session)?;
+    info!(
+        state = %session.state,
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "💸 Penalty payment attempt finished"
+    );
+    Ok((session, result))
+}
+
+/// Wait for the zap receipt of a paid penalty and store it
+///
+/// The receipt status is saved in the session and in the payment record.
+/// Returns the session (None if the alarm has no penalty); sessions not
+/// paid with a zap are returned unchanged.
+pub async fn confirm_zap_receipt(alarm_id: i32, timeout_secs: u64) -> Result<Option<PenaltySession>> {
+    let Some(session) = load(alarm_id)? else {
+        return Ok(None);
+    };
+    let Some(outcome) = session.outcome.clone().filter(|outcome| outcome.zap.is_some()) else {
+        return Ok(Some(session));
+    };
+
+    let outcome = payment::confirm_zap_receipt(outcome, timeout_secs).await?;
+    payment::record_zap_receipt(&session.key(), &outcome)?;
+
+    // Reloaded: the alarm may have rung again while waiting for relays
+    let Some(mut latest) = load(alarm_id)? else {
+        return Ok(None);
+    };
+    if latest.scheduled_at == session.scheduled_at {
+        latest.outcome = Some(outcome);
+        latest.updated_at = Timestamp::now().as_u64();
+        save(&latest)?;
+    }
+    Ok(Some(latest))
+}
+
+/// Pick up unfinished sessions after a process restart
+///
+/// Penalties that came due while the app was gone, and payments that were
+/// interrupted, are paid (resumed) now. Returns every unfinished session,
+/// including countdowns that are still running. A session that can't be
+/// resumed keeps its state, with the reason in `error`; the others are
+/// resumed regardless.
+pub async fn resume(client: &NwcClient) -> Result<Vec<PenaltySession>> {
+    let now = Timestamp::now().as_u64();
+    let mut sessions = Vec::new();
+    for session in all()? {
+        if session.is_finished() {
+            continue;
+        }
+        let payable = session.is_due(now)
+            || matches!(session.state, PenaltyState::Paying | PenaltyState::Unknown);
+        if !payable {
+            sessions.push(session);
+            continue;
+        }
+
+        info!(alarm_id = session.alarm_id, state = %session.state, "♻️ Resuming penalty");
+        match pay_due(client, session.alarm_id).await {
+            Ok(session) => sessions.push(session),
+            Err(e) => {
+                warn!(alarm_id = session.alarm_id, error = %e, "⚠️ Penalty could not be resumed");
+                let mut session = load(session.alarm_id).ok().flatten().unwrap_or(session);
+                session.error = Some(e.to_string());
+                session.updated_at = Timestamp::now().as_u64();
+                if let Err(e) = save(&session) {
+                    warn!(alarm_id = session.alarm_id, error = %e, "⚠️ Could not record resume error");
+                }
+                sessions.push(session);
+            }
+        }
+    }
+    Ok(sessions)
+}
+
+/// Cancellation handles of the penalties being paid, by alarm id
+fn in_flight() -> MutexGuard<'static, HashMap<i32, PaymentCancel>> {
+    static IN_FLIGHT: OnceLock<Mutex<HashMap<i32, PaymentCancel>>> = OnceLock::new();
+    IN_FLIGHT
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Session of an alarm, if any
+pub fn load(alarm_id: i32) -> Result<Option<PenaltySession>> {
+    let _guard = store::lock();
+    Ok(store::read_map::<PenaltySession>(PENALTY_FILE_NAME)?.remove(&alarm_id.to_string()))
+}
+
+/// All stored sessions
+pub fn all() -> Result<Vec<PenaltySession>> {
+    let _guard = store::lock();
+    Ok(store::read_map(PENALTY_FILE_NAME)?.into_values().collect())
+}
+
+fn save(session: &PenaltySession) -> Result<()> {
+    let _guard = store::lock();
+    let mut sessions = store::read_map(PENALTY_FILE_NAME)?;
+    sessions.insert(session.alarm_id.to_string(), session.clone());
+    store::write_map(PENALTY_FILE_NAME, &sessions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nwc::NwcSession;
+
+    const TIMEOUT_SECS: u64 = 60;
+    const ALL_STATES: [PenaltyState; 7] = [
+        PenaltyState::Ringing,
+        PenaltyState::CountingDown,
+        PenaltyState::Dismissed,
+        PenaltyState::Paying,
+        PenaltyState::Paid,
+        PenaltyState::Failed,
+        PenaltyState::Unknown,
+    ];
+
+    fn recipient() -> PaymentRecipient {
+        PaymentRecipient::Lnurl {
+            address: "penalty@example.com".to_string(),
+        }
+    }
+
+    /// Session in `state`, with a countdown started at t=100 if it has one
+    fn session(state: PenaltyState) -> PenaltySession {
+        let mut session = PenaltySession::new(1, 100, recipient(), 21, None, TIMEOUT_SECS, 100);
+        session.state = state;
+        if state != PenaltyState::Ringing {
+            session.countdown_started_at = Some(100);
+        }
+        session
+    }
+
+    fn outcome() -> PaymentOutcome {
+        PaymentOutcome {
+            bolt11: None,
+            payment_hash: "00".repeat(32),
+            preimage: "11".repeat(32),
+            amount_msats: 21_000,
+            fees_paid_msats: None,
+            recipient: "penalty@example.com".to_string(),
+            comment: None,
+            success_action: None,
+            zap: None,
+            started_at: 160,
+            paid_at: 161,
+        }
+    }
+
+    fn assert_rejected(result: Result<()>) {
+        assert!(
+            matches!(result, Err(ZapClockError::InvalidPenaltyTransition { .. })),
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn start_countdown_only_while_ringing() {
+        for state in ALL_STATES {
+            let mut session = session(state);
+            let result = session.start_countdown(110);
+            if state == PenaltyState::Ringing {
+                result.unwrap();
+                assert_eq!(session.state, PenaltyState::CountingDown);
+                assert_eq!(session.countdown_started_at, Some(110));
+            } else {
+                assert_rejected(result);
+                assert_eq!(session.state, state);
+            }
+        }
+    }
+
+    #[test]
+    fn dismiss_while_time_is_left() {
+        for state in ALL_STATES {
+            let mut session = session(state);
+            let result = session.dismiss(130);
+            match state {
+                PenaltyState::Ringing | PenaltyState::CountingDown | PenaltyState::Dismissed => {
+                    result.unwrap();
+                    assert_eq!(session.state, PenaltyState::Dismissed);
+                }
+                _ => {
+                    assert_rejected(result);
+                    assert_eq!(session.state, state);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dismiss_fails_once_due() {
+        let mut session = session(PenaltyState::CountingDown);
+        assert_rejected(session.dismiss(100 + TIMEOUT_SECS));
+        assert_eq!(session.state, PenaltyState::CountingDown);
+        assert!(session.is_due(100 + TIMEOUT_SECS));
+    }
+
+    #[test]
+    fn begin_payment_once_due_or_to_resume() {
+        for state in ALL_STATES {
+            let mut session = session(state);
+            let result = session.begin_payment(100 + TIMEOUT_SECS);
+            match state {
+                PenaltyState::CountingDown | PenaltyState::Paying | PenaltyState::Unknown => {
+                    result.unwrap();
+                    assert_eq!(session.state, PenaltyState::Paying);
+                }
+                _ => {
+                    assert_rejected(result);
+                    assert_eq!(session.state, state);
+                }
+            }
+        }
+
+        let mut session = session(PenaltyState::CountingDown);
+        assert_rejected(session.begin_payment(100 + TIMEOUT_SECS - 1));
+        assert_eq!(session.state, PenaltyState::CountingDown);
+    }
+
+    #[test]
+    fn begin_payment_clears_the_previous_error() {
+        let mut session = session(PenaltyState::Unknown);
+        session.error = Some("relay closed".to_string());
+        session.begin_payment(200).unwrap();
+        assert_eq!(session.error, None);
+    }
+
+    #[test]
+    fn cancel_payment_only_while_paying() {
+        for state in ALL_STATES {
+            let mut session = session(state);
+            let result = session.cancel_payment(170);
+            if state == PenaltyState::Paying {
+                result.unwrap();
+                assert_eq!(session.state, PenaltyState::Dismissed);
+            } else {
+                assert_rejected(result);
+                assert_eq!(session.state, state);
+            }
+        }
+    }
+
+    #[test]
+    fn finish_payment_only_while_paying() {
+        for state in ALL_STATES {
+            let mut session = session(state);
+            let result = session.finish_payment(&Ok(outcome()), false, 170);
+            if state == PenaltyState::Paying {
+                result.unwrap();
+                assert_eq!(session.state, PenaltyState::Paid);
+                assert!(session.outcome.is_some());
+            } else {
+                assert_rejected(result);
+                assert_eq!(session.state, state);
+            }
+        }
+    }
+
+    #[test]
+    fn finish_payment_tells_failed_from_unknown() {
+        let cases = [
+            (Err(ZapClockError::Cancelled), true, PenaltyState::Dismissed),
+            (Err(ZapClockError::Cancelled), false, PenaltyState::Dismissed),
+            (Err(ZapClockError::payment_unknown("timed out")), true, PenaltyState::Unknown),
+            (Err(ZapClockError::PaymentCommitted), false, PenaltyState::Failed),
+        ];
+        for (result, may_have_gone_out, expected) in cases {
+            let mut session = session(PenaltyState::Paying);
+            session.finish_payment(&result, may_have_gone_out, 170).unwrap();
+            assert_eq!(session.state, expected);
+            assert_eq!(session.error.is_some(), expected != PenaltyState::Dismissed);
+            assert!(session.outcome.is_none());
+        }
+    }
+
+    #[test]
+    fn remaining_secs_saturates_at_zero() {
+        assert_eq!(session(PenaltyState::Ringing).remaining_secs(1_000), TIMEOUT_SECS);
+
+        let counting_down = session(PenaltyState::CountingDown);
+        assert_eq!(counting_down.remaining_secs(100), TIMEOUT_SECS);
+        assert_eq!(counting_down.remaining_secs(130), 30);
+        assert_eq!(counting_down.remaining_secs(100 + TIMEOUT_SECS), 0);
+        assert_eq!(counting_down.remaining_secs(u64::MAX), 0);
+
+        for state in [
+            PenaltyState::Dismissed,
+            PenaltyState::Paying,
+            PenaltyState::Paid,
+            PenaltyState::Failed,
+            PenaltyState::Unknown,
+        ] {
+            assert_eq!(session(state).remaining_secs(130), 0);
+        }
+    }
+
+    /// Store directory shared by the tests of this process
+    fn use_test_store() {
+        let directory = std::env::temp_dir().join(format!("zapclock-penalty-{}", std::process::id()));
+        store::set_directory(directory.to_str().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn start_returns_the_session_of_the_same_occurrence() {
+        use_test_store();
+
+        let first = start(7, 1_000, recipient(), 21, None, TIMEOUT_SECS).unwrap();
+        assert_eq!(first.state, PenaltyState::CountingDown);
+
+        // Different settings don't restart the countdown of the same occurrence
+        let again = start(7, 1_000, recipient(), 500, None, 5).unwrap();
+        assert_eq!(again.amount_sats, 21);
+        assert_eq!(again.timeout_secs, TIMEOUT_SECS);
+        assert_eq!(again.countdown_started_at, first.countdown_started_at);
+
+        // The next occurrence replaces it
+        let next = start(7, 2_000, recipient(), 500, None, 5).unwrap();
+        assert_eq!(next.scheduled_at, 2_000);
+        assert_eq!(load(7).unwrap().unwrap().amount_sats, 500);
+    }
+
+    #[tokio::test]
+    async fn resume_goes_on_after_a_failing_session() {
+        use_test_store();
+        let due = |alarm_id, recipient| {
+            let mut session = PenaltySession::new(alarm_id, 100, recipient, 21, None, TIMEOUT_SECS, 100);
+            session.start_countdown(100).unwrap();
+            session
+        };
+        // Can't be paid: already being paid in this process
+        save(&due(20, recipient())).unwrap();
+        in_flight().insert(20, PaymentCancel::new());
+        // Paid (and failing) without any network access
+        let bad_invoice = PaymentRecipient::Bolt11 {
+            invoice: "lnbc1invalid".to_string(),
+        };
+        save(&due(21, bad_invoice)).unwrap();
+
+        let session = NwcSession::new(
+            "nostr+walletconnect://b889ff5b1513b641e2a139f661a661364979c5beee91842f8f0ef42ab558e9d4?relay=wss%3A%2F%2Frelay.example.com&secret=71a8c14c1407c113601079c4302dab36460f0ccd0ad506f1f2dc73b5100e4f3c",
+        )
+        .unwrap();
+        let sessions = resume(&NwcClient::with_session(session)).await.unwrap();
+        in_flight().remove(&20);
+
+        let find = |alarm_id| sessions.iter().find(|s| s.alarm_id == alarm_id).unwrap();
+        assert_eq!(find(20).state, PenaltyState::CountingDown);
+        assert!(find(20).error.as_deref().unwrap().contains("already being paid"));
+        assert_eq!(find(21).state, PenaltyState::Failed);
+        assert_eq!(load(20).unwrap().unwrap().error, find(20).error);
+        assert_eq!(load(21).unwrap().unwrap().state, PenaltyState::Failed);
+    }
+}
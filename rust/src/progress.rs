@@ -0,0 +1,53 @@
+//! Progress events of an in-flight payment
+
+use crate::error::ZapClockError;
+use crate::payment::PaymentOutcome;
+use std::fmt;
+use std::sync::Arc;
+
+/// Stage a payment has just reached
+#[derive(Debug, Clone)]
+pub enum PaymentProgress {
+    /// Reading the recipient's LNURL-pay parameters
+    ResolvingAddress { address: String },
+    /// Invoice obtained and validated
+    InvoiceReceived {
+        amount_msats: u64,
+        /// Hex-encoded payment hash
+        payment_hash: String,
+    },
+    /// Wallet relay connection is up
+    RelayConnected { relay: String },
+    /// NIP-47 request published to the wallet relay
+    RequestPublished { request_id: String },
+    /// Wallet answered the request
+    ResponseReceived,
+    /// Preimage matches the payment hash
+    Verified { payment_hash: String },
+    /// Payment finished; always the last event of a successful payment
+    Completed { outcome: Box<PaymentOutcome> },
+    /// Payment not made (or its fate unknown); always the last event of a failed payment
+    Failed { error: ZapClockError },
+}
+
+/// Receiver of progress events (does nothing by default)
+#[derive(Clone, Default)]
+pub struct ProgressReporter(Option<Arc<dyn Fn(PaymentProgress) + Send + Sync>>);
+
+impl ProgressReporter {
+    pub fn new(on_progress: impl Fn(PaymentProgress) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(on_progress)))
+    }
+
+    pub fn emit(&self, progress: PaymentProgress) {
+        if let Some(on_progress) = &self.0 {
+            on_progress(progress);
+        }
+    }
+}
+
+impl fmt::Debug for ProgressReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ProgressReporter").field(&self.0.is_some()).finish()
+    }
+}
@@ -0,0 +1,231 @@
+//! Keeping secrets out of logs
+//!
+//! Call sites log invoices through [`invoice`] and never log connection
+//! strings, preimages or comments. [`RedactedFields`] is the safety net
+//! of the `fmt` output: it formats event and span fields, masking whatever
+//! slips through, whatever the log level. Logcat output formats fields on
+//! its own, so on Android the call sites' redaction is all there is.
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::borrow::Cow;
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::FormatFields;
+
+/// Characters of an invoice kept in logs (enough to tell invoices apart)
+const INVOICE_PREFIX_CHARS: usize = 20;
+
+/// Placeholder for removed values
+const REDACTED: &str = "<redacted>";
+
+/// Event and span fields whose values are never logged
+const SENSITIVE_FIELDS: &[&str] = &[
+    "connection_string",
+    "secret",
+    "preimage",
+    "invoice",
+    "bolt11",
+    "comment",
+];
+
+/// NWC connection strings (the `secret` parameter is the wallet key)
+static CONNECTION_STRING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(nostr\+walletconnect|nostrwalletconnect)://[^\s\x22'<>]+").unwrap()
+});
+
+/// `secret=<hex>` outside a full connection string
+static SECRET_PARAM: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(secret=)[0-9a-f]{16,}").unwrap());
+
+/// 32-byte hex value labelled as a preimage
+static PREIMAGE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(preimage\W{0,4})[0-9a-f]{64}").unwrap());
+
+/// BOLT11 invoices: human-readable part, separator and bech32 data
+static BOLT11: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(ln(?:bcrt|bc|tbs|tb|sb)[0-9]*[munp]?1)[02-9ac-hj-np-z]{50,}").unwrap()
+});
+
+/// Invoice shortened for logs (safe for any string length)
+pub fn invoice(bolt11: &str) -> String {
+    match bolt11.char_indices().nth(INVOICE_PREFIX_CHARS) {
+        Some((end, _)) => format!("{}…", &bolt11[..end]),
+        None => bolt11.to_string(),
+    }
+}
+
+/// Mask connection strings, secrets, preimages and full invoices in `text`
+pub fn scrub(text: &str) -> Cow<'_, str> {
+    let mut text = Cow::Borrowed(text);
+    let mut replace = |pattern: &Regex, with: &dyn Fn(&Captures) -> String| {
+        if let Cow::Owned(replaced) = pattern.replace_all(&text, |c: &Captures| with(c)) {
+            text = Cow::Owned(replaced);
+        }
+    };
+
+    replace(&CONNECTION_STRING, &|c| format!("{}://{}", &c[1], REDACTED));
+    replace(&SECRET_PARAM, &|c| format!("{}{}", &c[1], REDACTED));
+    replace(&PREIMAGE, &|c| format!("{}{}", &c[1], REDACTED));
+    replace(&BOLT11, &|c| invoice(&c[0]));
+    text
+}
+
+/// Field formatter that masks secrets in event and span fields
+///
+/// Sensitive fields are replaced with a placeholder and every other value
+/// goes through [`scrub`]. Set it on each output layer with `fmt_fields`,
+/// so events keep their metadata and span fields are covered too.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RedactedFields;
+
+impl<'writer> FormatFields<'writer> for RedactedFields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = RedactingVisitor {
+            writer,
+            result: Ok(()),
+            delimit: false,
+        };
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+/// Writes fields like the default formatter, with secrets masked
+struct RedactingVisitor<'writer> {
+    writer: Writer<'writer>,
+    result: fmt::Result,
+    delimit: bool,
+}
+
+impl Visit for RedactingVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.record_debug(field, &format_args!("{}", value));
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        // `log` record metadata is shown as the event's own
+        if self.result.is_err() || field.name().starts_with("log.") {
+            return;
+        }
+        let value = if SENSITIVE_FIELDS.contains(&field.name()) {
+            REDACTED.to_string()
+        } else {
+            scrub(&format!("{:?}", value)).into_owned()
+        };
+
+        let delimiter = if self.delimit { " " } else { "" };
+        self.delimit = true;
+        self.result = match field.name() {
+            "message" => write!(self.writer, "{}{}", delimiter, value),
+            name => write!(self.writer, "{}{}={}", delimiter, name, value),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    const INVOICE: &str = "lnbc2500u1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdq5xysxxatsyp3k7enxv4jsxqzpu9qrsgquk0rl77nj30yxdy8j9vdx85fkpmdla2087ne0xh8nhedh8w27kyke0lp53ut353s06fv3qfegext0eh0ymjpf39tuven09sam30g4vgpfna3rh";
+
+    #[test]
+    fn scrub_masks_connection_strings() {
+        let text = "connecting to nostr+walletconnect://b889ff5b1513b641e2a139f661a661364979c5beee91842f8f0ef42ab558e9d4?relay=wss%3A%2F%2Frelay.example.com&secret=71a8c14c1407c113601079c4302dab36460f0ccd0ad506f1f2dc73b5100e4f3c now";
+        assert_eq!(scrub(text), "connecting to nostr+walletconnect://<redacted> now");
+    }
+
+    #[test]
+    fn scrub_masks_secrets_and_preimages() {
+        let secret = "71a8c14c1407c113601079c4302dab36460f0ccd0ad506f1f2dc73b5100e4f3c";
+        assert_eq!(
+            scrub(&format!("relay=wss://relay.example.com&secret={}", secret)),
+            "relay=wss://relay.example.com&secret=<redacted>"
+        );
+        assert_eq!(
+            scrub(&format!("Preimage: \"{}\"", secret)),
+            "Preimage: \"<redacted>\""
+        );
+        // A bare payment hash is not a secret
+        assert_eq!(scrub(&format!("payment_hash={}", secret)), format!("payment_hash={}", secret));
+    }
+
+    #[test]
+    fn scrub_shortens_invoices() {
+        assert_eq!(
+            scrub(&format!("paying {} now", INVOICE)),
+            format!("paying {} now", invoice(INVOICE))
+        );
+        assert_eq!(
+            scrub(&format!("lightning:{}", INVOICE.to_uppercase())),
+            format!("lightning:{}", invoice(&INVOICE.to_uppercase()))
+        );
+    }
+
+    #[test]
+    fn scrub_leaves_other_text_alone() {
+        for text in ["", "✅ Payment successful", "alice@example.com paid 21 sats", "lnbc1 too short"] {
+            assert!(matches!(scrub(text), Cow::Borrowed(t) if t == text));
+        }
+    }
+
+    #[test]
+    fn invoice_keeps_a_prefix_of_any_string() {
+        assert_eq!(invoice(INVOICE), format!("{}…", &INVOICE[..INVOICE_PREFIX_CHARS]));
+        assert_eq!(invoice("lnbc1"), "lnbc1");
+        assert_eq!(invoice(&"⚡".repeat(30)), format!("{}…", "⚡".repeat(INVOICE_PREFIX_CHARS)));
+    }
+
+    /// Log output captured in memory
+    #[derive(Clone, Default)]
+    struct Output(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn redacted_fields_mask_event_and_span_fields() {
+        let output = Output::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .fmt_fields(RedactedFields)
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .with_span_events(FmtSpan::CLOSE)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("payment", bolt11 = INVOICE, amount_sats = 21);
+            let _entered = span.enter();
+            tracing::info!(comment = "wake up!", payment_hash = "abc", "paying {}", INVOICE);
+        });
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains(INVOICE), "{}", output);
+        assert!(!output.contains("wake up!"), "{}", output);
+        assert!(output.contains(&format!("paying {}", invoice(INVOICE))), "{}", output);
+        assert!(output.contains("comment=<redacted>"), "{}", output);
+        assert!(output.contains("payment_hash=\"abc\""), "{}", output);
+        assert!(output.contains("bolt11=<redacted> amount_sats=21"), "{}", output);
+        // Events keep their own metadata
+        assert!(output.contains("zap_clock::redact::tests"), "{}", output);
+        assert!(output.contains("close"), "{}", output);
+    }
+}
@@ -0,0 +1,164 @@
+//! Persistent payment records keyed by alarm occurrence
+//!
+//! Lets a payment interrupted by an app restart be resumed instead of
+//! paid a second time.
+
+use crate::error::{Result, ZapClockError};
+use crate::lightning::PendingSuccessAction;
+use crate::nwc::PayInvoiceResult;
+use crate::payment::PaymentOutcome;
+use crate::zap::ZapInfo;
+use nostr_sdk::Timestamp;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+/// File holding all payment records, inside the store directory
+const STORE_FILE_NAME: &str = "payments.json";
+
+/// Directory set by the app at startup
+static STORE_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Serializes read-modify-write cycles on the store files
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Everything needed to finish (or recognize) a payment after a restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRecord {
+    /// Idempotency key (alarm id + scheduled fire time)
+    pub key: String,
+    pub recipient: String,
+    pub amount_msats: u64,
+    pub comment: Option<String>,
+    /// Invoice being paid (None for keysend)
+    pub bolt11: Option<String>,
+    /// Hex-encoded payment hash
+    pub payment_hash: String,
+    /// Unix timestamp after which the invoice can't be paid (None for keysend)
+    pub expires_at: Option<u64>,
+    pub success_action: Option<PendingSuccessAction>,
+    pub zap: Option<ZapInfo>,
+    /// Signed NIP-47 request event (JSON), saved before it is published
+    pub request_event: String,
+    /// Set once the payment is confirmed
+    pub outcome: Option<PaymentOutcome>,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+impl PaymentRecord {
+    /// Build the receipt once the wallet has confirmed the payment
+    pub fn complete(&mut self, paid: PayInvoiceResult) -> PaymentOutcome {
+        let outcome = PaymentOutcome {
+            bolt11: self.bolt11.clone(),
+            payment_hash: self.payment_hash.clone(),
+            amount_msats: self.amount_msats,
+            fees_paid_msats: paid.fees_paid,
+            recipient: self.recipient.clone(),
+            comment: self.comment.clone(),
+            success_action: self
+                .success_action
+                .clone()
+                .map(|action| action.resolve(&paid.preimage)),
+            zap: self.zap.clone(),
+            preimage: paid.preimage,
+            started_at: self.started_at,
+            paid_at: Timestamp::now().as_u64(),
+        };
+        self.outcome = Some(outcome.clone());
+        outcome
+    }
+}
+
+/// Set the directory payment records are stored in
+pub fn set_directory(directory: &str) -> Result<()> {
+    let directory = PathBuf::from(directory);
+    fs::create_dir_all(&directory).map_err(storage_error)?;
+    *STORE_DIR.lock().unwrap_or_else(|e| e.into_inner()) = Some(directory);
+    Ok(())
+}
+
+/// Directory set with [`set_directory`]
+pub fn directory() -> Result<PathBuf> {
+    STORE_DIR
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or_else(|| ZapClockError::Storage {
+            message: "Payment store directory not set".to_string(),
+        })
+}
+
+/// Record stored under `key`, if any
+pub fn load(key: &str) -> Result<Option<PaymentRecord>> {
+    let _guard = lock();
+    Ok(read_all()?.remove(key))
+}
+
+/// Insert or replace a record
+pub fn save(record: &mut PaymentRecord) -> Result<()> {
+    let _guard = lock();
+    record.updated_at = Timestamp::now().as_u64();
+    let mut records = read_all()?;
+    records.insert(record.key.clone(), record.clone());
+    write_all(&records)
+}
+
+/// Forget a record (the payment definitely didn't go out)
+pub fn remove(key: &str) -> Result<()> {
+    let _guard = lock();
+    let mut records = read_all()?;
+    if records.remove(key).is_some() {
+        write_all(&records)?;
+    }
+    Ok(())
+}
+
+/// All stored records
+pub fn all() -> Result<Vec<PaymentRecord>> {
+    let _guard = lock();
+    Ok(read_all()?.into_values().collect())
+}
+
+fn read_all() -> Result<HashMap<String, PaymentRecord>> {
+    read_map(STORE_FILE_NAME)
+}
+
+fn write_all(records: &HashMap<String, PaymentRecord>) -> Result<()> {
+    write_map(STORE_FILE_NAME, records)
+}
+
+/// Lock a file's read-modify-write cycle (shared by every file in the store)
+pub(crate) fn lock() -> MutexGuard<'static, ()> {
+    STORE_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Read a JSON map stored in the store directory (empty if missing)
+pub(crate) fn read_map<T: DeserializeOwned>(file_name: &str) -> Result<HashMap<String, T>> {
+    let path = directory()?.join(file_name);
+    match fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str(&json).map_err(storage_error),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(e) => Err(storage_error(e)),
+    }
+}
+
+/// Write via a temporary file so a crash never leaves a half-written store
+pub(crate) fn write_map<T: Serialize>(file_name: &str, map: &HashMap<String, T>) -> Result<()> {
+    let directory = directory()?;
+    let path = directory.join(file_name);
+    let tmp_path = directory.join(format!("{}.tmp", file_name));
+
+    let json = serde_json::to_string_pretty(map).map_err(storage_error)?;
+    fs::write(&tmp_path, json).map_err(storage_error)?;
+    fs::rename(&tmp_path, &path).map_err(storage_error)
+}
+
+fn storage_error(e: impl std::fmt::Display) -> ZapClockError {
+    ZapClockError::Storage {
+        message: e.to_string(),
+    }
+}
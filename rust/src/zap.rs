@@ -0,0 +1,254 @@
+//! NIP-57 zap requests for LNURL-pay recipients
+
+use crate::error::{Result, ZapClockError};
+use crate::invoice::DecodedInvoice;
+use crate::network::NetworkPolicy;
+use bech32::{Bech32, Hrp};
+use nostr::nips::nip57::{self, ZapRequestData};
+use nostr::{Event, Filter, JsonUtil, Kind, PublicKey, TagKind, TagStandard, Url};
+use nostr_sdk::{Client, RelayPoolNotification};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use tracing::{info, instrument, warn};
+
+/// Relays the recipient's LNURL server publishes zap receipts to
+pub const DEFAULT_ZAP_RELAYS: &[&str] = &[
+    "wss://relay.damus.io",
+    "wss://nos.lol",
+    "wss://relay.nostr.band",
+];
+
+/// Signed kind-9734 zap request sent along with an LNURL callback
+#[derive(Debug, Clone)]
+pub struct ZapRequest {
+    /// Signed zap request event
+    pub event: Event,
+    /// LNURL server key that signs the zap receipt (`nostrPubkey`)
+    pub server_pubkey: PublicKey,
+    /// Relays the receipt will be published to
+    pub relays: Vec<Url>,
+}
+
+impl ZapRequest {
+    /// Build and sign an anonymous zap request
+    ///
+    /// ZapClock has no Nostr identity of its own, so the request is signed
+    /// with a throwaway key and carries an `anon` tag.
+    pub fn anonymous(
+        recipient: PublicKey,
+        server_pubkey: PublicKey,
+        lnurl_endpoint: &str,
+        amount_msats: u64,
+        comment: Option<&str>,
+    ) -> Result<Self> {
+        let relays: Vec<Url> = DEFAULT_ZAP_RELAYS
+            .iter()
+            .filter_map(|relay| Url::parse(relay).ok())
+            .collect();
+
+        let data = ZapRequestData::new(recipient, relays.clone())
+            .amount(amount_msats)
+            .lnurl(encode_lnurl(lnurl_endpoint)?)
+            .message(comment.unwrap_or_default());
+
+        let event = nip57::anonymous_zap_request(data).map_err(|e| ZapClockError::InvalidLnurlResponse {
+            message: format!("Could not sign zap request: {}", e),
+        })?;
+
+        Ok(Self {
+            event,
+            server_pubkey,
+            relays,
+        })
+    }
+
+    /// JSON sent as the `nostr` callback parameter
+    ///
+    /// The invoice description hash must commit to exactly this string.
+    pub fn to_json(&self) -> String {
+        self.event.as_json()
+    }
+}
+
+/// Whether the recipient's LNURL server has published a zap receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ZapReceiptStatus {
+    /// Not checked yet
+    Pending,
+    /// Valid kind-9735 receipt found on the zap request relays
+    Observed { receipt_id: String },
+    /// No valid receipt showed up before the timeout
+    NotObserved,
+}
+
+/// Zap details attached to a payment result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZapInfo {
+    /// Id of the zap request event (hex)
+    pub request_id: String,
+    /// Zap request as sent in the `nostr` callback parameter
+    pub request_json: String,
+    /// LNURL server key expected to sign the receipt (hex)
+    pub server_pubkey: String,
+    pub receipt: ZapReceiptStatus,
+}
+
+impl From<ZapRequest> for ZapInfo {
+    fn from(zap_request: ZapRequest) -> Self {
+        Self {
+            request_id: zap_request.event.id.to_hex(),
+            request_json: zap_request.to_json(),
+            server_pubkey: zap_request.server_pubkey.to_hex(),
+            receipt: ZapReceiptStatus::Pending,
+        }
+    }
+}
+
+impl ZapInfo {
+    /// Wait for the kind-9735 receipt of a paid zap (NIP-57 appendix F)
+    ///
+    /// Subscribes on the relays listed in the zap request and accepts the
+    /// first receipt that is signed by the LNURL server, carries the paid
+    /// invoice and embeds our zap request. Gives up after `timeout_secs`.
+    #[instrument(skip(self, bolt11))]
+    pub async fn wait_for_receipt(&self, bolt11: &str, timeout_secs: u64) -> Result<ZapReceiptStatus> {
+        let invalid = |message: String| ZapClockError::InvalidResponse { message };
+        let zap_request = Event::from_json(&self.request_json)
+            .map_err(|e| invalid(format!("Invalid zap request: {}", e)))?;
+        let server_pubkey = PublicKey::from_hex(&self.server_pubkey)
+            .map_err(|e| invalid(format!("Invalid nostrPubkey: {}", e)))?;
+        let invoice = DecodedInvoice::parse(bolt11)?;
+
+        let relays: Vec<Url> = zap_request
+            .tags
+            .iter()
+            .filter_map(|tag| match tag.as_standardized() {
+                Some(TagStandard::Relays(relays)) => Some(relays.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let recipient = zap_request
+            .tags
+            .public_keys()
+            .next()
+            .copied()
+            .ok_or_else(|| invalid("Zap request has no p tag".to_string()))?;
+
+        info!(relays = relays.len(), "🔎 Waiting for zap receipt");
+        let started = Instant::now();
+
+        let client = Client::default();
+        for relay in &relays {
+            if let Err(e) = client.add_relay(relay.as_str()).await {
+                warn!(relay = %relay, error = %e, "⚠️ Skipping relay");
+            }
+        }
+        client
+            .connect_with_timeout(NetworkPolicy::current().relay_connect_timeout())
+            .await;
+
+        let mut notifications = client.notifications();
+        let filter = Filter::new()
+            .kind(Kind::ZapReceipt)
+            .author(server_pubkey)
+            .pubkey(recipient)
+            .since(zap_request.created_at);
+        client
+            .subscribe(vec![filter], None)
+            .await
+            .map_err(|e| ZapClockError::Relay { message: e.to_string() })?;
+
+        let wait = async {
+            loop {
+                match notifications.recv().await {
+                    Ok(RelayPoolNotification::Event { event, .. }) => {
+                        if is_valid_receipt(&event, &zap_request, &server_pubkey, &invoice) {
+                            return Some(event.id);
+                        }
+                    }
+                    Ok(RelayPoolNotification::Shutdown) => return None,
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        };
+        let receipt = tokio::time::timeout(Duration::from_secs(timeout_secs), wait)
+            .await
+            .ok()
+            .flatten();
+
+        if let Err(e) = client.shutdown().await {
+            warn!(error = %e, "⚠️ Relay shutdown failed");
+        }
+
+        Ok(match receipt {
+            Some(receipt_id) => {
+                info!(
+                    receipt_id = %receipt_id,
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    "✅ Zap receipt observed"
+                );
+                ZapReceiptStatus::Observed {
+                    receipt_id: receipt_id.to_hex(),
+                }
+            }
+            None => {
+                warn!("⚠️ No zap receipt observed in time");
+                ZapReceiptStatus::NotObserved
+            }
+        })
+    }
+}
+
+/// Validate a zap receipt against the zap request and paid invoice
+fn is_valid_receipt(
+    receipt: &Event,
+    zap_request: &Event,
+    server_pubkey: &PublicKey,
+    invoice: &DecodedInvoice,
+) -> bool {
+    if receipt.kind != Kind::ZapReceipt || receipt.pubkey != *server_pubkey || receipt.verify().is_err() {
+        return false;
+    }
+
+    // Receipt must be for the invoice we paid, with the amount we zapped
+    let Some(receipt_invoice) = receipt
+        .tags
+        .find(TagKind::Bolt11)
+        .and_then(|tag| tag.content())
+        .and_then(|bolt11| DecodedInvoice::parse(bolt11).ok())
+    else {
+        return false;
+    };
+    let requested_msats = zap_request
+        .tags
+        .find_standardized(TagKind::Amount)
+        .and_then(|tag| match tag {
+            TagStandard::Amount { millisats, .. } => Some(*millisats),
+            _ => None,
+        });
+    if receipt_invoice.payment_hash != invoice.payment_hash
+        || receipt_invoice.amount_msats != invoice.amount_msats
+        || (requested_msats.is_some() && receipt_invoice.amount_msats != requested_msats)
+    {
+        return false;
+    }
+
+    // Description must be our own zap request
+    receipt
+        .tags
+        .find(TagKind::Description)
+        .and_then(|tag| tag.content())
+        .and_then(|description| Event::from_json(description).ok())
+        .is_some_and(|embedded| embedded.id == zap_request.id)
+}
+
+/// Encode an LNURL endpoint as a bech32 `lnurl1...` string (LUD-01)
+pub fn encode_lnurl(url: &str) -> Result<String> {
+    let hrp = Hrp::parse("lnurl").expect("valid hrp");
+    bech32::encode::<Bech32>(hrp, url.as_bytes()).map_err(|e| ZapClockError::InvalidLnurlResponse {
+        message: format!("Could not encode LNURL: {}", e),
+    })
+}